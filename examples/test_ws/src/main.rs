@@ -6,27 +6,27 @@ use rucord_rest::RequestManager;
 use rucord_ws::{
     api_types, Result, ShardError, WebSocketEventHandler, WebSocketManager, WebSocketManagerOptions,
 };
+use tokio::sync::Mutex;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let token = env::var("BOT_TOKEN").expect("expected BOT_TOKEN env.");
 
-    let rest = Arc::new(RequestManager::new_with_token(Default::default(), token.clone()).into());
+    let rest: Arc<Mutex<RequestManager>> =
+        Arc::new(RequestManager::new_with_token(Default::default(), token.clone()).into());
 
     let intents = GatewayIntentBits::MessageContent | GatewayIntentBits::Guilds;
 
-    let mut ws = WebSocketManager::new(WebSocketManagerOptions {
-        token,
-        intents,
-        rest,
-    });
+    let mut ws = WebSocketManager::new(WebSocketManagerOptions::new(token, intents, rest.clone()));
 
-    ws.connect(RawEventHandler).await?;
+    ws.connect(Arc::new(RawEventHandler { rest })).await?;
 
     Ok(())
 }
 
-struct RawEventHandler;
+struct RawEventHandler {
+    rest: Arc<Mutex<RequestManager>>,
+}
 
 #[async_trait]
 impl WebSocketEventHandler for RawEventHandler {
@@ -46,4 +46,20 @@ impl WebSocketEventHandler for RawEventHandler {
     async fn resumed(&self, _id: usize) {
         // NO OP
     }
+    async fn guild_integrations_update(&self, id: usize, guild_id: &api_types::Snowflake) {
+        // The event doesn't say what changed, fetch the integrations again.
+        match self
+            .rest
+            .lock()
+            .await
+            .get_guild_integrations(guild_id)
+            .await
+        {
+            Ok(integrations) => println!(
+                "[INFO] [SHARD {id}]: guild {guild_id} has {} integrations",
+                integrations.len()
+            ),
+            Err(error) => eprintln!("[ERROR] [SHARD {id}]: {error}"),
+        }
+    }
 }
@@ -0,0 +1,783 @@
+use std::time::SystemTime;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::{
+    parse_iso8601, ApplicationCommandPermission, AuditLogEntryObject, ChannelMention, EmojiObject,
+    GuildMemberObject, MentionedUser, MessageObject, MessageReferenceObject, MessageType,
+    PresenceStateType, RoleObject, Snowflake, StickerObject, ThreadMemberObject, ThreadObject,
+    UserObject,
+};
+
+/// Sent when a guild channel's webhook is created, updated, or deleted.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#webhooks-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhooksUpdateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Id of the channel.
+    pub channel_id: Snowflake,
+}
+
+/// Sent when a message is edited, only the changed fields are set. The nullable fields are
+/// `Some(None)` when they were cleared.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#message-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageUpdateObject {
+    /// Id of the message.
+    pub id: Snowflake,
+
+    /// Id of the channel the message was sent in.
+    pub channel_id: Snowflake,
+
+    /// Id of the guild the message was sent in.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// The author of this message.
+    #[serde(default)]
+    pub author: Option<UserObject>,
+
+    /// Contents of the message.
+    #[serde(default)]
+    pub content: Option<String>,
+
+    /// When this message was sent.
+    #[serde(default)]
+    pub timestamp: Option<String>,
+
+    /// When this message was edited.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub edited_timestamp: Option<Option<String>>,
+
+    /// Whether this was a TTS message.
+    #[serde(default)]
+    pub tts: Option<bool>,
+
+    /// Whether this message mentions everyone.
+    #[serde(default)]
+    pub mention_everyone: Option<bool>,
+
+    /// Users specifically mentioned in the message.
+    #[serde(default)]
+    pub mentions: Option<Vec<MentionedUser>>,
+
+    /// Roles specifically mentioned in this message.
+    #[serde(default)]
+    pub mention_roles: Option<Vec<Snowflake>>,
+
+    /// Channels specifically mentioned in this message.
+    #[serde(default)]
+    pub mention_channels: Option<Vec<ChannelMention>>,
+
+    /// Any embedded content.
+    #[serde(default)]
+    pub embeds: Option<Vec<Value>>,
+
+    /// Whether this message is pinned.
+    #[serde(default)]
+    pub pinned: Option<bool>,
+
+    /// [Type of message](https://discord.com/developers/docs/resources/channel#message-object-message-types).
+    #[serde(rename = "type", default)]
+    pub message_type: Option<MessageType>,
+
+    /// Data showing the source of a crosspost, channel follow add, pin, or reply message.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub message_reference: Option<Option<MessageReferenceObject>>,
+
+    /// [Message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags).
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub flags: Option<Option<u64>>,
+
+    /// The message associated with the `message_reference`.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub referenced_message: Option<Option<Box<MessageObject>>>,
+}
+
+impl MessageUpdateObject {
+    /// Merges the set fields into `base`, the fields missing from the update are kept.
+    pub fn apply_to(&self, base: &mut MessageObject) {
+        if let Some(ref author) = self.author {
+            base.author = author.clone();
+        }
+        if let Some(ref content) = self.content {
+            base.content = content.clone();
+        }
+        if let Some(ref timestamp) = self.timestamp {
+            base.timestamp = timestamp.clone();
+        }
+        if let Some(tts) = self.tts {
+            base.tts = tts;
+        }
+        if let Some(mention_everyone) = self.mention_everyone {
+            base.mention_everyone = mention_everyone;
+        }
+        if let Some(ref mentions) = self.mentions {
+            base.mentions = mentions.clone();
+        }
+        if let Some(ref mention_roles) = self.mention_roles {
+            base.mention_roles = mention_roles.clone();
+        }
+        if let Some(ref embeds) = self.embeds {
+            base.embeds = embeds.clone();
+        }
+        if let Some(pinned) = self.pinned {
+            base.pinned = pinned;
+        }
+        if let Some(message_type) = self.message_type {
+            base.message_type = message_type;
+        }
+
+        if let Some(ref edited_timestamp) = self.edited_timestamp {
+            base.edited_timestamp = edited_timestamp.clone();
+        }
+        if let Some(ref message_reference) = self.message_reference {
+            base.message_reference = message_reference.clone();
+        }
+        if let Some(flags) = self.flags {
+            base.flags = flags;
+        }
+        if let Some(ref referenced_message) = self.referenced_message {
+            base.referenced_message = referenced_message.clone();
+        }
+
+        base.guild_id = self.guild_id.or(base.guild_id);
+        base.mention_channels = self
+            .mention_channels
+            .clone()
+            .or(base.mention_channels.take());
+    }
+}
+
+/// Sent when a message is deleted.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#message-delete).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDeleteObject {
+    /// Id of the message.
+    pub id: Snowflake,
+
+    /// Id of the channel.
+    pub channel_id: Snowflake,
+
+    /// Id of the guild.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+}
+
+/// Sent when multiple messages are deleted at once.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#message-delete-bulk).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDeleteBulkObject {
+    /// Ids of the messages.
+    pub ids: Vec<Snowflake>,
+
+    /// Id of the channel.
+    pub channel_id: Snowflake,
+
+    /// Id of the guild.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+}
+
+/// Sent when a user is banned from a guild.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-ban-add).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildBanAddObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// User who was banned.
+    pub user: UserObject,
+}
+
+/// Sent when a user is unbanned from a guild.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-ban-remove).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildBanRemoveObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// User who was unbanned.
+    pub user: UserObject,
+}
+
+/// Sent when properties about the current bot's user change.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#user-update).
+pub type UserUpdateObject = UserObject;
+
+/// A user's presence is their current state on a guild.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#presence-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceObject {
+    /// User whose presence is being updated.
+    pub user: PresenceUserObject,
+
+    /// Id of the guild, missing from the presences of `GUILD_CREATE`.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// Either "idle", "dnd", "online", or "offline".
+    pub status: PresenceStateType,
+
+    // TODO: When write ActivityObject.
+    /// User's current activities.
+    #[serde(default)]
+    pub activities: Vec<Value>,
+
+    /// User's platform-dependent status.
+    #[serde(default)]
+    pub client_status: ClientStatusObject,
+}
+
+/// The user of a presence, only `id` is guaranteed to be sent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresenceUserObject {
+    /// The user's id.
+    pub id: Snowflake,
+}
+
+/// The status of a user on each platform, only set for active sessions.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#client-status-object).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientStatusObject {
+    /// User's status set for an active desktop (Windows, Linux, Mac) application session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desktop: Option<PresenceStateType>,
+
+    /// User's status set for an active mobile (iOS, Android) application session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<PresenceStateType>,
+
+    /// User's status set for an active web (browser, bot user) application session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub web: Option<PresenceStateType>,
+}
+
+/// Sent when a guild's emojis have been updated.
+///
+/// `emojis` is the complete new list rather than a diff, compare it with a cached list
+/// to find the added and removed emojis.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-emojis-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildEmojisUpdateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Array of emojis.
+    pub emojis: Vec<EmojiObject>,
+}
+
+/// Sent when a guild's stickers have been updated.
+///
+/// `stickers` is the complete new list rather than a diff, compare it with a cached list
+/// to find the added and removed stickers.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-stickers-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildStickersUpdateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Array of stickers.
+    pub stickers: Vec<StickerObject>,
+}
+
+/// Sent when a user explicitly removes all reactions from a message.
+///
+/// Reaction caches should drop every reaction of the message.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#message-reaction-remove-all).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionRemoveAllObject {
+    /// Id of the channel.
+    pub channel_id: Snowflake,
+
+    /// Id of the message.
+    pub message_id: Snowflake,
+
+    /// Id of the guild.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+}
+
+/// Sent when a bot removes all instances of a given emoji from the reactions of a message.
+///
+/// Reaction caches should only drop the reactions of `emoji`.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#message-reaction-remove-emoji).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionRemoveEmojiObject {
+    /// Id of the channel.
+    pub channel_id: Snowflake,
+
+    /// Id of the message.
+    pub message_id: Snowflake,
+
+    /// Id of the guild.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// Emoji that was removed.
+    pub emoji: EmojiObject,
+}
+
+/// Sent when the current user gains access to a channel.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#thread-list-sync).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadListSyncObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Parent channel ids whose threads are being synced, the whole guild if omitted.
+    #[serde(default)]
+    pub channel_ids: Option<Vec<Snowflake>>,
+
+    /// All active threads in the given channels that the current user can access.
+    pub threads: Vec<ThreadObject>,
+
+    /// All thread member objects from the synced threads for the current user.
+    pub members: Vec<ThreadMemberObject>,
+}
+
+/// Sent when a guild audit log entry is created.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-audit-log-entry-create).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildAuditLogEntryCreateObject {
+    /// Id of the guild, only present in the gateway version of the entry.
+    pub guild_id: Snowflake,
+
+    #[serde(flatten)]
+    pub entry: AuditLogEntryObject,
+}
+
+/// Sent when a guild is updated, only the set fields changed. The nullable fields are
+/// `Some(None)` when they were cleared.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildUpdateObject {
+    /// Guild id.
+    pub id: Snowflake,
+
+    /// Guild name (2-100 characters, excluding trailing and leading whitespace).
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// [Icon hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub icon: Option<Option<String>>,
+
+    /// [Splash hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub splash: Option<Option<String>>,
+
+    /// Id of owner.
+    #[serde(default)]
+    pub owner_id: Option<Snowflake>,
+
+    /// Id of afk channel.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub afk_channel_id: Option<Option<Snowflake>>,
+
+    /// Afk timeout in seconds.
+    #[serde(default)]
+    pub afk_timeout: Option<u32>,
+
+    /// Roles in the guild.
+    #[serde(default)]
+    pub roles: Option<Vec<RoleObject>>,
+
+    /// Custom guild emojis.
+    #[serde(default)]
+    pub emojis: Option<Vec<EmojiObject>>,
+
+    /// Enabled guild features.
+    #[serde(default)]
+    pub features: Option<Vec<String>>,
+
+    /// The id of the channel where guild notices such as welcome messages and boost events are posted.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub system_channel_id: Option<Option<Snowflake>>,
+
+    /// The vanity url code for the guild.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub vanity_url_code: Option<Option<String>>,
+
+    /// The description of a guild.
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub description: Option<Option<String>>,
+
+    /// [Banner hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub banner: Option<Option<String>>,
+
+    /// The preferred locale of a Community guild.
+    #[serde(default)]
+    pub preferred_locale: Option<String>,
+
+    /// True if this guild is unavailable due to an outage.
+    #[serde(default)]
+    pub unavailable: Option<bool>,
+}
+
+/// Sent when an application command's permissions are updated.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#application-command-permissions-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationCommandPermissionsUpdateObject {
+    /// Id of the command, or the application id when the permissions apply to all commands.
+    pub id: Snowflake,
+
+    /// Id of the application the command belongs to.
+    pub application_id: Snowflake,
+
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Permissions for the command in the guild, max of 100.
+    pub permissions: Vec<ApplicationCommandPermission>,
+}
+
+/// Sent in response to a Request Guild Members, the members are split in chunks of up to 1000.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-members-chunk).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMembersChunkObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Set of guild members.
+    pub members: Vec<GuildMemberObject>,
+
+    /// Chunk index in the expected chunks for this response (0 <= chunk_index < chunk_count).
+    pub chunk_index: u32,
+
+    /// Total number of expected chunks for this response.
+    pub chunk_count: u32,
+
+    /// When passing an invalid id to Request Guild Members, it will be returned here.
+    #[serde(default)]
+    pub not_found: Option<Vec<String>>,
+
+    /// When passing true to Request Guild Members, presences of the returned members will be here.
+    #[serde(default)]
+    pub presences: Option<Vec<PresenceObject>>,
+
+    /// Nonce used in the Request Guild Members.
+    #[serde(default)]
+    pub nonce: Option<String>,
+}
+
+impl GuildMembersChunkObject {
+    /// Whether this is the last chunk of the response.
+    #[inline]
+    pub fn is_last(&self) -> bool {
+        self.chunk_index + 1 >= self.chunk_count
+    }
+}
+
+/// Sent when a new user joins a guild.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-member-add).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberAddObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    #[serde(flatten)]
+    pub member: GuildMemberObject,
+}
+
+/// Sent when a user is removed from a guild (leave/kick/ban).
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-member-remove).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildMemberRemoveObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// The user who was removed.
+    pub user: UserObject,
+}
+
+/// Sent when a guild's voice server is updated, e.g. when joining a voice channel.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#voice-server-update).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoiceServerUpdateObject {
+    /// Voice connection token.
+    pub token: String,
+
+    /// Guild this voice server update is for.
+    pub guild_id: Snowflake,
+
+    /// Voice server host, null when the voice server was deallocated.
+    pub endpoint: Option<String>,
+}
+
+/// Sent when a guild role is created.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-role-create).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildRoleCreateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Role that was created.
+    pub role: RoleObject,
+}
+
+/// Sent when a guild role is updated.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-role-update).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildRoleUpdateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Role that was updated.
+    pub role: RoleObject,
+}
+
+/// Sent when a guild role is deleted, only the id of the role is sent.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-role-delete).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildRoleDeleteObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Id of the role.
+    pub role_id: Snowflake,
+}
+
+/// Sent when a guild member is updated, the fields not sent are `None` rather than unchanged.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-member-update).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildMemberUpdateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// User role ids.
+    pub roles: Vec<Snowflake>,
+
+    /// The user.
+    pub user: UserObject,
+
+    /// Nickname of the user in the guild.
+    #[serde(default)]
+    pub nick: Option<String>,
+
+    /// The member's [guild avatar hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub avatar: Option<String>,
+
+    /// When the user joined the guild.
+    #[serde(default)]
+    pub joined_at: Option<String>,
+
+    /// When the user started boosting the guild.
+    #[serde(default)]
+    pub premium_since: Option<String>,
+
+    /// Whether the user is deafened in voice channels.
+    #[serde(default)]
+    pub deaf: Option<bool>,
+
+    /// Whether the user is muted in voice channels.
+    #[serde(default)]
+    pub mute: Option<bool>,
+
+    /// Whether the user has not yet passed the guild's Membership Screening requirements.
+    #[serde(default)]
+    pub pending: Option<bool>,
+
+    /// When the user's timeout will expire and the user will be able to communicate in the guild again.
+    #[serde(default)]
+    pub communication_disabled_until: Option<String>,
+
+    /// [Guild member flags](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags).
+    #[serde(default)]
+    pub flags: Option<u64>,
+}
+
+impl GuildMemberUpdateObject {
+    /// The roles the member got since `old`.
+    pub fn added_roles(&self, old: &GuildMemberObject) -> Vec<Snowflake> {
+        self.roles
+            .iter()
+            .filter(|role| !old.roles.contains(role))
+            .copied()
+            .collect()
+    }
+
+    /// The roles the member lost since `old`.
+    pub fn removed_roles(&self, old: &GuildMemberObject) -> Vec<Snowflake> {
+        old.roles
+            .iter()
+            .filter(|role| !self.roles.contains(role))
+            .copied()
+            .collect()
+    }
+
+    /// Applies the update to the member, keeping the fields that weren't sent.
+    pub fn apply_to(&self, member: &mut GuildMemberObject) {
+        member.user = Some(self.user.clone());
+        member.roles = self.roles.clone();
+        member.nick = self.nick.clone();
+        member.avatar = self.avatar.clone();
+        member.premium_since = self.premium_since.clone();
+        member.pending = self.pending;
+        member.communication_disabled_until = self.communication_disabled_until.clone();
+
+        if let Some(ref joined_at) = self.joined_at {
+            member.joined_at = joined_at.clone();
+        }
+        if let Some(deaf) = self.deaf {
+            member.deaf = deaf;
+        }
+        if let Some(mute) = self.mute {
+            member.mute = mute;
+        }
+        if let Some(flags) = self.flags {
+            member.flags = flags;
+        }
+    }
+}
+
+/// Sent when the thread member object for the current user is updated.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#thread-member-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMemberUpdateObject {
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    #[serde(flatten)]
+    pub member: ThreadMemberObject,
+}
+
+/// Sent when anyone is added to or removed from a thread.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#thread-members-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMembersUpdateObject {
+    /// Id of the thread.
+    pub id: Snowflake,
+
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+
+    /// Approximate number of members in the thread, stops counting at 50.
+    pub member_count: u32,
+
+    /// Users who were added to the thread.
+    #[serde(default)]
+    pub added_members: Option<Vec<ThreadMemberObject>>,
+
+    /// Ids of the users who were removed from the thread.
+    #[serde(default)]
+    pub removed_member_ids: Option<Vec<Snowflake>>,
+}
+
+/// Sent when a user has subscribed to a guild scheduled event.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-scheduled-event-user-add).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildScheduledEventUserAddObject {
+    /// Id of the guild scheduled event.
+    pub guild_scheduled_event_id: Snowflake,
+
+    /// Id of the user.
+    pub user_id: Snowflake,
+
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+}
+
+/// Sent when a user has unsubscribed from a guild scheduled event.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-scheduled-event-user-remove).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildScheduledEventUserRemoveObject {
+    /// Id of the guild scheduled event.
+    pub guild_scheduled_event_id: Snowflake,
+
+    /// Id of the user.
+    pub user_id: Snowflake,
+
+    /// Id of the guild.
+    pub guild_id: Snowflake,
+}
+
+/// Sent when a guild integration is updated, the event doesn't include the integrations.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#guild-integrations-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildIntegrationsUpdateObject {
+    /// Id of the guild whose integrations were updated.
+    pub guild_id: Snowflake,
+}
+
+/// Sent when a message is pinned or unpinned in a text channel, not when a pinned message is
+/// deleted.
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#channel-pins-update).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelPinsUpdateObject {
+    /// Id of the guild.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// Id of the channel.
+    pub channel_id: Snowflake,
+
+    /// Time at which the most recent pinned message was pinned, null when all the pins were
+    /// removed.
+    #[serde(default)]
+    pub last_pin_timestamp: Option<String>,
+}
+
+impl ChannelPinsUpdateObject {
+    /// The parsed `last_pin_timestamp`, `None` when there are no pins left.
+    #[inline]
+    pub fn last_pin_time(&self) -> Option<SystemTime> {
+        parse_iso8601(self.last_pin_timestamp.as_deref()?)
+    }
+}
+
+/// Tells a missing field, `None`, apart from a null one, `Some(None)`.
+fn double_option<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error> {
+    Option::deserialize(deserializer).map(Some)
+}
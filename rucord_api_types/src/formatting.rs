@@ -0,0 +1,208 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::Snowflake;
+
+/// A Unix timestamp in seconds that can be formatted as Discord timestamp markdown.
+/// [Discord documentation](https://discord.com/developers/docs/reference#message-formatting-timestamp-styles).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DiscordTimestamp(pub u64);
+
+impl DiscordTimestamp {
+    #[inline]
+    pub fn now() -> Self {
+        Self(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        )
+    }
+
+    /// The moment at which the given snowflake was created.
+    #[inline]
+    pub fn from_snowflake(id: &Snowflake) -> Self {
+        Self(id.timestamp() / 1000)
+    }
+
+    /// Returns the timestamp as an ISO 8601 UTC date, e.g. `2021-01-01T00:00:00Z`.
+    pub fn to_iso8601(self) -> String {
+        let (days, seconds) = (self.0 / 86400, self.0 % 86400);
+
+        // Converts the days since the epoch to a civil date, see
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+        let z = days + 719468;
+        let era = z / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + u64::from(month <= 2);
+
+        format!(
+            "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    }
+
+    /// Returns the markdown that Discord renders as this timestamp, e.g. `<t:1609459200:F>`.
+    #[inline]
+    pub fn format(self, style: TimestampStyle) -> String {
+        format!("<t:{}:{}>", self.0, style.as_char())
+    }
+}
+
+/// Parses an ISO 8601 date as sent by Discord, e.g. `2015-04-26T06:26:56.936000+00:00`, `None`
+/// if it is malformed.
+pub fn parse_iso8601(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.split_once('T')?;
+
+    let mut date = date.splitn(3, '-');
+    let year: i64 = date.next()?.parse().ok()?;
+    let month: i64 = date.next()?.parse().ok()?;
+    let day: i64 = date.next()?.parse().ok()?;
+
+    let (time, offset) = match time.strip_suffix('Z') {
+        Some(time) => (time, 0),
+        None => {
+            let (time, offset) = time.split_at(time.rfind(['+', '-'])?);
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            let seconds = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+
+            (
+                time,
+                if offset.starts_with('-') {
+                    -seconds
+                } else {
+                    seconds
+                },
+            )
+        }
+    };
+
+    let (time, nanos) = match time.split_once('.') {
+        Some((time, fraction)) if fraction.bytes().all(|b| b.is_ascii_digit()) => {
+            let digits = &fraction[..fraction.len().min(9)];
+            (
+                time,
+                digits.parse::<u32>().ok()? * 10u32.pow(9 - digits.len() as u32),
+            )
+        }
+        Some(_) => return None,
+        None => (time, 0),
+    };
+
+    let mut time = time.splitn(3, ':');
+    let hour: i64 = time.next()?.parse().ok()?;
+    let minute: i64 = time.next()?.parse().ok()?;
+    let second: i64 = time.next()?.parse().ok()?;
+
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Converts the civil date to days since the epoch, the inverse of `to_iso8601`, see
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil.
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let seconds = days * 86400 + hour * 3600 + minute * 60 + second - offset;
+    let nanos = Duration::from_nanos(nanos.into());
+
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64) + nanos)
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()) - nanos)
+    }
+}
+
+/// Represents a Timestamp Style.
+/// [Discord documentation](https://discord.com/developers/docs/reference#message-formatting-timestamp-styles).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TimestampStyle {
+    /// 16:20
+    ShortTime,
+
+    /// 16:20:30
+    LongTime,
+
+    /// 20/04/2021
+    ShortDate,
+
+    /// 20 April 2021
+    LongDate,
+
+    /// 20 April 2021 16:20
+    #[default]
+    ShortDateTime,
+
+    /// Tuesday, 20 April 2021 16:20
+    LongDateTime,
+
+    /// 2 months ago
+    Relative,
+}
+
+impl TimestampStyle {
+    #[inline]
+    pub fn as_char(self) -> char {
+        match self {
+            Self::ShortTime => 't',
+            Self::LongTime => 'T',
+            Self::ShortDate => 'd',
+            Self::LongDate => 'D',
+            Self::ShortDateTime => 'f',
+            Self::LongDateTime => 'F',
+            Self::Relative => 'R',
+        }
+    }
+}
+
+/// Extracts the ids of the channels mentioned with `<#id>` in the given content.
+pub fn parse_channel_mentions(content: &str) -> Vec<Snowflake> {
+    parse_mentions(content, &["<#"])
+}
+
+/// Extracts the ids of the users mentioned with `<@id>` or `<@!id>` in the given content.
+pub fn parse_user_mentions(content: &str) -> Vec<Snowflake> {
+    parse_mentions(content, &["<@!", "<@"])
+}
+
+/// Extracts the ids of the roles mentioned with `<@&id>` in the given content.
+pub fn parse_role_mentions(content: &str) -> Vec<Snowflake> {
+    parse_mentions(content, &["<@&"])
+}
+
+/// Extracts the ids wrapped between any of the given prefixes and `>`.
+fn parse_mentions(content: &str, prefixes: &[&str]) -> Vec<Snowflake> {
+    let mut ids = Vec::new();
+
+    for (start, _) in content.match_indices('<') {
+        let rest = &content[start..];
+
+        let Some(rest) = prefixes.iter().find_map(|p| rest.strip_prefix(p)) else {
+            continue;
+        };
+
+        let Some(end) = rest.find('>') else {
+            continue;
+        };
+
+        if let Ok(id) = Snowflake::parse(&rest[..end]) {
+            ids.push(id);
+        }
+    }
+
+    ids
+}
@@ -1,16 +1,34 @@
 #![allow(non_upper_case_globals)]
 
-use std::{env, str::FromStr};
-
-use crate::{Snowflake, UnavailableGuildObject, UserObject};
+use std::{env, fmt, str::FromStr};
+
+use crate::{
+    ApplicationCommandPermissionsUpdateObject, ChannelObject, ChannelPinsUpdateObject,
+    GuildAuditLogEntryCreateObject, GuildBanAddObject, GuildBanRemoveObject,
+    GuildEmojisUpdateObject, GuildIntegrationsUpdateObject, GuildMemberAddObject,
+    GuildMemberRemoveObject, GuildMemberUpdateObject, GuildMembersChunkObject, GuildObject,
+    GuildRoleCreateObject, GuildRoleDeleteObject, GuildRoleUpdateObject,
+    GuildScheduledEventUserAddObject, GuildScheduledEventUserRemoveObject,
+    GuildStickersUpdateObject, GuildUpdateObject, InteractionObject, MessageDeleteBulkObject,
+    MessageDeleteObject, MessageReactionRemoveAllObject, MessageReactionRemoveEmojiObject,
+    MessageUpdateObject, PartialChannelObject, Permissions, PresenceObject, Snowflake,
+    ThreadListSyncObject, ThreadMemberUpdateObject, ThreadMembersUpdateObject, ThreadObject,
+    UnavailableGuildObject, UserObject, UserUpdateObject, VoiceServerUpdateObject,
+    WebhooksUpdateObject,
+};
 use bitflags::bitflags;
-use derive_more::From;
+use derive_more::{Error, From};
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
-use serde::{ser::SerializeStruct, Deserialize, Serialize};
-use serde_json::{from_value, Value};
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Serialize,
+};
+use serde_json::{from_value, Error as JsonError, Value};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use strum_macros::{Display, EnumString};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString, IntoStaticStr};
 
 type JsonMap = serde_json::Map<String, Value>;
 
@@ -56,7 +74,7 @@ pub enum GatewayOpcode {
 /// Represents a Discord gateway close event code and associated error message.
 ///
 /// [Discord documentation](https://discord.com/developers/docs/topics/opcodes-and-status-codes#gateway-gateway-opcodes).
-#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr, FromPrimitive, PartialEq, Eq)]
 #[repr(u32)]
 pub enum GatewayCloseCode {
     /// We're not sure what went wrong. Try reconnecting?
@@ -89,11 +107,26 @@ pub enum GatewayCloseCode {
     DisallowedIntents = 4014,
 }
 
+impl GatewayCloseCode {
+    /// Whether the client should reconnect after receiving this close code.
+    pub fn is_reconnectable(&self) -> bool {
+        !matches!(
+            self,
+            Self::AuthenticationFailed
+                | Self::InvalidShard
+                | Self::ShardingRequired
+                | Self::InvalidApiVersion
+                | Self::InvalidIntents
+                | Self::DisallowedIntents
+        )
+    }
+}
+
 bitflags! {
     /// Represents the different events that can be received over the gateway.
     ///
     /// [Discord documentation](https://discord.com/developers/docs/topics/gateway#list-of-intents).
-    #[derive(Serialize, Default, Deserialize)]
+    #[derive(Default)]
     pub struct GatewayIntentBits: u64 {
         const Guilds = 1 << 0;
         const GuildMembers = 1 << 1;
@@ -117,6 +150,214 @@ bitflags! {
     }
 }
 
+/// The name of each intent, as written in the Discord documentation.
+const INTENT_NAMES: &[(&str, GatewayIntentBits)] = &[
+    ("Guilds", GatewayIntentBits::Guilds),
+    ("GuildMembers", GatewayIntentBits::GuildMembers),
+    ("GuildModeration", GatewayIntentBits::GuildModeration),
+    (
+        "GuildEmojisAndStickers",
+        GatewayIntentBits::GuildEmojisAndStickers,
+    ),
+    ("GuildIntegrations", GatewayIntentBits::GuildIntegrations),
+    ("GuildWebhooks", GatewayIntentBits::GuildWebhooks),
+    ("GuildInvites", GatewayIntentBits::GuildInvites),
+    ("GuildVoiceStates", GatewayIntentBits::GuildVoiceStates),
+    ("GuildPresences", GatewayIntentBits::GuildPresences),
+    ("GuildMessages", GatewayIntentBits::GuildMessages),
+    (
+        "GuildMessageReactions",
+        GatewayIntentBits::GuildMessageReactions,
+    ),
+    ("GuildMessageTyping", GatewayIntentBits::GuildMessageTyping),
+    ("DirectMessages", GatewayIntentBits::DirectMessages),
+    (
+        "DirectMessageReactions",
+        GatewayIntentBits::DirectMessageReactions,
+    ),
+    (
+        "DirectMessageTyping",
+        GatewayIntentBits::DirectMessageTyping,
+    ),
+    ("MessageContent", GatewayIntentBits::MessageContent),
+    (
+        "GuildScheduledEvents",
+        GatewayIntentBits::GuildScheduledEvents,
+    ),
+    (
+        "AutoModerationConfiguration",
+        GatewayIntentBits::AutoModerationConfiguration,
+    ),
+    (
+        "AutoModerationExecution",
+        GatewayIntentBits::AutoModerationExecution,
+    ),
+];
+
+/// The intents delivering the events a bot with the permissions usually acts on, e.g. the bans
+/// of a bot with `BanMembers`.
+const PERMISSION_INTENTS: &[(Permissions, GatewayIntentBits)] = &[
+    (
+        Permissions::ManageGuild,
+        GatewayIntentBits::GuildIntegrations.union(GatewayIntentBits::AutoModerationConfiguration),
+    ),
+    (
+        Permissions::KickMembers
+            .union(Permissions::BanMembers)
+            .union(Permissions::ModerateMembers)
+            .union(Permissions::ViewAuditLog),
+        GatewayIntentBits::GuildModeration,
+    ),
+    (
+        Permissions::ManageRoles
+            .union(Permissions::ManageChannels)
+            .union(Permissions::ManageThreads),
+        GatewayIntentBits::Guilds,
+    ),
+    (
+        Permissions::ManageGuildExpressions,
+        GatewayIntentBits::GuildEmojisAndStickers,
+    ),
+    (
+        Permissions::ManageWebhooks,
+        GatewayIntentBits::GuildWebhooks,
+    ),
+    (
+        Permissions::CreateInstantInvite,
+        GatewayIntentBits::GuildInvites,
+    ),
+    (
+        Permissions::Connect
+            .union(Permissions::Speak)
+            .union(Permissions::MuteMembers)
+            .union(Permissions::DeafenMembers)
+            .union(Permissions::MoveMembers),
+        GatewayIntentBits::GuildVoiceStates,
+    ),
+    (
+        Permissions::SendMessages
+            .union(Permissions::ManageMessages)
+            .union(Permissions::ReadMessageHistory),
+        GatewayIntentBits::GuildMessages,
+    ),
+    (
+        Permissions::AddReactions,
+        GatewayIntentBits::GuildMessageReactions,
+    ),
+    (
+        Permissions::ManageEvents,
+        GatewayIntentBits::GuildScheduledEvents,
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Error, derive_more::Display)]
+#[display(fmt = "unknown gateway intent: {name}")]
+pub struct UnknownIntentError {
+    #[error(not(source))]
+    pub name: String,
+}
+
+impl GatewayIntentBits {
+    /// Returns the names of the enabled intents, in bit order.
+    pub fn to_name_list(&self) -> Vec<&'static str> {
+        INTENT_NAMES
+            .iter()
+            .filter(|(_, intent)| self.contains(*intent))
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// The intents a bot with the permissions usually needs, e.g. `GuildModeration` for
+    /// `BanMembers`. This is only a hint for configuring a bot, the permissions don't require
+    /// any intent and the intents don't grant any permission. Privileged intents are never
+    /// suggested.
+    pub fn from_permission_bits(permissions: Permissions) -> Self {
+        let permissions = if permissions.contains(Permissions::Administrator) {
+            Permissions::all()
+        } else {
+            permissions
+        };
+
+        PERMISSION_INTENTS
+            .iter()
+            .filter(|(mapped, _)| permissions.intersects(*mapped))
+            .fold(Self::empty(), |intents, (_, mapped)| intents | *mapped)
+    }
+
+    /// The permissions a bot usually needs to act on the events of the intents, the inverse
+    /// hint of [`from_permission_bits`](Self::from_permission_bits).
+    pub fn suggested_permissions(&self) -> Permissions {
+        PERMISSION_INTENTS
+            .iter()
+            .filter(|(_, mapped)| self.intersects(*mapped))
+            .fold(Permissions::empty(), |permissions, (mapped, _)| {
+                permissions | *mapped
+            })
+    }
+}
+
+impl TryFrom<&str> for GatewayIntentBits {
+    type Error = UnknownIntentError;
+
+    fn try_from(name: &str) -> Result<Self, Self::Error> {
+        INTENT_NAMES
+            .iter()
+            .find(|(intent_name, _)| *intent_name == name)
+            .map(|(_, intent)| *intent)
+            .ok_or_else(|| UnknownIntentError { name: name.into() })
+    }
+}
+
+impl Serialize for GatewayIntentBits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+/// Deserializes either the integer bitfield, or a list of intent names.
+impl<'de> Deserialize<'de> for GatewayIntentBits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct IntentsVisitor;
+
+        impl<'de> Visitor<'de> for IntentsVisitor {
+            type Value = GatewayIntentBits;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an intents bitfield or a list of intent names")
+            }
+
+            fn visit_u64<E: de::Error>(self, bits: u64) -> Result<Self::Value, E> {
+                Ok(GatewayIntentBits::from_bits_truncate(bits))
+            }
+
+            fn visit_i64<E: de::Error>(self, bits: i64) -> Result<Self::Value, E> {
+                u64::try_from(bits)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(bits), &self))
+                    .and_then(|bits| self.visit_u64(bits))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut intents = GatewayIntentBits::empty();
+
+                while let Some(name) = seq.next_element::<String>()? {
+                    intents |=
+                        GatewayIntentBits::try_from(name.as_str()).map_err(de::Error::custom)?;
+                }
+
+                Ok(intents)
+            }
+        }
+
+        deserializer.deserialize_any(IntentsVisitor)
+    }
+}
+
 /// The events that can be received over the Discord gateway.
 ///
 /// This enum represents all of the different events that can be sent to your bot
@@ -124,7 +365,9 @@ bitflags! {
 /// feature.
 ///
 /// [Discord documentation](https://discord.com/developers/docs/topics/gateway#commands-and-events-gateway-events).
-#[derive(Debug, Clone, Serialize, Deserialize, EnumString, Display, PartialEq, Eq)]
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, EnumString, EnumIter, Display, PartialEq, Eq, Hash,
+)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum GatewayDispatchEvents {
     /// Emitted when the application command permissions for a guild have been updated.
@@ -283,16 +526,167 @@ pub enum GatewayDispatchEvents {
     GuildAuditLogEntryCreate,
 }
 
+/// The events enabled by each intent.
+///
+/// [Discord documentation](https://discord.com/developers/docs/topics/gateway#list-of-intents).
+const INTENT_EVENTS: &[(GatewayIntentBits, &[GatewayDispatchEvents])] = {
+    use GatewayDispatchEvents::*;
+
+    &[
+        (
+            GatewayIntentBits::Guilds,
+            &[
+                GuildCreate,
+                GuildUpdate,
+                GuildDelete,
+                GuildRoleCreate,
+                GuildRoleUpdate,
+                GuildRoleDelete,
+                ChannelCreate,
+                ChannelUpdate,
+                ChannelDelete,
+                ChannelPinsUpdate,
+                ThreadCreate,
+                ThreadUpdate,
+                ThreadDelete,
+                ThreadListSync,
+                ThreadMemberUpdate,
+                ThreadMembersUpdate,
+                StageInstanceCreate,
+                StageInstanceUpdate,
+                StageInstanceDelete,
+            ],
+        ),
+        (
+            GatewayIntentBits::GuildMembers,
+            &[
+                GuildMemberAdd,
+                GuildMemberUpdate,
+                GuildMemberRemove,
+                ThreadMembersUpdate,
+            ],
+        ),
+        (
+            GatewayIntentBits::GuildModeration,
+            &[GuildAuditLogEntryCreate, GuildBanAdd, GuildBanRemove],
+        ),
+        (
+            GatewayIntentBits::GuildEmojisAndStickers,
+            &[GuildEmojisUpdate, GuildStickersUpdate],
+        ),
+        (
+            GatewayIntentBits::GuildIntegrations,
+            &[
+                GuildIntegrationsUpdate,
+                IntegrationCreate,
+                IntegrationUpdate,
+                IntegrationDelete,
+            ],
+        ),
+        (GatewayIntentBits::GuildWebhooks, &[WebhooksUpdate]),
+        (
+            GatewayIntentBits::GuildInvites,
+            &[InviteCreate, InviteDelete],
+        ),
+        (GatewayIntentBits::GuildVoiceStates, &[VoiceStateUpdate]),
+        (GatewayIntentBits::GuildPresences, &[PresenceUpdate]),
+        (
+            GatewayIntentBits::GuildMessages,
+            &[
+                MessageCreate,
+                MessageUpdate,
+                MessageDelete,
+                MessageDeleteBulk,
+            ],
+        ),
+        (
+            GatewayIntentBits::GuildMessageReactions,
+            &[
+                MessageReactionAdd,
+                MessageReactionRemove,
+                MessageReactionRemoveAll,
+                MessageReactionRemoveEmoji,
+            ],
+        ),
+        (GatewayIntentBits::GuildMessageTyping, &[TypingStart]),
+        (
+            GatewayIntentBits::DirectMessages,
+            &[
+                MessageCreate,
+                MessageUpdate,
+                MessageDelete,
+                ChannelPinsUpdate,
+            ],
+        ),
+        (
+            GatewayIntentBits::DirectMessageReactions,
+            &[
+                MessageReactionAdd,
+                MessageReactionRemove,
+                MessageReactionRemoveAll,
+                MessageReactionRemoveEmoji,
+            ],
+        ),
+        (GatewayIntentBits::DirectMessageTyping, &[TypingStart]),
+        (
+            GatewayIntentBits::GuildScheduledEvents,
+            &[
+                GuildScheduledEventCreate,
+                GuildScheduledEventUpdate,
+                GuildScheduledEventDelete,
+                GuildScheduledEventUserAdd,
+                GuildScheduledEventUserRemove,
+            ],
+        ),
+        (
+            GatewayIntentBits::AutoModerationConfiguration,
+            &[
+                AutoModerationRuleCreate,
+                AutoModerationRuleUpdate,
+                AutoModerationRuleDelete,
+            ],
+        ),
+        (
+            GatewayIntentBits::AutoModerationExecution,
+            &[AutoModerationActionExecution],
+        ),
+    ]
+};
+
+/// Returns the intents that enable the event, any of them is enough to receive it.
+///
+/// An empty set means the event is always received.
+pub fn required_intents(event: GatewayDispatchEvents) -> GatewayIntentBits {
+    INTENT_EVENTS
+        .iter()
+        .filter(|(_, events)| events.contains(&event))
+        .fold(GatewayIntentBits::empty(), |intents, (intent, _)| {
+            intents | *intent
+        })
+}
+
+/// Returns the events received with the given intents, including the ones that don't require any intent.
+pub fn events_for_intents(intents: GatewayIntentBits) -> Vec<GatewayDispatchEvents> {
+    GatewayDispatchEvents::iter()
+        .filter(|event| {
+            let required = required_intents(*event);
+
+            required.is_empty() || required.intersects(intents)
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone, Deserialize, From)]
 pub enum GatewaySendPayload {
     Identify(IdentifyData),
     Resume(ResumeData),
-    Heartbeat(Option<u64>),
+    Heartbeat(Option<i64>),
     RequestGuildMembers(RequestGuildMembersData),
     VoiceStateUpdate(VoiceStateUpdateData),
     UpdatePresence(UpdatePresenceData),
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, Serialize)]
 pub enum GatewayReceivePayload {
     /// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#hello).
@@ -311,7 +705,7 @@ pub enum GatewayReceivePayload {
     Reconnect,
 
     /// [Discord documentation](https://discord.com/developers/docs/topics/gateway#reconnect).
-    Dispatch((u64, DispatchPayload)),
+    Dispatch((i64, DispatchPayload)),
 
     UnknownOp(u64, JsonMap),
 }
@@ -319,13 +713,16 @@ pub enum GatewayReceivePayload {
 //TODO: Write all events when need it.
 /// Represents a payload for a `Dispatch` GatewayOpcode.
 /// [Discord documentation](https://discord.com/developers/docs/topics/gateway-events#receive-events).
-#[derive(Debug, Clone, Serialize)]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Serialize, IntoStaticStr)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum DispatchPayload {
     /// Contains the initial state information.
     Ready(ReadyData),
     /// Response to [Resume](https://discord.com/developers/docs/topics/gateway-events#resumed).
+    #[strum(serialize = "RESUMED")]
     Resume,
-    ApplicationCommandPermissionsUpdate(JsonMap),
+    ApplicationCommandPermissionsUpdate(ApplicationCommandPermissionsUpdateObject),
 
     AutoModerationRuleCreate(JsonMap),
 
@@ -335,57 +732,57 @@ pub enum DispatchPayload {
 
     AutoModerationActionExecution(JsonMap),
 
-    ChannelCreate(JsonMap),
+    ChannelCreate(ChannelObject),
 
-    ChannelUpdate(JsonMap),
+    ChannelUpdate(ChannelObject),
 
-    ChannelDelete(JsonMap),
+    ChannelDelete(PartialChannelObject),
 
-    ChannelPinsUpdate(JsonMap),
+    ChannelPinsUpdate(ChannelPinsUpdateObject),
 
-    ThreadCreate(JsonMap),
+    ThreadCreate(ThreadObject),
 
-    ThreadUpdate(JsonMap),
+    ThreadUpdate(ThreadObject),
 
-    ThreadDelete(JsonMap),
+    ThreadDelete(PartialChannelObject),
 
-    ThreadListSync(JsonMap),
+    ThreadListSync(ThreadListSyncObject),
 
-    ThreadMemberUpdate(JsonMap),
+    ThreadMemberUpdate(ThreadMemberUpdateObject),
 
-    ThreadMembersUpdate(JsonMap),
+    ThreadMembersUpdate(ThreadMembersUpdateObject),
 
-    GuildCreate(JsonMap),
+    GuildCreate(GuildObject),
 
-    GuildUpdate(JsonMap),
+    GuildUpdate(GuildUpdateObject),
 
     GuildDelete(JsonMap),
 
-    GuildAuditLogEntryCreate(JsonMap),
+    GuildAuditLogEntryCreate(GuildAuditLogEntryCreateObject),
 
-    GuildBanAdd(JsonMap),
+    GuildBanAdd(GuildBanAddObject),
 
-    GuildBanRemove(JsonMap),
+    GuildBanRemove(GuildBanRemoveObject),
 
-    GuildEmojisUpdate(JsonMap),
+    GuildEmojisUpdate(GuildEmojisUpdateObject),
 
-    GuildStickersUpdate(JsonMap),
+    GuildStickersUpdate(GuildStickersUpdateObject),
 
-    GuildIntegrationsUpdate(JsonMap),
+    GuildIntegrationsUpdate(GuildIntegrationsUpdateObject),
 
-    GuildMemberAdd(JsonMap),
+    GuildMemberAdd(GuildMemberAddObject),
 
-    GuildMemberRemove(JsonMap),
+    GuildMemberRemove(GuildMemberRemoveObject),
 
-    GuildMemberUpdate(JsonMap),
+    GuildMemberUpdate(GuildMemberUpdateObject),
 
-    GuildMembersChunk(JsonMap),
+    GuildMembersChunk(GuildMembersChunkObject),
 
-    GuildRoleCreate(JsonMap),
+    GuildRoleCreate(GuildRoleCreateObject),
 
-    GuildRoleUpdate(JsonMap),
+    GuildRoleUpdate(GuildRoleUpdateObject),
 
-    GuildRoleDelete(JsonMap),
+    GuildRoleDelete(GuildRoleDeleteObject),
 
     GuildScheduledEventCreate(JsonMap),
 
@@ -393,11 +790,11 @@ pub enum DispatchPayload {
 
     GuildScheduledEventDelete(JsonMap),
 
-    GuildScheduledEventUserAdd(JsonMap),
+    GuildScheduledEventUserAdd(GuildScheduledEventUserAddObject),
 
-    GuildScheduledEventUserRemove(JsonMap),
+    GuildScheduledEventUserRemove(GuildScheduledEventUserRemoveObject),
 
-    InteractionCreate(JsonMap),
+    InteractionCreate(InteractionObject),
 
     IntegrationCreate(JsonMap),
 
@@ -411,21 +808,21 @@ pub enum DispatchPayload {
 
     MessageCreate(JsonMap),
 
-    MessageUpdate(JsonMap),
+    MessageUpdate(MessageUpdateObject),
 
-    MessageDelete(JsonMap),
+    MessageDelete(MessageDeleteObject),
 
-    MessageDeleteBulk(JsonMap),
+    MessageDeleteBulk(MessageDeleteBulkObject),
 
     MessageReactionAdd(JsonMap),
 
     MessageReactionRemove(JsonMap),
 
-    MessageReactionRemoveAll(JsonMap),
+    MessageReactionRemoveAll(MessageReactionRemoveAllObject),
 
-    MessageReactionRemoveEmoji(JsonMap),
+    MessageReactionRemoveEmoji(MessageReactionRemoveEmojiObject),
 
-    PresenceUpdate(JsonMap),
+    PresenceUpdate(PresenceObject),
 
     StageInstanceCreate(JsonMap),
 
@@ -435,14 +832,15 @@ pub enum DispatchPayload {
 
     TypingStart(JsonMap),
 
-    UserUpdate(JsonMap),
+    UserUpdate(UserUpdateObject),
 
     VoiceStateUpdate(JsonMap),
 
-    VoiceServerUpdate(JsonMap),
+    VoiceServerUpdate(VoiceServerUpdateObject),
 
-    WebhooksUpdate(JsonMap),
+    WebhooksUpdate(WebhooksUpdateObject),
 
+    /// An event this version doesn't know about, with its name and `d` field.
     Unknown(String, JsonMap),
 }
 
@@ -494,7 +892,7 @@ pub struct ResumeData {
 
     pub session_id: String,
 
-    pub seq: u64,
+    pub seq: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -520,7 +918,8 @@ pub struct RequestGuildMembersData {
 pub struct VoiceStateUpdateData {
     pub guild_id: Snowflake,
 
-    pub channel_id: Snowflake,
+    /// The voice channel to join, `None` disconnects from the current one.
+    pub channel_id: Option<Snowflake>,
 
     pub self_mute: bool,
 
@@ -539,8 +938,9 @@ pub struct UpdatePresenceData {
     pub afk: bool,
 }
 
-#[derive(Debug, Clone, EnumString, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Serialize, Deserialize)]
 #[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum PresenceStateType {
     Online,
     Dnd,
@@ -606,16 +1006,18 @@ impl Serialize for GatewaySendPayload {
 }
 
 impl GatewayReceivePayload {
-    pub fn unpack(str: String) -> Self {
-        let mut payload: JsonMap = Value::from_str(&str).and_then(from_value).unwrap();
+    /// Parses a payload received from the gateway, the unknown opcodes and events are kept as they
+    /// were received.
+    pub fn unpack(str: String) -> Result<Self, JsonError> {
+        let mut payload: JsonMap = Value::from_str(&str).and_then(from_value)?;
 
-        let op = to_value!(payload, op);
+        let raw_op = to_value!(payload, op);
 
-        let Some(op) = FromPrimitive::from_u64(op) else {
-            return Self::UnknownOp(op, payload);
+        let Some(op) = FromPrimitive::from_u64(raw_op) else {
+            return Ok(Self::UnknownOp(raw_op, payload));
         };
 
-        match op {
+        Ok(match op {
             GatewayOpcode::Hello => {
                 let mut d: JsonMap = to_value!(payload, d);
 
@@ -625,20 +1027,33 @@ impl GatewayReceivePayload {
             GatewayOpcode::HeartbeatAck => Self::HeartbeatAck,
             GatewayOpcode::InvalidSession => Self::InvalidSession(to_value!(payload, d)),
             GatewayOpcode::Reconnect => Self::Reconnect,
-            GatewayOpcode::Dispatch => Self::Dispatch(DispatchPayload::from_payload(payload)),
-            _ => unreachable!("not receive op"),
-        }
+            GatewayOpcode::Dispatch => Self::Dispatch(DispatchPayload::from_payload(payload)?),
+            // Send-only opcodes are never expected from Discord, let the handler inspect them.
+            _ => Self::UnknownOp(raw_op, payload),
+        })
     }
 }
 
 impl DispatchPayload {
-    pub fn from_payload(mut payload: JsonMap) -> (u64, Self) {
+    /// The name of the event, e.g. `GUILD_CREATE`, or `UNKNOWN` for unknown events.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
+
+    /// Parses the sequence number and the data of a dispatch payload.
+    pub fn from_payload(mut payload: JsonMap) -> Result<(i64, Self), JsonError> {
         let s = to_value!(payload, s);
 
         let event_str: String = to_value!(payload, t);
 
         let Ok(event) = GatewayDispatchEvents::from_str(&event_str) else {
-            return (s, Self::Unknown(event_str, payload));
+            let data = match payload.remove("d") {
+                Some(Value::Object(data)) => data,
+                _ => JsonMap::new(),
+            };
+
+            return Ok((s, Self::Unknown(event_str, data)));
         };
 
         macro_rules! event_arms {
@@ -651,7 +1066,7 @@ impl DispatchPayload {
             }
         }
 
-        (
+        Ok((
             s,
             event_arms! {
                 ApplicationCommandPermissionsUpdate,
@@ -714,6 +1129,6 @@ impl DispatchPayload {
                 AutoModerationActionExecution,
                 GuildAuditLogEntryCreate,
             },
-        )
+        ))
     }
 }
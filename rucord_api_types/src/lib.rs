@@ -1,11 +1,20 @@
 #[macro_use]
 mod macros;
 
-pub type Snowflake = String;
+/// The Discord API version used by the REST and gateway clients.
+pub const DISCORD_API_VERSION: u8 = 10;
 
+pub mod events;
+pub mod formatting;
 pub mod gateway;
+pub mod rest;
 pub mod routes;
+pub mod snowflake;
 pub mod structures;
 
+pub use events::*;
+pub use formatting::*;
 pub use gateway::*;
+pub use rest::*;
+pub use snowflake::*;
 pub use structures::*;
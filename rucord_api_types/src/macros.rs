@@ -1,9 +1,65 @@
+/// Takes the `$key` field out of the map and deserializes it, returning early with the error when
+/// it's missing or has another type.
 macro_rules! to_value {
     ($map:ident, $key:ident) => {
-        serde_json::from_value(
-            $map.remove(stringify!($key))
-                .expect(format!("expected `{}` field", stringify!($key)).as_str()),
-        )
-        .expect("Invalid field type")
+        serde_json::from_value($map.remove(stringify!($key)).ok_or_else(|| {
+            <serde_json::Error as serde::de::Error>::missing_field(stringify!($key))
+        })?)?
+    };
+}
+
+/// Declares an enum (de)serialized as its `$repr` value, the values added to the API after this
+/// version are kept in the `Unknown` variant instead of failing the whole payload.
+macro_rules! repr_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $Name:ident: $repr:ty {
+            $($(#[$variant_meta:meta])* $Variant:ident = $value:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $Name {
+            $($(#[$variant_meta])* $Variant,)+
+
+            /// A value this version doesn't know about.
+            Unknown($repr),
+        }
+
+        impl From<$repr> for $Name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $($value => Self::$Variant,)+
+                    value => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<&$Name> for $repr {
+            fn from(value: &$Name) -> Self {
+                match value {
+                    $($Name::$Variant => $value,)+
+                    $Name::Unknown(value) => *value,
+                }
+            }
+        }
+
+        impl From<$Name> for $repr {
+            #[inline]
+            fn from(value: $Name) -> Self {
+                Self::from(&value)
+            }
+        }
+
+        impl serde::Serialize for $Name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$repr>::from(self).serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $Name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$repr>::deserialize(deserializer).map(Self::from)
+            }
+        }
     };
 }
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    ActionRowObject, AllowedMentionsObject, InviteTargetType, MessageObject,
+    MessageReferenceObject, Snowflake, ValidationError,
+};
+
+/// The body of a Create Message request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#create-message-jsonform-params).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateMessageBody {
+    /// Message contents (up to 2000 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// true if this is a TTS message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    // TODO: When write EmbedObject.
+    /// Up to 10 rich embeds (up to 6000 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Value>>,
+
+    /// Allowed mentions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsObject>,
+
+    /// Include to make your message a reply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReferenceObject>,
+
+    /// Components to include with the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ActionRowObject>>,
+
+    /// [Message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+}
+
+impl CreateMessageBody {
+    #[inline]
+    pub fn builder() -> CreateMessageBodyBuilder {
+        CreateMessageBodyBuilder::default()
+    }
+}
+
+/// Builds a [`CreateMessageBody`], `rucord_rest::SendMessageExt` sends it to its channel.
+#[derive(Debug, Clone, Default)]
+pub struct CreateMessageBodyBuilder {
+    body: CreateMessageBody,
+
+    channel_id: Option<Snowflake>,
+}
+
+impl CreateMessageBodyBuilder {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The channel to send the message to, only used when sending from the builder.
+    #[inline]
+    #[must_use]
+    pub fn channel_id(mut self, channel_id: &Snowflake) -> Self {
+        self.channel_id = Some(*channel_id);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.body.content = Some(content.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn tts(mut self, tts: bool) -> Self {
+        self.body.tts = Some(tts);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn embeds(mut self, embeds: Vec<Value>) -> Self {
+        self.body.embeds = Some(embeds);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn reply_to(mut self, message_id: &Snowflake) -> Self {
+        self.body.message_reference = Some(MessageReferenceObject::reply_to(message_id));
+        self
+    }
+
+    /// Replies to the message, see [`MessageObject::to_reference`].
+    #[inline]
+    #[must_use]
+    pub fn reply_to_message(mut self, message: &MessageObject) -> Self {
+        self.body.message_reference = Some(message.to_reference());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentionsObject) -> Self {
+        self.body.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Doesn't ping anyone, including the author of the replied message.
+    #[inline]
+    #[must_use]
+    pub fn suppress_pings(self) -> Self {
+        self.allowed_mentions(AllowedMentionsObject::none())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn components(mut self, components: Vec<ActionRowObject>) -> Self {
+        self.body.components = Some(components);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn flags(mut self, flags: u64) -> Self {
+        self.body.flags = Some(flags);
+        self
+    }
+
+    /// Fails with [`ValidationError::EmptyMessage`] if none of `content`, `embeds` or
+    /// `components` is set.
+    pub fn build(self) -> Result<CreateMessageBody, ValidationError> {
+        if self.body.content.is_none()
+            && self.body.embeds.is_none()
+            && self.body.components.is_none()
+        {
+            return Err(ValidationError::EmptyMessage);
+        }
+
+        Ok(self.body)
+    }
+
+    /// Builds the body along with the channel set by [`channel_id`](Self::channel_id), see
+    /// [`build`](Self::build).
+    pub fn build_for_channel(
+        self,
+    ) -> Result<(Option<Snowflake>, CreateMessageBody), ValidationError> {
+        let channel_id = self.channel_id;
+        Ok((channel_id, self.build()?))
+    }
+}
+
+/// The body of an Edit Message request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#edit-message-jsonform-params).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditMessageBody {
+    /// Message contents (up to 2000 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    // TODO: When write EmbedObject.
+    /// Up to 10 rich embeds (up to 6000 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Value>>,
+
+    /// [Message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+
+    /// Allowed mentions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsObject>,
+}
+
+/// The body of a Create Channel Invite request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#create-channel-invite-json-params).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateChannelInviteBody {
+    /// Duration of invite in seconds before expiry, or 0 for never. between 0 and 604800 (7 days).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_age: Option<u32>,
+
+    /// Max number of uses or 0 for unlimited. between 0 and 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u32>,
+
+    /// Whether this invite only grants temporary membership.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temporary: Option<bool>,
+
+    /// If true, don't try to reuse a similar invite (useful for creating many unique one time use invites).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique: Option<bool>,
+
+    /// The [type of target](https://discord.com/developers/docs/resources/invite#invite-object-invite-target-types) for this voice channel invite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<InviteTargetType>,
+
+    /// The id of the user whose stream to display for this invite, required if `target_type` is `Stream`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_user_id: Option<Snowflake>,
+}
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    GuildScheduledEventEntityMetadata, GuildScheduledEventEntityType,
+    GuildScheduledEventPrivacyLevel, Snowflake,
+};
+
+/// The query of a Get Guild Prune Count request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#get-guild-prune-count-query-string-params).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetPruneCountQuery {
+    /// Number of days to count prune for (1-30).
+    pub days: u8,
+
+    /// Role(s) to include.
+    #[serde(default)]
+    pub include_roles: Vec<Snowflake>,
+}
+
+impl Default for GetPruneCountQuery {
+    fn default() -> Self {
+        Self {
+            days: 7,
+            include_roles: vec![],
+        }
+    }
+}
+
+/// The query of a Search Guild Members request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#search-guild-members-query-string-params).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchGuildMembersQuery {
+    /// Query string to match username(s) and nickname(s) against.
+    pub query: String,
+
+    /// Max number of members to return (1-1000), defaults to 1.
+    #[serde(default)]
+    pub limit: Option<u16>,
+}
+
+impl SearchGuildMembersQuery {
+    #[inline]
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            limit: None,
+        }
+    }
+}
+
+/// The body of a Begin Guild Prune request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#begin-guild-prune-json-params).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeginPruneBody {
+    /// Number of days to prune (1-30).
+    pub days: u8,
+
+    /// Whether `pruned` is returned, discouraged for large guilds.
+    pub compute_prune_count: bool,
+
+    /// Role(s) to include.
+    #[serde(default)]
+    pub include_roles: Vec<Snowflake>,
+
+    /// Sent as the audit log reason.
+    #[serde(skip)]
+    pub reason: Option<String>,
+}
+
+impl Default for BeginPruneBody {
+    fn default() -> Self {
+        Self {
+            days: 7,
+            compute_prune_count: true,
+            include_roles: vec![],
+            reason: None,
+        }
+    }
+}
+
+/// The response of the Get Guild Prune Count and Begin Guild Prune requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PruneCountObject {
+    /// The number of members that would be (or were) removed, null when `compute_prune_count` is false.
+    pub pruned: Option<u32>,
+}
+
+/// The body of a Create Guild Emoji request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/emoji#create-guild-emoji-json-params).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEmojiBody {
+    /// Name of the emoji.
+    pub name: String,
+
+    /// The 128x128 emoji image, as a base64 [data URI](https://discord.com/developers/docs/reference#image-data).
+    pub image: String,
+
+    /// Roles allowed to use this emoji.
+    #[serde(default)]
+    pub roles: Vec<Snowflake>,
+}
+
+/// The body of a Modify Guild Sticker request, only the set fields are changed.
+/// [Discord documentation](https://discord.com/developers/docs/resources/sticker#modify-guild-sticker-json-params).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModifyStickerBody {
+    /// Name of the sticker (2-30 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Description of the sticker (2-100 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Autocomplete/suggestion tags for the sticker (max 200 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+}
+
+/// The body of a Modify Guild Member request, only the set fields are changed.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#modify-guild-member-json-params).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModifyGuildMemberBody {
+    /// Value to set user's nickname to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nick: Option<String>,
+
+    /// Array of role ids the member is assigned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<Snowflake>>,
+
+    /// Whether the user is muted in voice channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mute: Option<bool>,
+
+    /// Whether the user is deafened in voice channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deaf: Option<bool>,
+
+    /// Id of channel to move user to (if they are connected to voice).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+
+    /// ISO 8601 timestamp when the user's timeout will expire (up to 28 days in the future).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub communication_disabled_until: Option<String>,
+
+    /// [Guild member flags](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+}
+
+/// The query of a List Scheduled Events for Guild request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#list-scheduled-events-for-guild-query-string-params).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetGuildScheduledEventsQuery {
+    /// Include number of users subscribed to each event.
+    #[serde(default)]
+    pub with_user_count: Option<bool>,
+}
+
+/// The body of a Create Guild Scheduled Event request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#create-guild-scheduled-event-json-params).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateGuildScheduledEventBody {
+    /// The channel id of the scheduled event, optional for `External` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+
+    /// The entity metadata of the scheduled event, required for `External` events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+
+    /// The name of the scheduled event.
+    pub name: String,
+
+    /// The privacy level of the scheduled event.
+    pub privacy_level: GuildScheduledEventPrivacyLevel,
+
+    /// The ISO 8601 time to schedule the scheduled event.
+    pub scheduled_start_time: String,
+
+    /// The ISO 8601 time when the scheduled event is scheduled to end, required for `External`
+    /// events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_end_time: Option<String>,
+
+    /// The description of the scheduled event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The entity type of the scheduled event.
+    pub entity_type: GuildScheduledEventEntityType,
+
+    /// The cover image of the scheduled event, as a
+    /// [data URI](https://discord.com/developers/docs/reference#image-data).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
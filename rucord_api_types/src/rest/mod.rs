@@ -0,0 +1,9 @@
+mod channel;
+mod guild;
+mod user;
+mod validation;
+
+pub use channel::*;
+pub use guild::*;
+pub use user::*;
+pub use validation::*;
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Snowflake;
+
+/// The body of a Create DM request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/user#create-dm-json-params).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDmBody {
+    /// The recipient to open a DM channel with.
+    pub recipient_id: Snowflake,
+}
@@ -0,0 +1,43 @@
+use std::ops::RangeInclusive;
+
+use derive_more::{Display, Error};
+
+/// A request argument Discord would reject, reported before sending the request.
+#[derive(Debug, Clone, PartialEq, Eq, Error, Display)]
+pub enum ValidationError {
+    #[display(fmt = "`{field}` must be between {min} and {max}, got {value}")]
+    OutOfRange {
+        field: &'static str,
+        value: u64,
+        min: u64,
+        max: u64,
+    },
+    #[display(fmt = "a message must have either content, embeds or components")]
+    EmptyMessage,
+    #[display(fmt = "`{field}` must be set")]
+    Missing { field: &'static str },
+    #[display(fmt = "`{field}` can't be empty")]
+    Empty { field: &'static str },
+}
+
+impl ValidationError {
+    /// Checks that the `field` argument is in `range`.
+    pub fn check_range(
+        field: &'static str,
+        value: impl Into<u64>,
+        range: RangeInclusive<u64>,
+    ) -> Result<(), Self> {
+        let value = value.into();
+
+        if range.contains(&value) {
+            Ok(())
+        } else {
+            Err(Self::OutOfRange {
+                field,
+                value,
+                min: *range.start(),
+                max: *range.end(),
+            })
+        }
+    }
+}
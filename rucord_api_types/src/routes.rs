@@ -1,29 +1,172 @@
+use crate::Snowflake;
+
+/// Generates a function formatting each route, and a module of the same name whose
+/// `route_name()` returns the route template, e.g. `routes::gateway_bot::route_name()`.
 macro_rules! create_routes {
-    ($name:ident => $ret:literal $($tt:tt)*) => {
-        create_routes! {
-            $name() => $ret
-            $($tt)*
-        }
+    ($($name:ident $(($($param_name:ident: $param_ty:ty),* $(,)?))? => $ret:literal)*) => {
+        $(
+            #[inline(always)]
+            pub fn $name($($($param_name: $param_ty),*)?) -> String {
+                format!($ret)
+            }
+
+            pub mod $name {
+                /// The unformatted template of this route, useful as a rate limit bucket key.
+                #[inline(always)]
+                pub const fn route_name() -> &'static str {
+                    $ret
+                }
+            }
+        )*
+
+        const _: () = assert!(
+            !has_duplicates(&[$(stringify!($name)),*]),
+            "create_routes! got two routes with the same name"
+        );
+
+        const ROUTE_NAMES: &[&str] = &[$($ret),*];
     };
+}
+
+/// The template of the route the formatted `path` was made from, e.g. `/channels/{channel_id}`
+/// for `/channels/123`. When several templates match, the one with the most literal segments
+/// wins, so `/guilds/1/members/search` isn't taken for a member id.
+pub fn route_name_of(path: &str) -> Option<&'static str> {
+    ROUTE_NAMES
+        .iter()
+        .filter_map(|template| Some((literal_segments(template, path)?, *template)))
+        .max_by_key(|(literals, _)| *literals)
+        .map(|(_, template)| template)
+}
+
+/// How many segments of `path` match the literal segments of `template`, `None` if `path`
+/// doesn't match it.
+fn literal_segments(template: &str, path: &str) -> Option<usize> {
+    let (template, path) = (template.split('/'), path.split('/'));
+
+    if template.clone().count() != path.clone().count() {
+        return None;
+    }
+
+    let mut literals = 0;
 
-    ($name:ident($($param_name:path: $param_ty:ty),* $(,)?) => $ret:literal $($tt:tt)*) => {
-        #[inline(always)]
-        pub fn $name($(param_name: $param_ty),*) -> String {
-            format!($ret)
+    for (expected, segment) in template.zip(path) {
+        if expected.starts_with('{') && expected.ends_with('}') {
+            continue;
         }
 
-        create_routes! {
-            $($tt)*
+        if expected != segment {
+            return None;
         }
 
+        literals += 1;
+    }
 
-    };
+    Some(literals)
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
 
-    () => {}
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn has_duplicates(names: &[&str]) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        let mut j = i + 1;
+        while j < names.len() {
+            if str_eq(names[i], names[j]) {
+                return true;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    false
 }
 
 create_routes! {
     gateway => "/gateway"
 
     gateway_bot => "/gateway/bot"
+
+    current_application => "/applications/@me"
+
+    application_commands(application_id: &Snowflake) => "/applications/{application_id}/commands"
+
+    current_user => "/users/@me"
+
+    current_user_channels => "/users/@me/channels"
+
+    channel_messages(channel_id: &Snowflake) => "/channels/{channel_id}/messages"
+
+    channel_message(channel_id: &Snowflake, message_id: &Snowflake) => "/channels/{channel_id}/messages/{message_id}"
+
+    channel_invites(channel_id: &Snowflake) => "/channels/{channel_id}/invites"
+
+    channel_typing(channel_id: &Snowflake) => "/channels/{channel_id}/typing"
+
+    channel_webhooks(channel_id: &Snowflake) => "/channels/{channel_id}/webhooks"
+
+    guild_prune(guild_id: &Snowflake) => "/guilds/{guild_id}/prune"
+
+    guild_widget_settings(guild_id: &Snowflake) => "/guilds/{guild_id}/widget"
+
+    guild_widget(guild_id: &Snowflake) => "/guilds/{guild_id}/widget.json"
+
+    guild_invites(guild_id: &Snowflake) => "/guilds/{guild_id}/invites"
+
+    guild_members(guild_id: &Snowflake) => "/guilds/{guild_id}/members"
+
+    guild_roles(guild_id: &Snowflake) => "/guilds/{guild_id}/roles"
+
+    guild_members_search(guild_id: &Snowflake) => "/guilds/{guild_id}/members/search"
+
+    guild_member(guild_id: &Snowflake, user_id: &Snowflake) => "/guilds/{guild_id}/members/{user_id}"
+
+    guild_bans(guild_id: &Snowflake) => "/guilds/{guild_id}/bans"
+
+    guild_emojis(guild_id: &Snowflake) => "/guilds/{guild_id}/emojis"
+
+    guild_emoji(guild_id: &Snowflake, emoji_id: &Snowflake) => "/guilds/{guild_id}/emojis/{emoji_id}"
+
+    guild_integrations(guild_id: &Snowflake) => "/guilds/{guild_id}/integrations"
+
+    guild_integration(guild_id: &Snowflake, integration_id: &Snowflake) => "/guilds/{guild_id}/integrations/{integration_id}"
+
+    guild_webhooks(guild_id: &Snowflake) => "/guilds/{guild_id}/webhooks"
+
+    guild_stickers(guild_id: &Snowflake) => "/guilds/{guild_id}/stickers"
+
+    guild_sticker(guild_id: &Snowflake, sticker_id: &Snowflake) => "/guilds/{guild_id}/stickers/{sticker_id}"
+
+    guild_scheduled_events(guild_id: &Snowflake) => "/guilds/{guild_id}/scheduled-events"
+
+    guild_scheduled_event_users(guild_id: &Snowflake, event_id: &Snowflake) => "/guilds/{guild_id}/scheduled-events/{event_id}/users"
+
+    sticker(sticker_id: &Snowflake) => "/stickers/{sticker_id}"
+
+    sticker_packs => "/sticker-packs"
+
+    interaction_callback(interaction_id: &Snowflake, token: &str) => "/interactions/{interaction_id}/{token}/callback"
+
+    webhook(webhook_id: &Snowflake) => "/webhooks/{webhook_id}"
+
+    webhook_token(webhook_id: &Snowflake, token: &str) => "/webhooks/{webhook_id}/{token}"
+
+    webhook_token_message(webhook_id: &Snowflake, token: &str, message_id: &Snowflake) => "/webhooks/{webhook_id}/{token}/messages/{message_id}"
 }
@@ -0,0 +1,112 @@
+use core::{fmt, str::FromStr};
+
+use derive_more::{Display, Error};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The first second of 2015, the epoch Discord snowflakes are relative to, in milliseconds.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// A Discord unique id, sent as a string of a 64-bit unsigned integer.
+/// [Discord documentation](https://discord.com/developers/docs/reference#snowflakes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+#[display(fmt = "{_0}")]
+pub struct Snowflake(u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error, Display)]
+pub enum SnowflakeError {
+    #[display(fmt = "a snowflake can't be empty")]
+    Empty,
+    #[display(fmt = "a snowflake can only contain ASCII digits")]
+    NotNumeric,
+    #[display(fmt = "a snowflake can't be larger than a 64-bit unsigned integer")]
+    TooLarge,
+}
+
+impl Snowflake {
+    #[inline]
+    pub const fn new(id: u64) -> Self {
+        Self(id)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, SnowflakeError> {
+        if s.is_empty() {
+            return Err(SnowflakeError::Empty);
+        }
+
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(SnowflakeError::NotNumeric);
+        }
+
+        s.parse().map(Self).map_err(|_| SnowflakeError::TooLarge)
+    }
+
+    #[inline]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// The Unix timestamp in milliseconds at which this snowflake was created.
+    #[inline]
+    pub const fn timestamp(self) -> u64 {
+        (self.0 >> 22) + DISCORD_EPOCH
+    }
+}
+
+impl FromStr for Snowflake {
+    type Err = SnowflakeError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl From<u64> for Snowflake {
+    #[inline]
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<Snowflake> for u64 {
+    #[inline]
+    fn from(id: Snowflake) -> Self {
+        id.0
+    }
+}
+
+impl Serialize for Snowflake {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Snowflake {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SnowflakeVisitor;
+
+        impl<'de> de::Visitor<'de> for SnowflakeVisitor {
+            type Value = Snowflake;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a snowflake string")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Snowflake::parse(v).map_err(|e| E::custom(format!("invalid snowflake `{v}`: {e}")))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Snowflake(v))
+            }
+        }
+
+        deserializer.deserialize_any(SnowflakeVisitor)
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Snowflake, UserObject};
+
+/// Represents a Discord Application Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/application#application-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationObject {
+    /// The id of the app.
+    pub id: Snowflake,
+
+    /// The name of the app.
+    pub name: String,
+
+    /// The [icon hash](https://discord.com/developers/docs/reference#image-formatting) of the app.
+    pub icon: Option<String>,
+
+    /// The description of the app.
+    pub description: String,
+
+    /// When false only app owner can join the app's bot to guilds.
+    pub bot_public: bool,
+
+    /// When true the app's bot will only join upon completion of the full oauth2 code grant flow.
+    pub bot_require_code_grant: bool,
+
+    /// Partial user object containing info on the owner of the application.
+    pub owner: UserObject,
+
+    // TODO: When write TeamObject.
+    /// If the application belongs to a team, this will be a list of the members of that team.
+    #[serde(default)]
+    pub team: Option<Value>,
+
+    /// The application's public [flags](https://discord.com/developers/docs/resources/application#application-object-application-flags).
+    #[serde(default)]
+    pub flags: u32,
+}
+
+/// Represents a Discord Application Command Permission Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permissions-structure).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationCommandPermission {
+    /// Id of the role, user, or channel. It can also be a
+    /// [permission constant](https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permissions-constants).
+    pub id: Snowflake,
+
+    /// The type of the permission.
+    #[serde(rename = "type")]
+    pub ty: ApplicationCommandPermissionType,
+
+    /// true to allow, false, to disallow.
+    pub permission: bool,
+}
+
+repr_enum! {
+    /// Represents an Application Command Permission Type.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permission-type).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ApplicationCommandPermissionType: u8 {
+        Role = 1,
+        User = 2,
+        Channel = 3,
+    }
+}
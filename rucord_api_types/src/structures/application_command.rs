@@ -0,0 +1,195 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Number;
+
+use crate::{ChannelType, Snowflake};
+
+/// The localized names or descriptions, keyed by [locale](https://discord.com/developers/docs/reference#locales),
+/// e.g. `en-US` or `fr`.
+pub type Localizations = HashMap<String, String>;
+
+/// Represents a Discord Application Command Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-object).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationCommandObject {
+    /// Unique ID of command.
+    pub id: Snowflake,
+
+    /// [Type of command](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types), defaults to `ChatInput`.
+    #[serde(rename = "type", default)]
+    pub ty: ApplicationCommandType,
+
+    /// ID of the parent application.
+    pub application_id: Snowflake,
+
+    /// Guild ID of the command, if not global.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// Name of command, 1-32 characters.
+    pub name: String,
+
+    /// Localization dictionary for the `name` field, only sent when the localizations are
+    /// requested.
+    #[serde(default)]
+    pub name_localizations: Option<Localizations>,
+
+    /// Description for `ChatInput` commands, 1-100 characters. Empty string for `User` and
+    /// `Message` commands.
+    pub description: String,
+
+    /// Localization dictionary for the `description` field, only sent when the localizations
+    /// are requested.
+    #[serde(default)]
+    pub description_localizations: Option<Localizations>,
+
+    /// Parameters for the command, max of 25.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandOption>,
+
+    /// Set of [permissions](https://discord.com/developers/docs/topics/permissions) represented
+    /// as a bit set.
+    #[serde(default)]
+    pub default_member_permissions: Option<String>,
+
+    /// Indicates whether the command is available in DMs with the app, only for globally-scoped
+    /// commands.
+    #[serde(default)]
+    pub dm_permission: Option<bool>,
+
+    /// Indicates whether the command is age-restricted.
+    #[serde(default)]
+    pub nsfw: bool,
+
+    /// Autoincrementing version identifier updated during substantial record changes.
+    pub version: Snowflake,
+}
+
+repr_enum! {
+    /// Represents an Application Command Type.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-types).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum ApplicationCommandType: u8 {
+        /// Slash commands, a text-based command that shows up when a user types `/`.
+        #[default]
+        ChatInput = 1,
+
+        /// A UI-based command that shows up when you right click or tap on a user.
+        User = 2,
+
+        /// A UI-based command that shows up when you right click or tap on a message.
+        Message = 3,
+    }
+}
+
+/// Represents a Discord Application Command Option.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationCommandOption {
+    /// Type of option.
+    #[serde(rename = "type")]
+    pub ty: ApplicationCommandOptionType,
+
+    /// 1-32 character name.
+    pub name: String,
+
+    /// Localization dictionary for the `name` field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+
+    /// 1-100 character description.
+    pub description: String,
+
+    /// Localization dictionary for the `description` field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<Localizations>,
+
+    /// If the parameter is required or optional, defaults to `false`.
+    #[serde(default)]
+    pub required: bool,
+
+    /// Choices for `String`, `Integer`, and `Number` types for the user to pick from, max 25.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<ApplicationCommandOptionChoice>,
+
+    /// If the option is a subcommand or subcommand group type, these nested options will be the
+    /// parameters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandOption>,
+
+    /// If the option is a channel type, the channels shown will be restricted to these types.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+
+    /// If the option is an `Integer` or `Number` type, the minimum value permitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<Number>,
+
+    /// If the option is an `Integer` or `Number` type, the maximum value permitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<Number>,
+
+    /// For option type `String`, the minimum allowed length (minimum of 0, maximum of 6000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+
+    /// For option type `String`, the maximum allowed length (minimum of 1, maximum of 6000).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+
+    /// If autocomplete interactions are enabled for this `String`, `Integer`, or `Number` type
+    /// option.
+    #[serde(default)]
+    pub autocomplete: bool,
+}
+
+repr_enum! {
+    /// Represents an Application Command Option Type.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-type).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ApplicationCommandOptionType: u8 {
+        SubCommand = 1,
+        SubCommandGroup = 2,
+        String = 3,
+
+        /// Any integer between -2^53 and 2^53.
+        Integer = 4,
+        Boolean = 5,
+        User = 6,
+
+        /// Includes all channel types + categories.
+        Channel = 7,
+        Role = 8,
+
+        /// Includes users and roles.
+        Mentionable = 9,
+
+        /// Any double between -2^53 and 2^53.
+        Number = 10,
+        Attachment = 11,
+    }
+}
+
+/// Represents a Discord Application Command Option Choice.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-choice-structure).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApplicationCommandOptionChoice {
+    /// 1-100 character choice name.
+    pub name: String,
+
+    /// Localization dictionary for the `name` field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<Localizations>,
+
+    /// Value for the choice, up to 100 characters if string.
+    pub value: ApplicationCommandOptionChoiceValue,
+}
+
+/// The value of a choice, its type matches the type of the option.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApplicationCommandOptionChoiceValue {
+    String(String),
+    Number(Number),
+}
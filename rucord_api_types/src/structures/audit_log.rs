@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Snowflake;
+
+/// Represents a Discord Audit Log Entry Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLogEntryObject {
+    /// Id of the entry.
+    pub id: Snowflake,
+
+    /// [Type of action](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events) that occurred.
+    pub action_type: AuditLogEvent,
+
+    /// User or app that made the changes.
+    pub user_id: Option<Snowflake>,
+
+    /// Id of the affected entity (webhook, user, role, etc.).
+    pub target_id: Option<String>,
+
+    /// Changes made to the `target_id`.
+    #[serde(default)]
+    pub changes: Option<Vec<AuditLogChange>>,
+
+    /// Additional info for certain event types.
+    #[serde(default)]
+    pub options: Option<AuditLogEntryInfo>,
+
+    /// Reason for the change (1-512 characters).
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Represents a Discord Audit Log Change Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/audit-log#audit-log-change-object).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogChange {
+    /// Name of the changed entity, with a few [exceptions](https://discord.com/developers/docs/resources/audit-log#audit-log-change-object-audit-log-change-exceptions).
+    pub key: String,
+
+    /// Old value of the key.
+    #[serde(default)]
+    pub old_value: Option<Value>,
+
+    /// New value of the key.
+    #[serde(default)]
+    pub new_value: Option<Value>,
+}
+
+/// Represents a Discord Optional Audit Entry Info.
+/// [Discord documentation](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-optional-audit-entry-info).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditLogEntryInfo {
+    /// Id of the app whose permissions were targeted.
+    #[serde(default)]
+    pub application_id: Option<Snowflake>,
+
+    /// Name of the Auto Moderation rule that was triggered.
+    #[serde(default)]
+    pub auto_moderation_rule_name: Option<String>,
+
+    /// Trigger type of the Auto Moderation rule that was triggered.
+    #[serde(default)]
+    pub auto_moderation_rule_trigger_type: Option<String>,
+
+    /// Channel in which the entities were targeted.
+    #[serde(default)]
+    pub channel_id: Option<Snowflake>,
+
+    /// Number of entities that were targeted.
+    #[serde(default)]
+    pub count: Option<String>,
+
+    /// Number of days after which inactive members were kicked.
+    #[serde(default)]
+    pub delete_member_days: Option<String>,
+
+    /// Id of the overwritten entity.
+    #[serde(default)]
+    pub id: Option<Snowflake>,
+
+    /// Number of members removed by the prune.
+    #[serde(default)]
+    pub members_removed: Option<String>,
+
+    /// Id of the message that was targeted.
+    #[serde(default)]
+    pub message_id: Option<Snowflake>,
+
+    /// Name of the role if type is "0" (not present if type is "1").
+    #[serde(default)]
+    pub role_name: Option<String>,
+
+    /// Type of overwritten entity - role ("0") or member ("1").
+    #[serde(rename = "type", default)]
+    pub ty: Option<String>,
+
+    /// The type of integration which performed the action.
+    #[serde(default)]
+    pub integration_type: Option<String>,
+}
+
+repr_enum! {
+    /// Represents an Audit Log Event.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-audit-log-events).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AuditLogEvent: u16 {
+        GuildUpdate = 1,
+        ChannelCreate = 10,
+        ChannelUpdate = 11,
+        ChannelDelete = 12,
+        ChannelOverwriteCreate = 13,
+        ChannelOverwriteUpdate = 14,
+        ChannelOverwriteDelete = 15,
+        MemberKick = 20,
+        MemberPrune = 21,
+        MemberBanAdd = 22,
+        MemberBanRemove = 23,
+        MemberUpdate = 24,
+        MemberRoleUpdate = 25,
+        MemberMove = 26,
+        MemberDisconnect = 27,
+        BotAdd = 28,
+        RoleCreate = 30,
+        RoleUpdate = 31,
+        RoleDelete = 32,
+        InviteCreate = 40,
+        InviteUpdate = 41,
+        InviteDelete = 42,
+        WebhookCreate = 50,
+        WebhookUpdate = 51,
+        WebhookDelete = 52,
+        EmojiCreate = 60,
+        EmojiUpdate = 61,
+        EmojiDelete = 62,
+        MessageDelete = 72,
+        MessageBulkDelete = 73,
+        MessagePin = 74,
+        MessageUnpin = 75,
+        IntegrationCreate = 80,
+        IntegrationUpdate = 81,
+        IntegrationDelete = 82,
+        StageInstanceCreate = 83,
+        StageInstanceUpdate = 84,
+        StageInstanceDelete = 85,
+        StickerCreate = 90,
+        StickerUpdate = 91,
+        StickerDelete = 92,
+        GuildScheduledEventCreate = 100,
+        GuildScheduledEventUpdate = 101,
+        GuildScheduledEventDelete = 102,
+        ThreadCreate = 110,
+        ThreadUpdate = 111,
+        ThreadDelete = 112,
+        ApplicationCommandPermissionUpdate = 121,
+        AutoModerationRuleCreate = 140,
+        AutoModerationRuleUpdate = 141,
+        AutoModerationRuleDelete = 142,
+        AutoModerationBlockMessage = 143,
+        AutoModerationFlagToChannel = 144,
+        AutoModerationUserCommunicationDisabled = 145,
+        CreatorMonetizationRequestCreated = 150,
+        CreatorMonetizationTermsAccepted = 151,
+    }
+}
@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Snowflake, UserObject};
+
+/// Represents a Discord Channel Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#channel-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelObject {
+    /// The id of this channel.
+    pub id: Snowflake,
+
+    /// The [type of channel](https://discord.com/developers/docs/resources/channel#channel-object-channel-types).
+    #[serde(rename = "type")]
+    pub ty: ChannelType,
+
+    /// The id of the guild (may be missing for some channel objects received over gateway guild dispatches).
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// Sorting position of the channel.
+    #[serde(default)]
+    pub position: Option<i32>,
+
+    // TODO: When write OverwriteObject.
+    /// Explicit permission overwrites for members and roles.
+    #[serde(default)]
+    pub permission_overwrites: Vec<Value>,
+
+    /// The name of the channel (1-100 characters).
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The channel topic (0-4096 characters for GUILD_FORUM channels, 0-1024 characters for all others).
+    #[serde(default)]
+    pub topic: Option<String>,
+
+    /// Whether the channel is nsfw.
+    #[serde(default)]
+    pub nsfw: Option<bool>,
+
+    /// The id of the last message sent in this channel (or thread for GUILD_FORUM channels).
+    #[serde(default)]
+    pub last_message_id: Option<Snowflake>,
+
+    /// The bitrate (in bits) of the voice channel.
+    #[serde(default)]
+    pub bitrate: Option<u32>,
+
+    /// The user limit of the voice channel.
+    #[serde(default)]
+    pub user_limit: Option<u32>,
+
+    /// Amount of seconds a user has to wait before sending another message (0-21600).
+    #[serde(default)]
+    pub rate_limit_per_user: Option<u32>,
+
+    /// The recipients of the DM.
+    #[serde(default)]
+    pub recipients: Option<Vec<UserObject>>,
+
+    /// Icon hash of the group DM.
+    #[serde(default)]
+    pub icon: Option<String>,
+
+    /// Id of the creator of the group DM or thread.
+    #[serde(default)]
+    pub owner_id: Option<Snowflake>,
+
+    /// For guild channels: id of the parent category for a channel, for threads: id of the text channel this thread was created.
+    #[serde(default)]
+    pub parent_id: Option<Snowflake>,
+
+    /// When the last pinned message was pinned.
+    #[serde(default)]
+    pub last_pin_timestamp: Option<String>,
+
+    /// [Voice region](https://discord.com/developers/docs/resources/voice#voice-region-object) id for the voice channel, automatic when set to null.
+    #[serde(default)]
+    pub rtc_region: Option<String>,
+
+    /// [Channel flags](https://discord.com/developers/docs/resources/channel#channel-object-channel-flags).
+    #[serde(default)]
+    pub flags: Option<u64>,
+}
+
+/// Represents a thread, a channel with the thread specific fields.
+/// [Discord documentation](https://discord.com/developers/docs/topics/threads).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadObject {
+    #[serde(flatten)]
+    pub channel: ChannelObject,
+
+    /// Number of messages (not including the initial message or deleted messages) in the thread.
+    #[serde(default)]
+    pub message_count: Option<u32>,
+
+    /// An approximate count of users in the thread, stops counting at 50.
+    #[serde(default)]
+    pub member_count: Option<u32>,
+
+    /// Thread-specific fields not needed by other channels.
+    #[serde(default)]
+    pub thread_metadata: Option<ThreadMetadataObject>,
+
+    /// The current user's thread member, only included when the user joined the thread.
+    #[serde(default)]
+    pub member: Option<ThreadMemberObject>,
+}
+
+/// Represents a Discord Thread Metadata Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#thread-metadata-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMetadataObject {
+    /// Whether the thread is archived.
+    pub archived: bool,
+
+    /// The thread will stop showing in the channel list after `auto_archive_duration` minutes of inactivity,
+    /// can be set to: 60, 1440, 4320, 10080.
+    pub auto_archive_duration: u32,
+
+    /// When the thread's archive status was last changed, used for calculating recent activity.
+    pub archive_timestamp: String,
+
+    /// Whether the thread is locked, only users with MANAGE_THREADS can unarchive it.
+    pub locked: bool,
+
+    /// Whether non-moderators can add other non-moderators to a thread, only available on private threads.
+    #[serde(default)]
+    pub invitable: Option<bool>,
+
+    /// When the thread was created, only populated for threads created after 2022-01-09.
+    #[serde(default)]
+    pub create_timestamp: Option<String>,
+}
+
+/// Represents a Discord Thread Member Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#thread-member-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThreadMemberObject {
+    /// Id of the thread, omitted in `GUILD_CREATE`.
+    #[serde(default)]
+    pub id: Option<Snowflake>,
+
+    /// Id of the user, omitted in `GUILD_CREATE`.
+    #[serde(default)]
+    pub user_id: Option<Snowflake>,
+
+    /// Time the user last joined the thread.
+    pub join_timestamp: String,
+
+    /// Any user-thread settings, currently only used for notifications.
+    pub flags: u64,
+}
+
+/// Represents a Discord Channel Object that only has the fields every channel payload includes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialChannelObject {
+    /// The id of this channel.
+    pub id: Snowflake,
+
+    /// The [type of channel](https://discord.com/developers/docs/resources/channel#channel-object-channel-types).
+    #[serde(rename = "type")]
+    pub ty: ChannelType,
+
+    /// The id of the guild.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// The name of the channel (1-100 characters).
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Id of the parent category for a channel.
+    #[serde(default)]
+    pub parent_id: Option<Snowflake>,
+}
+
+repr_enum! {
+    /// Represents a Channel Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/channel#channel-object-channel-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ChannelType: u8 {
+        /// A text channel within a server.
+        GuildText = 0,
+
+        /// A direct message between users.
+        DM = 1,
+
+        /// A voice channel within a server.
+        GuildVoice = 2,
+
+        /// A direct message between multiple users.
+        GroupDM = 3,
+
+        /// An organizational category that contains up to 50 channels.
+        GuildCategory = 4,
+
+        /// A channel that users can follow and crosspost into their own server.
+        GuildAnnouncement = 5,
+
+        /// A temporary sub-channel within a GUILD_ANNOUNCEMENT channel.
+        AnnouncementThread = 10,
+
+        /// A temporary sub-channel within a GUILD_TEXT or GUILD_FORUM channel.
+        PublicThread = 11,
+
+        /// A temporary sub-channel within a GUILD_TEXT channel that is only viewable by those invited and those with the MANAGE_THREADS permission.
+        PrivateThread = 12,
+
+        /// A voice channel for hosting events with an audience.
+        GuildStageVoice = 13,
+
+        /// The channel in a hub containing the listed servers.
+        GuildDirectory = 14,
+
+        /// Channel that can only contain threads.
+        GuildForum = 15,
+
+        /// Channel that can only contain threads, similar to GUILD_FORUM channels.
+        GuildMedia = 16,
+    }
+}
@@ -0,0 +1,329 @@
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{from_value, to_value, Value};
+
+use crate::{ChannelType, EmojiObject};
+
+repr_enum! {
+    /// Represents a Component Type.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#component-object-component-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ComponentType: u8 {
+        /// Container for other components.
+        ActionRow = 1,
+
+        /// Button object.
+        Button = 2,
+
+        /// Select menu for picking from defined text options.
+        StringSelect = 3,
+
+        /// Text input object.
+        TextInput = 4,
+
+        /// Select menu for users.
+        UserSelect = 5,
+
+        /// Select menu for roles.
+        RoleSelect = 6,
+
+        /// Select menu for mentionables (users and roles).
+        MentionableSelect = 7,
+
+        /// Select menu for channels.
+        ChannelSelect = 8,
+    }
+}
+
+/// Represents a Discord Action Row Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#action-rows).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionRowObject {
+    /// Always `1` for action rows.
+    #[serde(rename = "type")]
+    pub ty: u8,
+
+    /// The components of this row, up to 5 buttons or a single select menu or text input.
+    pub components: Vec<ComponentObject>,
+}
+
+impl ActionRowObject {
+    #[inline]
+    pub fn new(components: Vec<ComponentObject>) -> Self {
+        Self {
+            ty: ComponentType::ActionRow.into(),
+            components,
+        }
+    }
+}
+
+impl Default for ActionRowObject {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+/// Represents a Discord Component Object that can be placed inside an [`ActionRowObject`].
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#component-object).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentObject {
+    Button(ButtonObject),
+    StringSelectMenu(StringSelectMenuObject),
+    UserSelectMenu(SelectMenuObject),
+    RoleSelectMenu(SelectMenuObject),
+    MentionableSelectMenu(SelectMenuObject),
+    ChannelSelectMenu(SelectMenuObject),
+    TextInput(TextInputObject),
+
+    /// A component type this version doesn't know about, kept as it was received.
+    Unknown(u8, Value),
+}
+
+impl ComponentObject {
+    #[inline]
+    pub fn ty(&self) -> ComponentType {
+        match self {
+            Self::Button(_) => ComponentType::Button,
+            Self::StringSelectMenu(_) => ComponentType::StringSelect,
+            Self::UserSelectMenu(_) => ComponentType::UserSelect,
+            Self::RoleSelectMenu(_) => ComponentType::RoleSelect,
+            Self::MentionableSelectMenu(_) => ComponentType::MentionableSelect,
+            Self::ChannelSelectMenu(_) => ComponentType::ChannelSelect,
+            Self::TextInput(_) => ComponentType::TextInput,
+            Self::Unknown(ty, _) => ComponentType::Unknown(*ty),
+        }
+    }
+}
+
+impl Serialize for ComponentObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self {
+            Self::Button(c) => to_value(c),
+            Self::StringSelectMenu(c) => to_value(c),
+            Self::UserSelectMenu(c)
+            | Self::RoleSelectMenu(c)
+            | Self::MentionableSelectMenu(c)
+            | Self::ChannelSelectMenu(c) => to_value(c),
+            Self::TextInput(c) => to_value(c),
+            Self::Unknown(_, value) => return value.serialize(serializer),
+        };
+
+        let Value::Object(mut obj) = value.map_err(S::Error::custom)? else {
+            return Err(S::Error::custom(
+                "expected component to serialize as an object",
+            ));
+        };
+
+        obj.insert("type".into(), Value::from(u8::from(self.ty())));
+
+        obj.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+
+        let ty = value
+            .get("type")
+            .cloned()
+            .ok_or_else(|| D::Error::missing_field("type"))
+            .and_then(|ty| from_value(ty).map_err(D::Error::custom))?;
+
+        match ty {
+            ComponentType::ActionRow => {
+                return Err(D::Error::custom("action rows can't be nested"));
+            }
+            ComponentType::Button => from_value(value).map(Self::Button),
+            ComponentType::StringSelect => from_value(value).map(Self::StringSelectMenu),
+            ComponentType::UserSelect => from_value(value).map(Self::UserSelectMenu),
+            ComponentType::RoleSelect => from_value(value).map(Self::RoleSelectMenu),
+            ComponentType::MentionableSelect => from_value(value).map(Self::MentionableSelectMenu),
+            ComponentType::ChannelSelect => from_value(value).map(Self::ChannelSelectMenu),
+            ComponentType::TextInput => from_value(value).map(Self::TextInput),
+            ComponentType::Unknown(ty) => Ok(Self::Unknown(ty, value)),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+/// Represents a Discord Button Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#button-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ButtonObject {
+    /// A [button style](https://discord.com/developers/docs/interactions/message-components#button-object-button-styles).
+    pub style: ButtonStyle,
+
+    /// Text that appears on the button; max 80 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// `name`, `id`, and `animated`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<EmojiObject>,
+
+    /// Developer-defined identifier for the button; max 100 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+
+    /// URL for link-style buttons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Whether the button is disabled (defaults to false).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+repr_enum! {
+    /// Represents a Button Style.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#button-object-button-styles).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ButtonStyle: u8 {
+        /// Blurple, requires `custom_id`.
+        Primary = 1,
+
+        /// Grey, requires `custom_id`.
+        Secondary = 2,
+
+        /// Green, requires `custom_id`.
+        Success = 3,
+
+        /// Red, requires `custom_id`.
+        Danger = 4,
+
+        /// Grey, navigates to a URL, requires `url`.
+        Link = 5,
+    }
+}
+
+/// Represents a Discord String Select Menu Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#select-menu-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringSelectMenuObject {
+    /// ID for the select menu; max 100 characters.
+    pub custom_id: String,
+
+    /// Specified choices in a select menu; max 25.
+    pub options: Vec<SelectOptionObject>,
+
+    /// Placeholder text if nothing is selected; max 150 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+
+    /// Minimum number of items that must be chosen (defaults to 1); min 0, max 25.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+
+    /// Maximum number of items that can be chosen (defaults to 1); max 25.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+
+    /// Whether select menu is disabled (defaults to false).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+/// Represents a Discord Select Option Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectOptionObject {
+    /// User-facing name of the option; max 100 characters.
+    pub label: String,
+
+    /// Dev-defined value of the option; max 100 characters.
+    pub value: String,
+
+    /// Additional description of the option; max 100 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// `id`, `name`, and `animated`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<EmojiObject>,
+
+    /// Will show this option as selected by default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+/// Represents a Discord auto-populated Select Menu Object (users, roles, mentionables or channels).
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#select-menu-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectMenuObject {
+    /// ID for the select menu; max 100 characters.
+    pub custom_id: String,
+
+    /// List of channel types to include in the channel select component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_types: Option<Vec<ChannelType>>,
+
+    /// Placeholder text if nothing is selected; max 150 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+
+    /// Minimum number of items that must be chosen (defaults to 1); min 0, max 25.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+
+    /// Maximum number of items that can be chosen (defaults to 1); max 25.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+
+    /// Whether select menu is disabled (defaults to false).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disabled: Option<bool>,
+}
+
+/// Represents a Discord Text Input Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#text-inputs).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextInputObject {
+    /// Developer-defined identifier for the input; max 100 characters.
+    pub custom_id: String,
+
+    /// The [Text Input Style](https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-styles).
+    pub style: TextInputStyle,
+
+    /// Label for this component; max 45 characters.
+    pub label: String,
+
+    /// Minimum input length for a text input; min 0, max 4000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+
+    /// Maximum input length for a text input; min 1, max 4000.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+
+    /// Whether this component is required to be filled (defaults to true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+
+    /// Pre-filled value for this component; max 4000 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Custom placeholder text if the input is empty; max 100 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+}
+
+repr_enum! {
+    /// Represents a Text Input Style.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-styles).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum TextInputStyle: u8 {
+        /// Single-line input.
+        Short = 1,
+
+        /// Multi-line input.
+        Paragraph = 2,
+    }
+}
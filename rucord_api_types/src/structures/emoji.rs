@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Snowflake, UserObject};
+
+/// Represents a Discord Emoji Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/emoji#emoji-object).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmojiObject {
+    /// [Emoji id](https://discord.com/developers/docs/reference#image-formatting).
+    pub id: Option<Snowflake>,
+
+    /// Emoji name, can be null only in reaction emoji objects.
+    pub name: Option<String>,
+
+    /// Roles allowed to use this emoji.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<Snowflake>,
+
+    /// User that created this emoji.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<UserObject>>,
+
+    /// Whether this emoji must be wrapped in colons.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_colons: Option<bool>,
+
+    /// Whether this emoji is managed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub managed: Option<bool>,
+
+    /// Whether this emoji is animated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animated: Option<bool>,
+
+    /// Whether this emoji can be used, may be false due to loss of Server Boosts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub available: Option<bool>,
+}
+
+impl EmojiObject {
+    /// A standard unicode emoji, e.g. `👍`.
+    #[inline]
+    pub fn unicode(name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+}
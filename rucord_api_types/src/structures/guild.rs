@@ -1,6 +1,231 @@
 use serde::{Deserialize, Serialize};
+use unicase::UniCase;
 
-use crate::Snowflake;
+use crate::{
+    ChannelObject, ChannelType, EmojiObject, GuildUpdateObject, Permissions, PresenceObject,
+    RoleObject, Snowflake, UserObject,
+};
+
+/// Represents a Discord Guild Object, with the extra fields sent in `GUILD_CREATE`.
+/// Unavailable guilds only have `id` and `unavailable` set.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#guild-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildObject {
+    /// Guild id.
+    pub id: Snowflake,
+
+    /// Guild name (2-100 characters, excluding trailing and leading whitespace).
+    #[serde(default)]
+    pub name: String,
+
+    /// [Icon hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub icon: Option<String>,
+
+    /// [Splash hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub splash: Option<String>,
+
+    /// Id of owner.
+    #[serde(default)]
+    pub owner_id: Option<Snowflake>,
+
+    /// Id of afk channel.
+    #[serde(default)]
+    pub afk_channel_id: Option<Snowflake>,
+
+    /// Afk timeout in seconds.
+    #[serde(default)]
+    pub afk_timeout: Option<u32>,
+
+    /// Roles in the guild.
+    #[serde(default)]
+    pub roles: Vec<RoleObject>,
+
+    /// Custom guild emojis.
+    #[serde(default)]
+    pub emojis: Vec<EmojiObject>,
+
+    /// Enabled guild features.
+    #[serde(default)]
+    pub features: Vec<String>,
+
+    /// The id of the channel where guild notices such as welcome messages and boost events are posted.
+    #[serde(default)]
+    pub system_channel_id: Option<Snowflake>,
+
+    /// The vanity url code for the guild.
+    #[serde(default)]
+    pub vanity_url_code: Option<String>,
+
+    /// The description of a guild.
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// [Banner hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub banner: Option<String>,
+
+    /// The preferred locale of a Community guild; used in server discovery and notices from Discord, and sent in interactions; defaults to "en-US".
+    #[serde(default)]
+    pub preferred_locale: Option<String>,
+
+    /// When this guild was joined at, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub joined_at: Option<String>,
+
+    /// True if this is considered a large guild, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub large: Option<bool>,
+
+    /// True if this guild is unavailable due to an outage.
+    #[serde(default)]
+    pub unavailable: Option<bool>,
+
+    /// Total number of members in this guild, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub member_count: Option<u64>,
+
+    /// Users in the guild, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub members: Vec<GuildMemberObject>,
+
+    /// Channels in the guild, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub channels: Vec<ChannelObject>,
+
+    /// All active threads in the guild that current user has permission to view, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub threads: Vec<ChannelObject>,
+
+    /// Presences of the members in the guild, only sent in `GUILD_CREATE`.
+    #[serde(default)]
+    pub presences: Vec<PresenceObject>,
+}
+
+impl GuildObject {
+    /// Whether the guild is large, offline members aren't sent for large guilds.
+    #[inline]
+    pub fn is_large(&self) -> bool {
+        self.large.unwrap_or(false)
+    }
+
+    #[inline]
+    pub fn is_available(&self) -> bool {
+        !self.unavailable.unwrap_or(false)
+    }
+
+    /// The first role with the given name, ignoring the case.
+    pub fn find_role_by_name(&self, name: &str) -> Option<&RoleObject> {
+        let name = UniCase::new(name);
+        self.roles
+            .iter()
+            .find(|role| UniCase::new(role.name.as_str()) == name)
+    }
+
+    /// Every role with the given name, ignoring the case, since role names aren't unique.
+    pub fn find_roles_by_name(&self, name: &str) -> Vec<&RoleObject> {
+        let name = UniCase::new(name);
+        self.roles
+            .iter()
+            .filter(|role| UniCase::new(role.name.as_str()) == name)
+            .collect()
+    }
+
+    /// The first channel with the given name, ignoring the case. The channels are only sent in
+    /// `GUILD_CREATE`.
+    pub fn find_channel_by_name(&self, name: &str) -> Option<&ChannelObject> {
+        let name = UniCase::new(name);
+        self.channels
+            .iter()
+            .find(|channel| channel.name.as_deref().map(UniCase::new) == Some(name))
+    }
+
+    /// The channels of the given type, e.g. the voice channels.
+    pub fn channels_of_type(&self, channel_type: ChannelType) -> Vec<&ChannelObject> {
+        self.channels
+            .iter()
+            .filter(|channel| channel.ty == channel_type)
+            .collect()
+    }
+
+    /// Merges the set fields of the update into the guild, the `GUILD_CREATE` only fields are kept.
+    /// The nullable fields sent as null are cleared.
+    pub fn apply_update(&mut self, update: GuildUpdateObject) {
+        if let Some(name) = update.name {
+            self.name = name;
+        }
+        if let Some(roles) = update.roles {
+            self.roles = roles;
+        }
+        if let Some(emojis) = update.emojis {
+            self.emojis = emojis;
+        }
+        if let Some(features) = update.features {
+            self.features = features;
+        }
+
+        if let Some(icon) = update.icon {
+            self.icon = icon;
+        }
+        if let Some(splash) = update.splash {
+            self.splash = splash;
+        }
+        if let Some(afk_channel_id) = update.afk_channel_id {
+            self.afk_channel_id = afk_channel_id;
+        }
+        if let Some(system_channel_id) = update.system_channel_id {
+            self.system_channel_id = system_channel_id;
+        }
+        if let Some(vanity_url_code) = update.vanity_url_code {
+            self.vanity_url_code = vanity_url_code;
+        }
+        if let Some(description) = update.description {
+            self.description = description;
+        }
+        if let Some(banner) = update.banner {
+            self.banner = banner;
+        }
+
+        self.owner_id = update.owner_id.or(self.owner_id);
+        self.afk_timeout = update.afk_timeout.or(self.afk_timeout);
+        self.preferred_locale = update.preferred_locale.or(self.preferred_locale.take());
+        self.unavailable = update.unavailable.or(self.unavailable);
+    }
+}
+
+impl From<GuildUpdateObject> for GuildObject {
+    fn from(update: GuildUpdateObject) -> Self {
+        let mut guild = Self {
+            id: update.id,
+            name: Default::default(),
+            icon: None,
+            splash: None,
+            owner_id: None,
+            afk_channel_id: None,
+            afk_timeout: None,
+            roles: vec![],
+            emojis: vec![],
+            features: vec![],
+            system_channel_id: None,
+            vanity_url_code: None,
+            description: None,
+            banner: None,
+            preferred_locale: None,
+            joined_at: None,
+            large: None,
+            unavailable: None,
+            member_count: None,
+            members: vec![],
+            channels: vec![],
+            threads: vec![],
+            presences: vec![],
+        };
+
+        guild.apply_update(update);
+        guild
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnavailableGuildObject {
@@ -8,3 +233,175 @@ pub struct UnavailableGuildObject {
 
     unavailable: bool,
 }
+
+/// Represents a Discord Guild Widget Settings Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#guild-widget-settings-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildWidgetSettingsObject {
+    /// Whether the widget is enabled.
+    pub enabled: bool,
+
+    /// The widget channel id.
+    pub channel_id: Option<Snowflake>,
+}
+
+/// Represents a Discord Guild Widget Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#guild-widget-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuildWidgetObject {
+    /// Guild id.
+    pub id: Snowflake,
+
+    /// Guild name (2-100 characters).
+    pub name: String,
+
+    /// Instant invite for the guilds specified widget invite channel.
+    pub instant_invite: Option<String>,
+
+    /// Voice and stage channels which are accessible by @everyone.
+    pub channels: Vec<WidgetChannel>,
+
+    /// Special widget user objects that includes users presence (Limit 100).
+    pub members: Vec<WidgetMember>,
+
+    /// Number of online members in this guild.
+    pub presence_count: u32,
+}
+
+/// A partial channel of a [`GuildWidgetObject`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetChannel {
+    /// The id of the channel.
+    pub id: Snowflake,
+
+    /// The name of the channel.
+    pub name: String,
+
+    /// Sorting position of the channel.
+    pub position: i32,
+}
+
+/// A partial user of a [`GuildWidgetObject`], the ids are anonymized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetMember {
+    /// The anonymized id of the user.
+    pub id: String,
+
+    /// The user's username.
+    pub username: String,
+
+    /// Always "0000".
+    pub discriminator: String,
+
+    /// Always null.
+    pub avatar: Option<String>,
+
+    /// The user's status.
+    pub status: String,
+
+    /// The user's avatar url.
+    pub avatar_url: String,
+}
+
+/// A guild member without its `user`, as sent alongside the user in message mentions.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#guild-member-object).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialGuildMemberObject {
+    /// This user's guild nickname.
+    #[serde(default)]
+    pub nick: Option<String>,
+
+    /// The member's [guild avatar hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub avatar: Option<String>,
+
+    /// Array of role object ids.
+    #[serde(default)]
+    pub roles: Vec<Snowflake>,
+
+    /// When the user joined the guild.
+    #[serde(default)]
+    pub joined_at: Option<String>,
+
+    /// When the user started boosting the guild.
+    #[serde(default)]
+    pub premium_since: Option<String>,
+
+    /// Whether the user is deafened in voice channels.
+    #[serde(default)]
+    pub deaf: Option<bool>,
+
+    /// Whether the user is muted in voice channels.
+    #[serde(default)]
+    pub mute: Option<bool>,
+
+    /// Whether the user has not yet passed the guild's Membership Screening requirements.
+    #[serde(default)]
+    pub pending: Option<bool>,
+
+    /// When the user's timeout will expire and the user will be able to communicate in the guild again.
+    #[serde(default)]
+    pub communication_disabled_until: Option<String>,
+}
+
+/// Represents a Discord Guild Member Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#guild-member-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildMemberObject {
+    /// The user this guild member represents, not included in `MESSAGE_CREATE` and `MESSAGE_UPDATE`.
+    #[serde(default)]
+    pub user: Option<UserObject>,
+
+    /// This user's guild nickname.
+    #[serde(default)]
+    pub nick: Option<String>,
+
+    /// The member's [guild avatar hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub avatar: Option<String>,
+
+    /// Array of role object ids.
+    pub roles: Vec<Snowflake>,
+
+    /// When the user joined the guild.
+    pub joined_at: String,
+
+    /// When the user started boosting the guild.
+    #[serde(default)]
+    pub premium_since: Option<String>,
+
+    /// Whether the user is deafened in voice channels.
+    #[serde(default)]
+    pub deaf: bool,
+
+    /// Whether the user is muted in voice channels.
+    #[serde(default)]
+    pub mute: bool,
+
+    /// [Guild member flags](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags).
+    #[serde(default)]
+    pub flags: u64,
+
+    /// Whether the user has not yet passed the guild's Membership Screening requirements.
+    #[serde(default)]
+    pub pending: Option<bool>,
+
+    /// Total permissions of the member in the channel, including overwrites, only sent in interactions.
+    #[serde(default)]
+    pub permissions: Option<Permissions>,
+
+    /// When the user's timeout will expire and the user will be able to communicate in the guild again.
+    #[serde(default)]
+    pub communication_disabled_until: Option<String>,
+}
+
+/// Represents a Discord Ban Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#ban-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BanObject {
+    /// The reason for the ban.
+    pub reason: Option<String>,
+
+    /// The banned user.
+    pub user: UserObject,
+}
@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{GuildMemberObject, Snowflake, UserObject};
+
+/// Represents a Discord Guild Scheduled Event Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildScheduledEventObject {
+    /// The id of the scheduled event.
+    pub id: Snowflake,
+
+    /// The guild id which the scheduled event belongs to.
+    pub guild_id: Snowflake,
+
+    /// The channel id in which the scheduled event will be hosted, or null if the entity type
+    /// is `External`.
+    #[serde(default)]
+    pub channel_id: Option<Snowflake>,
+
+    /// The id of the user that created the scheduled event, null for events created before
+    /// October 25th, 2021.
+    #[serde(default)]
+    pub creator_id: Option<Snowflake>,
+
+    /// The name of the scheduled event (1-100 characters).
+    pub name: String,
+
+    /// The description of the scheduled event (1-1000 characters).
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// The ISO 8601 time the scheduled event will start.
+    pub scheduled_start_time: String,
+
+    /// The ISO 8601 time the scheduled event will end, required if the entity type is
+    /// `External`.
+    #[serde(default)]
+    pub scheduled_end_time: Option<String>,
+
+    /// The privacy level of the scheduled event.
+    pub privacy_level: GuildScheduledEventPrivacyLevel,
+
+    /// The status of the scheduled event.
+    pub status: GuildScheduledEventStatus,
+
+    /// The type of the scheduled event.
+    pub entity_type: GuildScheduledEventEntityType,
+
+    /// The id of an entity associated with a guild scheduled event.
+    #[serde(default)]
+    pub entity_id: Option<Snowflake>,
+
+    /// Additional metadata for the guild scheduled event.
+    #[serde(default)]
+    pub entity_metadata: Option<GuildScheduledEventEntityMetadata>,
+
+    /// The user that created the scheduled event.
+    #[serde(default)]
+    pub creator: Option<UserObject>,
+
+    /// The number of users subscribed to the scheduled event, only sent when requested.
+    #[serde(default)]
+    pub user_count: Option<u32>,
+
+    /// The [cover image hash](https://discord.com/developers/docs/reference#image-formatting) of
+    /// the scheduled event.
+    #[serde(default)]
+    pub image: Option<String>,
+}
+
+repr_enum! {
+    /// Represents a Guild Scheduled Event Privacy Level.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-privacy-level).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum GuildScheduledEventPrivacyLevel: u8 {
+        /// The scheduled event is only accessible to guild members.
+        #[default]
+        GuildOnly = 2,
+    }
+}
+
+repr_enum! {
+    /// Represents a Guild Scheduled Event Entity Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum GuildScheduledEventEntityType: u8 {
+        StageInstance = 1,
+        Voice = 2,
+        External = 3,
+    }
+}
+
+repr_enum! {
+    /// Represents a Guild Scheduled Event Status.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum GuildScheduledEventStatus: u8 {
+        Scheduled = 1,
+        Active = 2,
+        Completed = 3,
+        Canceled = 4,
+    }
+}
+
+/// Represents a Discord Guild Scheduled Event Entity Metadata.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-entity-metadata).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildScheduledEventEntityMetadata {
+    /// Location of the event (1-100 characters), required for `External` events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// Represents a Discord Guild Scheduled Event User Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-user-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuildScheduledEventUserObject {
+    /// The scheduled event id which the user subscribed to.
+    pub guild_scheduled_event_id: Snowflake,
+
+    /// User which subscribed to an event.
+    pub user: UserObject,
+
+    /// Guild member data for this user for the guild which this event belongs to, if any.
+    #[serde(default)]
+    pub member: Option<GuildMemberObject>,
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Snowflake, UserObject};
+
+/// Represents a Discord Integration Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#integration-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrationObject {
+    /// Integration id.
+    pub id: Snowflake,
+
+    /// Integration name.
+    pub name: String,
+
+    /// Integration type (twitch, youtube, discord, or guild_subscription).
+    #[serde(rename = "type")]
+    pub ty: String,
+
+    /// Is this integration enabled.
+    pub enabled: bool,
+
+    /// Is this integration syncing.
+    #[serde(default)]
+    pub syncing: Option<bool>,
+
+    /// Id that this integration uses for "subscribers".
+    #[serde(default)]
+    pub role_id: Option<Snowflake>,
+
+    /// Whether emoticons should be synced for this integration (twitch only currently).
+    #[serde(default)]
+    pub enable_emoticons: Option<bool>,
+
+    /// The behavior of expiring subscribers.
+    #[serde(default)]
+    pub expire_behavior: Option<IntegrationExpireBehavior>,
+
+    /// The grace period (in days) before expiring subscribers.
+    #[serde(default)]
+    pub expire_grace_period: Option<u32>,
+
+    /// User for this integration.
+    #[serde(default)]
+    pub user: Option<UserObject>,
+
+    /// Integration account information.
+    pub account: IntegrationAccountObject,
+
+    /// When this integration was last synced.
+    #[serde(default)]
+    pub synced_at: Option<String>,
+
+    /// How many subscribers this integration has.
+    #[serde(default)]
+    pub subscriber_count: Option<u32>,
+
+    /// Has this integration been revoked.
+    #[serde(default)]
+    pub revoked: Option<bool>,
+
+    /// The bot/OAuth2 application for discord integrations.
+    #[serde(default)]
+    pub application: Option<IntegrationApplicationObject>,
+
+    /// The scopes the application has been authorized for.
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
+}
+
+repr_enum! {
+    /// Represents an Integration Expire Behavior.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/guild#integration-object-integration-expire-behaviors).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum IntegrationExpireBehavior: u8 {
+        RemoveRole = 0,
+        Kick = 1,
+    }
+}
+
+/// Represents a Discord Integration Account Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#integration-account-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrationAccountObject {
+    /// Id of the account, not a snowflake for external services.
+    pub id: String,
+
+    /// Name of the account.
+    pub name: String,
+}
+
+/// Represents a Discord Integration Application Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/guild#integration-application-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrationApplicationObject {
+    /// The id of the app.
+    pub id: Snowflake,
+
+    /// The name of the app.
+    pub name: String,
+
+    /// The [icon hash](https://discord.com/developers/docs/reference#image-formatting) of the app.
+    pub icon: Option<String>,
+
+    /// The description of the app.
+    pub description: String,
+
+    /// The bot associated with this application.
+    #[serde(default)]
+    pub bot: Option<UserObject>,
+}
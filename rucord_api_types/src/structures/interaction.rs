@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::{
+    ActionRowObject, AllowedMentionsObject, ComponentType, MessageObject, Snowflake, UserObject,
+};
+
+/// Represents a Discord Interaction Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionObject {
+    /// Id of the interaction.
+    pub id: Snowflake,
+
+    /// Id of the application this interaction is for.
+    pub application_id: Snowflake,
+
+    /// Type of interaction.
+    #[serde(rename = "type")]
+    pub ty: InteractionType,
+
+    /// Interaction data payload.
+    #[serde(default)]
+    pub data: Option<InteractionData>,
+
+    /// Guild that the interaction was sent from.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// Channel that the interaction was sent from.
+    #[serde(default)]
+    pub channel_id: Option<Snowflake>,
+
+    // TODO: When write GuildMemberObject.
+    /// Guild member data for the invoking user, including permissions.
+    #[serde(default)]
+    pub member: Option<Value>,
+
+    /// User object for the invoking user, if invoked in a DM.
+    #[serde(default)]
+    pub user: Option<UserObject>,
+
+    /// Continuation token for responding to the interaction.
+    pub token: String,
+
+    /// Read-only property, always `1`.
+    pub version: u8,
+
+    /// For components, the message they were attached to.
+    #[serde(default)]
+    pub message: Option<MessageObject>,
+
+    /// Selected [language](https://discord.com/developers/docs/reference#locales) of the invoking user.
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// [Guild's preferred locale](https://discord.com/developers/docs/resources/guild#guild-object), if invoked in a guild.
+    #[serde(default)]
+    pub guild_locale: Option<String>,
+}
+
+impl InteractionObject {
+    /// The `custom_id` of the component that triggered this interaction.
+    #[inline]
+    pub fn custom_id(&self) -> Option<&str> {
+        self.data.as_ref()?.custom_id.as_deref()
+    }
+
+    /// The name of the invoked application command.
+    #[inline]
+    pub fn command_name(&self) -> Option<&str> {
+        self.data.as_ref()?.name.as_deref()
+    }
+}
+
+repr_enum! {
+    /// Represents an Interaction Type.
+    /// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-type).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum InteractionType: u8 {
+        Ping = 1,
+        ApplicationCommand = 2,
+        MessageComponent = 3,
+        ApplicationCommandAutocomplete = 4,
+        ModalSubmit = 5,
+    }
+}
+
+/// The data of an Interaction, which fields are set depends on the [`InteractionType`].
+/// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-interaction-data).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InteractionData {
+    /// The ID of the invoked command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Snowflake>,
+
+    /// The name of the invoked command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    // TODO: When write ApplicationCommandOptionObject.
+    /// The params + values from the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<Value>>,
+
+    /// The `custom_id` of the component or modal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+
+    /// The [type](https://discord.com/developers/docs/interactions/message-components#component-object-component-types) of the component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<ComponentType>,
+
+    /// Values the user selected in a select menu component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+
+    /// Id of the user or message targeted by a user or message command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Snowflake>,
+
+    /// The values submitted by the user in a modal, as action rows of partial text inputs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Value>>,
+}
+
+/// The data of a message Interaction Response.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-messages).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InteractionCallbackData {
+    /// Is the response TTS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    /// Message content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    // TODO: When write EmbedObject.
+    /// Supports up to 10 embeds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Value>>,
+
+    /// [Allowed mentions](https://discord.com/developers/docs/resources/channel#allowed-mentions-object) object.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsObject>,
+
+    /// [Message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+
+    /// Message components.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ActionRowObject>>,
+}
+
+impl InteractionCallbackData {
+    /// A message only the user who invoked the interaction can see.
+    #[inline]
+    pub fn ephemeral(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            flags: Some(1 << 6),
+            ..Default::default()
+        }
+    }
+}
+
+/// Represents a Discord Interaction Response Object.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionResponseObject {
+    /// The type of response.
+    #[serde(rename = "type")]
+    pub ty: InteractionCallbackType,
+
+    /// An optional response message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<InteractionCallbackData>,
+}
+
+impl InteractionResponseObject {
+    /// Responds with a message.
+    #[inline]
+    pub fn message(data: InteractionCallbackData) -> Self {
+        Self {
+            ty: InteractionCallbackType::ChannelMessageWithSource,
+            data: Some(data),
+        }
+    }
+}
+
+/// Represents an Interaction Callback Type.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum InteractionCallbackType {
+    Pong = 1,
+    ChannelMessageWithSource = 4,
+    DeferredChannelMessageWithSource = 5,
+    DeferredUpdateMessage = 6,
+    UpdateMessage = 7,
+    ApplicationCommandAutocompleteResult = 8,
+    Modal = 9,
+}
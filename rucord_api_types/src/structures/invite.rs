@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{PartialChannelObject, UserObject};
+
+/// Represents a Discord Invite Object, including its metadata when available.
+/// [Discord documentation](https://discord.com/developers/docs/resources/invite#invite-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteObject {
+    /// The invite code (unique ID).
+    pub code: String,
+
+    // TODO: When write GuildObject.
+    /// The guild this invite is for.
+    #[serde(default)]
+    pub guild: Option<Value>,
+
+    /// The channel this invite is for.
+    #[serde(default)]
+    pub channel: Option<PartialChannelObject>,
+
+    /// The user who created the invite.
+    #[serde(default)]
+    pub inviter: Option<UserObject>,
+
+    /// The [type of target](https://discord.com/developers/docs/resources/invite#invite-object-invite-target-types) for this voice channel invite.
+    #[serde(default)]
+    pub target_type: Option<InviteTargetType>,
+
+    /// The user whose stream to display for this voice channel stream invite.
+    #[serde(default)]
+    pub target_user: Option<UserObject>,
+
+    /// Approximate count of online members.
+    #[serde(default)]
+    pub approximate_presence_count: Option<u64>,
+
+    /// Approximate count of total members.
+    #[serde(default)]
+    pub approximate_member_count: Option<u64>,
+
+    /// The expiration date of this invite.
+    #[serde(default)]
+    pub expires_at: Option<String>,
+
+    /// Number of times this invite has been used.
+    #[serde(default)]
+    pub uses: Option<u32>,
+
+    /// Max number of times this invite can be used.
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+
+    /// Duration (in seconds) after which the invite expires.
+    #[serde(default)]
+    pub max_age: Option<u32>,
+
+    /// Whether this invite only grants temporary membership.
+    #[serde(default)]
+    pub temporary: Option<bool>,
+
+    /// When this invite was created.
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+repr_enum! {
+    /// Represents an Invite Target Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/invite#invite-object-invite-target-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum InviteTargetType: u8 {
+        Stream = 1,
+        EmbeddedApplication = 2,
+    }
+}
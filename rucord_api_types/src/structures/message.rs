@@ -0,0 +1,360 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    ChannelType, CreateMessageBodyBuilder, PartialGuildMemberObject, Snowflake, UserObject,
+};
+
+/// User, role and channel mentions, e.g. `<@!80351110224678912>`.
+static MENTION_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"<(?:@[!&]?|#)\d+>").unwrap());
+
+static CHANNEL_MENTION_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"<#\d+>").unwrap());
+
+/// The markdown removed by [`MessageObject::strip_formatting`], in order, bold before italic
+/// since both use `*`.
+static FORMATTING_PATTERNS: Lazy<[Regex; 5]> = Lazy::new(|| {
+    [
+        r"(?s)\*\*(.+?)\*\*",
+        r"(?s)__(.+?)__",
+        r"(?s)~~(.+?)~~",
+        r"(?s)\*(.+?)\*",
+        r"`([^`]+)`",
+    ]
+    .map(|pattern| Regex::new(pattern).unwrap())
+});
+
+/// Represents a Discord Message Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#message-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageObject {
+    /// Id of the message.
+    pub id: Snowflake,
+
+    /// Id of the channel the message was sent in.
+    pub channel_id: Snowflake,
+
+    /// Id of the guild the message was sent in, only sent in gateway events.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// The author of this message.
+    pub author: UserObject,
+
+    /// Contents of the message.
+    pub content: String,
+
+    /// When this message was sent.
+    pub timestamp: String,
+
+    /// When this message was edited (or null if never).
+    pub edited_timestamp: Option<String>,
+
+    /// Whether this was a TTS message.
+    pub tts: bool,
+
+    /// Whether this message mentions everyone.
+    pub mention_everyone: bool,
+
+    /// Users specifically mentioned in the message.
+    #[serde(default)]
+    pub mentions: Vec<MentionedUser>,
+
+    /// Roles specifically mentioned in this message.
+    #[serde(default)]
+    pub mention_roles: Vec<Snowflake>,
+
+    /// Channels specifically mentioned in this message.
+    #[serde(default)]
+    pub mention_channels: Option<Vec<ChannelMention>>,
+
+    // TODO: When write EmbedObject.
+    /// Any embedded content.
+    #[serde(default)]
+    pub embeds: Vec<Value>,
+
+    /// Whether this message is pinned.
+    pub pinned: bool,
+
+    /// [Type of message](https://discord.com/developers/docs/resources/channel#message-object-message-types).
+    #[serde(rename = "type", default)]
+    pub message_type: MessageType,
+
+    /// Data showing the source of a crosspost, channel follow add, pin, or reply message.
+    #[serde(default)]
+    pub message_reference: Option<MessageReferenceObject>,
+
+    /// [Message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags).
+    #[serde(default)]
+    pub flags: Option<u64>,
+
+    /// The message associated with the `message_reference`, null if it was deleted.
+    #[serde(default)]
+    pub referenced_message: Option<Box<MessageObject>>,
+}
+
+impl MessageObject {
+    /// The ids of the users mentioned in the message.
+    pub fn mentioned_user_ids(&self) -> Vec<&Snowflake> {
+        self.mentions
+            .iter()
+            .map(|mention| &mention.user.id)
+            .collect()
+    }
+
+    #[inline]
+    pub fn is_from_bot(&self) -> bool {
+        self.author.is_bot()
+    }
+
+    /// Whether the message was sent by Discord, e.g. a pin or a member join.
+    #[inline]
+    pub fn is_system(&self) -> bool {
+        !matches!(
+            self.message_type,
+            MessageType::Default
+                | MessageType::Reply
+                | MessageType::ChatInputCommand
+                | MessageType::ContextMenuCommand
+        )
+    }
+
+    /// Whether the message replies to a message that still exists.
+    #[inline]
+    pub fn is_reply(&self) -> bool {
+        self.message_type == MessageType::Reply && self.referenced_message.is_some()
+    }
+
+    /// The content without user, role and channel mentions. When `mentions` or `mention_roles`
+    /// is populated, only the mentions they list are removed, so text that merely looks like a
+    /// mention is kept.
+    pub fn strip_mentions(&self) -> String {
+        if self.mentions.is_empty() && self.mention_roles.is_empty() {
+            return MENTION_PATTERN.replace_all(&self.content, "").into_owned();
+        }
+
+        let mut content = self.content.clone();
+
+        for id in self.mentioned_user_ids() {
+            content = content
+                .replace(&format!("<@{id}>"), "")
+                .replace(&format!("<@!{id}>"), "");
+        }
+
+        for id in &self.mention_roles {
+            content = content.replace(&format!("<@&{id}>"), "");
+        }
+
+        CHANNEL_MENTION_PATTERN
+            .replace_all(&content, "")
+            .into_owned()
+    }
+
+    /// The content without bold, italic, underline, strikethrough and inline code markdown, the
+    /// formatted text is kept. The markdown inside inline code is removed too.
+    #[inline]
+    pub fn strip_formatting(&self) -> String {
+        strip_formatting(&self.content)
+    }
+
+    /// The content without mentions and formatting, see [`strip_mentions`](Self::strip_mentions)
+    /// and [`strip_formatting`](Self::strip_formatting).
+    #[inline]
+    pub fn strip_all(&self) -> String {
+        strip_formatting(&self.strip_mentions())
+    }
+
+    /// Starts a reply to this message in its channel.
+    #[inline]
+    pub fn reply(&self, content: &str) -> CreateMessageBodyBuilder {
+        CreateMessageBodyBuilder::new()
+            .channel_id(&self.channel_id)
+            .reply_to_message(self)
+            .content(content)
+    }
+
+    /// References this message to reply to it, the reply is still sent if the message
+    /// gets deleted in the meantime.
+    #[inline]
+    pub fn to_reference(&self) -> MessageReferenceObject {
+        MessageReferenceObject {
+            message_id: Some(self.id),
+            channel_id: Some(self.channel_id),
+            guild_id: self.guild_id,
+            fail_if_not_exists: Some(false),
+        }
+    }
+}
+
+fn strip_formatting(content: &str) -> String {
+    FORMATTING_PATTERNS
+        .iter()
+        .fold(content.to_owned(), |content, pattern| {
+            pattern.replace_all(&content, "$1").into_owned()
+        })
+}
+
+/// A user mentioned in a message, with their member in guild messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MentionedUser {
+    #[serde(flatten)]
+    pub user: UserObject,
+
+    /// The member of the user, only sent in guild messages.
+    #[serde(default)]
+    pub member: Option<PartialGuildMemberObject>,
+}
+
+repr_enum! {
+    /// Represents a Message Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/channel#message-object-message-types).
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum MessageType: u8 {
+        #[default]
+        Default = 0,
+        RecipientAdd = 1,
+        RecipientRemove = 2,
+        Call = 3,
+        ChannelNameChange = 4,
+        ChannelIconChange = 5,
+        ChannelPinnedMessage = 6,
+        UserJoin = 7,
+        GuildBoost = 8,
+        GuildBoostTier1 = 9,
+        GuildBoostTier2 = 10,
+        GuildBoostTier3 = 11,
+        ChannelFollowAdd = 12,
+        GuildDiscoveryDisqualified = 14,
+        GuildDiscoveryRequalified = 15,
+        GuildDiscoveryGracePeriodInitialWarning = 16,
+        GuildDiscoveryGracePeriodFinalWarning = 17,
+        ThreadCreated = 18,
+        Reply = 19,
+        ChatInputCommand = 20,
+        ThreadStarterMessage = 21,
+        GuildInviteReminder = 22,
+        ContextMenuCommand = 23,
+        AutoModerationAction = 24,
+        RoleSubscriptionPurchase = 25,
+        InteractionPremiumUpsell = 26,
+        StageStart = 27,
+        StageEnd = 28,
+        StageSpeaker = 29,
+        StageTopic = 31,
+        GuildApplicationPremiumSubscription = 32,
+    }
+}
+
+/// Represents a Discord Channel Mention Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#channel-mention-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelMention {
+    /// Id of the channel.
+    pub id: Snowflake,
+
+    /// Id of the guild containing the channel.
+    pub guild_id: Snowflake,
+
+    /// The [type of channel](https://discord.com/developers/docs/resources/channel#channel-object-channel-types).
+    #[serde(rename = "type")]
+    pub ty: ChannelType,
+
+    /// The name of the channel.
+    pub name: String,
+}
+
+/// Represents a Discord Allowed Mentions Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#allowed-mentions-object).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowedMentionsObject {
+    /// An array of [allowed mention types](https://discord.com/developers/docs/resources/channel#allowed-mentions-object-allowed-mention-types) to parse from the content.
+    #[serde(default)]
+    pub parse: Vec<AllowedMentionType>,
+
+    /// Array of role ids to mention (max size of 100).
+    #[serde(default)]
+    pub roles: Vec<Snowflake>,
+
+    /// Array of user ids to mention (max size of 100).
+    #[serde(default)]
+    pub users: Vec<Snowflake>,
+
+    /// For replies, whether to mention the author of the message being replied to.
+    #[serde(default)]
+    pub replied_user: bool,
+}
+
+impl AllowedMentionsObject {
+    /// Doesn't ping anyone.
+    #[inline]
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Only pings the author of the message being replied to.
+    #[inline]
+    pub fn reply_only() -> Self {
+        Self {
+            replied_user: true,
+            ..Default::default()
+        }
+    }
+
+    /// Only pings the given roles and users.
+    #[inline]
+    pub fn roles_and_users(roles: Vec<Snowflake>, users: Vec<Snowflake>) -> Self {
+        Self {
+            roles,
+            users,
+            ..Default::default()
+        }
+    }
+}
+
+/// Represents an Allowed Mention Type.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#allowed-mentions-object-allowed-mention-types).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AllowedMentionType {
+    /// Controls role mentions.
+    Roles,
+
+    /// Controls user mentions.
+    Users,
+
+    /// Controls @everyone and @here mentions.
+    Everyone,
+}
+
+/// Represents a Discord Message Reference Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageReferenceObject {
+    /// Id of the originating message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<Snowflake>,
+
+    /// Id of the originating message's channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<Snowflake>,
+
+    /// Id of the originating message's guild.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<Snowflake>,
+
+    /// When sending, whether to error if the referenced message doesn't exist instead of sending as a normal (non-reply) message, default true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
+}
+
+impl MessageReferenceObject {
+    /// References the given message to reply to it.
+    #[inline]
+    pub fn reply_to(message_id: &Snowflake) -> Self {
+        Self {
+            message_id: Some(*message_id),
+            ..Default::default()
+        }
+    }
+}
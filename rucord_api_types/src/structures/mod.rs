@@ -1,9 +1,39 @@
+pub mod application;
+pub mod application_command;
+pub mod audit_log;
+pub mod channel;
+pub mod component;
+pub mod emoji;
 pub mod gateway;
 pub mod guild;
+pub mod guild_scheduled_event;
+pub mod integration;
+pub mod interaction;
+pub mod invite;
+pub mod message;
+pub mod permissions;
+pub mod role;
+pub mod sticker;
 pub mod user;
+pub mod webhook;
 
 mod traits;
 
+pub use application::*;
+pub use application_command::*;
+pub use audit_log::*;
+pub use channel::*;
+pub use component::*;
+pub use emoji::*;
 pub use gateway::*;
 pub use guild::*;
+pub use guild_scheduled_event::*;
+pub use integration::*;
+pub use interaction::*;
+pub use invite::*;
+pub use message::*;
+pub use permissions::*;
+pub use role::*;
+pub use sticker::*;
 pub use user::*;
+pub use webhook::*;
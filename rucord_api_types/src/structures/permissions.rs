@@ -0,0 +1,117 @@
+#![allow(non_upper_case_globals)]
+
+use std::fmt;
+
+use bitflags::bitflags;
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Serialize,
+};
+
+bitflags! {
+    /// Represents the permissions of a role, a member or a channel overwrite.
+    ///
+    /// [Discord documentation](https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags).
+    #[derive(Default)]
+    pub struct Permissions: u64 {
+        const CreateInstantInvite = 1 << 0;
+        const KickMembers = 1 << 1;
+        const BanMembers = 1 << 2;
+        const Administrator = 1 << 3;
+        const ManageChannels = 1 << 4;
+        const ManageGuild = 1 << 5;
+        const AddReactions = 1 << 6;
+        const ViewAuditLog = 1 << 7;
+        const PrioritySpeaker = 1 << 8;
+        const Stream = 1 << 9;
+        const ViewChannel = 1 << 10;
+        const SendMessages = 1 << 11;
+        const SendTtsMessages = 1 << 12;
+        const ManageMessages = 1 << 13;
+        const EmbedLinks = 1 << 14;
+        const AttachFiles = 1 << 15;
+        const ReadMessageHistory = 1 << 16;
+        const MentionEveryone = 1 << 17;
+        const UseExternalEmojis = 1 << 18;
+        const ViewGuildInsights = 1 << 19;
+        const Connect = 1 << 20;
+        const Speak = 1 << 21;
+        const MuteMembers = 1 << 22;
+        const DeafenMembers = 1 << 23;
+        const MoveMembers = 1 << 24;
+        const UseVad = 1 << 25;
+        const ChangeNickname = 1 << 26;
+        const ManageNicknames = 1 << 27;
+        const ManageRoles = 1 << 28;
+        const ManageWebhooks = 1 << 29;
+        const ManageGuildExpressions = 1 << 30;
+        const UseApplicationCommands = 1 << 31;
+        const RequestToSpeak = 1 << 32;
+        const ManageEvents = 1 << 33;
+        const ManageThreads = 1 << 34;
+        const CreatePublicThreads = 1 << 35;
+        const CreatePrivateThreads = 1 << 36;
+        const UseExternalStickers = 1 << 37;
+        const SendMessagesInThreads = 1 << 38;
+        const UseEmbeddedActivities = 1 << 39;
+        const ModerateMembers = 1 << 40;
+    }
+}
+
+impl Permissions {
+    /// Whether all the given permissions are granted, `Administrator` grants every permission.
+    #[inline]
+    pub fn has(self, permissions: Permissions) -> bool {
+        self.contains(Self::Administrator) || self.contains(permissions)
+    }
+
+    /// The given permissions that aren't granted, empty for administrators.
+    #[inline]
+    pub fn missing(self, permissions: Permissions) -> Permissions {
+        if self.contains(Self::Administrator) {
+            Self::empty()
+        } else {
+            permissions - self
+        }
+    }
+}
+
+/// Serializes the bitfield as a decimal string, like Discord sends it.
+impl Serialize for Permissions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&self.bits())
+    }
+}
+
+/// Deserializes the decimal string, or the integer bitfield. Unknown bits are dropped.
+impl<'de> Deserialize<'de> for Permissions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PermissionsVisitor;
+
+        impl<'de> Visitor<'de> for PermissionsVisitor {
+            type Value = Permissions;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a permissions bitfield")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(Permissions::from_bits_truncate(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse()
+                    .map(Permissions::from_bits_truncate)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(PermissionsVisitor)
+    }
+}
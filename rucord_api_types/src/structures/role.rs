@@ -0,0 +1,111 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Permissions, Snowflake};
+
+/// Represents a Discord Role Object.
+/// [Discord documentation](https://discord.com/developers/docs/topics/permissions#role-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleObject {
+    /// Role id.
+    pub id: Snowflake,
+
+    /// Role name.
+    pub name: String,
+
+    /// Integer representation of hexadecimal color code.
+    pub color: u32,
+
+    /// If this role is pinned in the user listing.
+    pub hoist: bool,
+
+    /// Role [icon hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub icon: Option<String>,
+
+    /// Role unicode emoji.
+    #[serde(default)]
+    pub unicode_emoji: Option<String>,
+
+    /// Position of this role.
+    pub position: i32,
+
+    /// Permission bit set.
+    pub permissions: Permissions,
+
+    /// Whether this role is managed by an integration.
+    pub managed: bool,
+
+    /// Whether this role is mentionable.
+    pub mentionable: bool,
+
+    /// The tags this role has.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<RoleTags>,
+}
+
+impl RoleObject {
+    /// Whether this is the @everyone role, which shares its id with the guild.
+    #[inline]
+    pub fn is_everyone(&self, guild_id: &Snowflake) -> bool {
+        self.id == *guild_id
+    }
+}
+
+/// Represents the tags of a Discord Role.
+/// [Discord documentation](https://discord.com/developers/docs/topics/permissions#role-object-role-tags-structure).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleTags {
+    /// The id of the bot this role belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<Snowflake>,
+
+    /// The id of the integration this role belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<Snowflake>,
+
+    /// Whether this is the guild's Booster role.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_flag",
+        serialize_with = "serialize_flag",
+        skip_serializing_if = "is_false"
+    )]
+    pub premium_subscriber: bool,
+
+    /// The id of this role's subscription sku and listing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_listing_id: Option<Snowflake>,
+
+    /// Whether this role is available for purchase.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_flag",
+        serialize_with = "serialize_flag",
+        skip_serializing_if = "is_false"
+    )]
+    pub available_for_purchase: bool,
+
+    /// Whether this role is a guild's linked role.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_flag",
+        serialize_with = "serialize_flag",
+        skip_serializing_if = "is_false"
+    )]
+    pub guild_connections: bool,
+}
+
+// Boolean tags are sent as `null` when set and omitted otherwise.
+fn deserialize_flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Option::<()>::deserialize(deserializer)?;
+    Ok(true)
+}
+
+fn serialize_flag<S: Serializer>(_: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_none()
+}
+
+#[inline]
+fn is_false(value: &bool) -> bool {
+    !value
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Snowflake, UserObject};
+
+/// Represents a Discord Sticker Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/sticker#sticker-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerObject {
+    /// [Id of the sticker](https://discord.com/developers/docs/reference#image-formatting).
+    pub id: Snowflake,
+
+    /// For standard stickers, id of the pack the sticker is from.
+    #[serde(default)]
+    pub pack_id: Option<Snowflake>,
+
+    /// Name of the sticker.
+    pub name: String,
+
+    /// Description of the sticker.
+    pub description: Option<String>,
+
+    /// Autocomplete/suggestion tags for the sticker (max 200 characters).
+    pub tags: String,
+
+    /// Deprecated, previously the sticker asset hash, now an empty string.
+    #[serde(default)]
+    pub asset: Option<String>,
+
+    /// [Type of sticker](https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types).
+    #[serde(rename = "type")]
+    pub ty: StickerType,
+
+    /// [Type of sticker format](https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types).
+    pub format_type: StickerFormatType,
+
+    /// Whether this guild sticker can be used, may be false due to loss of Server Boosts.
+    #[serde(default)]
+    pub available: Option<bool>,
+
+    /// Id of the guild that owns this sticker.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// The user that uploaded the guild sticker.
+    #[serde(default)]
+    pub user: Option<UserObject>,
+
+    /// The standard sticker's sort order within its pack.
+    #[serde(default)]
+    pub sort_value: Option<u32>,
+}
+
+repr_enum! {
+    /// Represents a Sticker Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum StickerType: u8 {
+        /// An official sticker in a pack.
+        Standard = 1,
+
+        /// A sticker uploaded to a guild for the guild's members.
+        Guild = 2,
+    }
+}
+
+repr_enum! {
+    /// Represents a Sticker Format Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/sticker#sticker-object-sticker-format-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum StickerFormatType: u8 {
+        Png = 1,
+        Apng = 2,
+        Lottie = 3,
+        Gif = 4,
+    }
+}
+
+/// Represents a Discord Sticker Pack Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/sticker#sticker-pack-object).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StickerPackObject {
+    /// Id of the sticker pack.
+    pub id: Snowflake,
+
+    /// The stickers in the pack.
+    pub stickers: Vec<StickerObject>,
+
+    /// Name of the sticker pack.
+    pub name: String,
+
+    /// Id of the pack's SKU.
+    pub sku_id: Snowflake,
+
+    /// Id of a sticker in the pack which is shown as the pack's icon.
+    #[serde(default)]
+    pub cover_sticker_id: Option<Snowflake>,
+
+    /// Description of the sticker pack.
+    pub description: String,
+
+    /// Id of the sticker pack's [banner image](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub banner_asset_id: Option<Snowflake>,
+}
+
+/// The response of a List Nitro Sticker Packs request.
+/// [Discord documentation](https://discord.com/developers/docs/resources/sticker#list-nitro-sticker-packs).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NitroStickerPacksObject {
+    pub sticker_packs: Vec<StickerPackObject>,
+}
@@ -3,17 +3,16 @@
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use serde_repr::{Deserialize_repr, Serialize_repr};
 use strum_macros::EnumString;
 
 use crate::Snowflake;
 
 /// Represents a Discord User Object.
 /// [Discord documentation](https://discord.com/developers/docs/resources/user#user-object).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UserObject {
     /// The user's id.
-    id: Snowflake,
+    pub(crate) id: Snowflake,
 
     /// The user's username, not unique across the platform.
     username: String,
@@ -69,6 +68,30 @@ pub struct UserObject {
     public_flags: Option<UserFlags>,
 }
 
+impl UserObject {
+    #[inline]
+    pub fn id(&self) -> Snowflake {
+        self.id
+    }
+
+    #[inline]
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Whether the user belongs to an OAuth2 application.
+    #[inline]
+    pub fn is_bot(&self) -> bool {
+        self.bot.unwrap_or(false)
+    }
+
+    /// Whether the user is an Official Discord System user.
+    #[inline]
+    pub fn is_system(&self) -> bool {
+        self.system.unwrap_or(false)
+    }
+}
+
 bitflags! {
     /// Represents a Discord User Flags.
     /// [Discord documentation](https://discord.com/developers/docs/resources/user#user-object-user-flags).
@@ -120,15 +143,16 @@ bitflags! {
     }
 }
 
-/// Represents a User Premium Type.
-/// [Discord documentation](https://discord.com/developers/docs/resources/user#user-object-premium-types).
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum PremiumType {
-    None,
-    NitroClassic,
-    Nitro,
-    NitroBasic,
+repr_enum! {
+    /// Represents a User Premium Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/user#user-object-premium-types).
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum PremiumType: u8 {
+        None = 0,
+        NitroClassic = 1,
+        Nitro = 2,
+        NitroBasic = 3,
+    }
 }
 
 /// Represents a User Connection Object.
@@ -195,12 +219,13 @@ pub enum ConnectionService {
     YouTube,
 }
 
-#[derive(Debug, Clone, Serialize_repr, Deserialize_repr)]
-#[repr(u8)]
-pub enum ConnectionVisibility {
-    /// Invisible to everyone except the user themselves.
-    None,
+repr_enum! {
+    #[derive(Debug, Clone)]
+    pub enum ConnectionVisibility: u8 {
+        /// Invisible to everyone except the user themselves.
+        None = 0,
 
-    /// Visible to everyone
-    Everyone,
+        /// Visible to everyone
+        Everyone = 1,
+    }
 }
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Snowflake, UserObject};
+
+/// Represents a Discord Webhook Object.
+/// [Discord documentation](https://discord.com/developers/docs/resources/webhook#webhook-object).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookObject {
+    /// The id of the webhook.
+    pub id: Snowflake,
+
+    /// The [type](https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-types) of the webhook.
+    #[serde(rename = "type")]
+    pub ty: WebhookType,
+
+    /// The guild id this webhook is for, if any.
+    #[serde(default)]
+    pub guild_id: Option<Snowflake>,
+
+    /// The channel id this webhook is for, if any.
+    #[serde(default)]
+    pub channel_id: Option<Snowflake>,
+
+    /// The user this webhook was created by, not sent when getting a webhook with its token.
+    #[serde(default)]
+    pub user: Option<UserObject>,
+
+    /// The default name of the webhook.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// The default user avatar [hash](https://discord.com/developers/docs/reference#image-formatting) of the webhook.
+    #[serde(default)]
+    pub avatar: Option<String>,
+
+    /// The secure token of the webhook, only sent for `Incoming` webhooks.
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// The bot/OAuth2 application that created this webhook.
+    #[serde(default)]
+    pub application_id: Option<Snowflake>,
+
+    /// The guild of the channel that this webhook is following, only sent for `ChannelFollower`
+    /// webhooks.
+    #[serde(default)]
+    pub source_guild: Option<WebhookSourceGuild>,
+
+    /// The channel that this webhook is following, only sent for `ChannelFollower` webhooks.
+    #[serde(default)]
+    pub source_channel: Option<WebhookSourceChannel>,
+
+    /// The url used for executing the webhook, only sent by the webhooks OAuth2 flow.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+repr_enum! {
+    /// Represents a Webhook Type.
+    /// [Discord documentation](https://discord.com/developers/docs/resources/webhook#webhook-object-webhook-types).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum WebhookType: u8 {
+        /// Incoming webhooks can post messages to channels with a generated token.
+        Incoming = 1,
+
+        /// Channel follower webhooks are internal webhooks used with channel following to post
+        /// new messages into channels.
+        ChannelFollower = 2,
+
+        /// Application webhooks are webhooks used with interactions.
+        Application = 3,
+    }
+}
+
+/// The partial guild a `ChannelFollower` webhook follows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookSourceGuild {
+    pub id: Snowflake,
+
+    pub name: String,
+
+    /// [Icon hash](https://discord.com/developers/docs/reference#image-formatting).
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+/// The partial channel a `ChannelFollower` webhook follows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookSourceChannel {
+    pub id: Snowflake,
+
+    pub name: String,
+}
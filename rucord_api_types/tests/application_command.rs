@@ -0,0 +1,102 @@
+use rucord_api_types::{
+    ApplicationCommandObject, ApplicationCommandOptionChoiceValue, ApplicationCommandOptionType,
+    ApplicationCommandType, ChannelType,
+};
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_localized_application_command() {
+    let command: ApplicationCommandObject = from_value(json!({
+        "id": "1067461416862814208",
+        "application_id": "775799577604522054",
+        "version": "1067461416862814209",
+        "name": "blep",
+        "name_localizations": { "fr": "blep", "de": "blep" },
+        "description": "Send a random adorable animal photo",
+        "description_localizations": { "fr": "Envoie une photo d'animal adorable" },
+        "options": [
+            {
+                "type": 3,
+                "name": "animal",
+                "name_localizations": { "fr": "animal" },
+                "description": "The type of animal",
+                "required": true,
+                "choices": [
+                    { "name": "Dog", "name_localizations": { "fr": "Chien" }, "value": "animal_dog" },
+                    { "name": "Cat", "value": "animal_cat" }
+                ]
+            },
+            {
+                "type": 4,
+                "name": "count",
+                "description": "How many photos",
+                "min_value": 1,
+                "max_value": 10
+            },
+            {
+                "type": 7,
+                "name": "channel",
+                "description": "Where to send them",
+                "channel_types": [0, 5]
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(command.ty, ApplicationCommandType::ChatInput);
+    assert_eq!(
+        command.name_localizations.as_ref().unwrap()["de"],
+        "blep".to_owned()
+    );
+    assert_eq!(
+        command.description_localizations.as_ref().unwrap()["fr"],
+        "Envoie une photo d'animal adorable".to_owned()
+    );
+
+    let [animal, count, channel] = &command.options[..] else {
+        panic!("expected 3 options");
+    };
+
+    assert_eq!(animal.ty, ApplicationCommandOptionType::String);
+    assert!(animal.required);
+    assert_eq!(
+        animal.choices[0].value,
+        ApplicationCommandOptionChoiceValue::String("animal_dog".into())
+    );
+    assert_eq!(
+        animal.choices[0].name_localizations.as_ref().unwrap()["fr"],
+        "Chien".to_owned()
+    );
+    assert!(animal.choices[1].name_localizations.is_none());
+
+    assert_eq!(count.ty, ApplicationCommandOptionType::Integer);
+    assert_eq!(count.min_value.as_ref().and_then(|n| n.as_i64()), Some(1));
+    assert_eq!(count.max_value.as_ref().and_then(|n| n.as_i64()), Some(10));
+
+    let count = to_value(count).unwrap();
+    assert_eq!(count["min_value"], json!(1));
+    assert!(count.get("choices").is_none());
+    assert!(count.get("name_localizations").is_none());
+
+    assert_eq!(
+        channel.channel_types,
+        [ChannelType::GuildText, ChannelType::GuildAnnouncement]
+    );
+}
+
+#[test]
+fn test_application_command_without_localizations() {
+    let command: ApplicationCommandObject = from_value(json!({
+        "id": "1067461416862814208",
+        "type": 2,
+        "application_id": "775799577604522054",
+        "version": "1067461416862814209",
+        "name": "High Five",
+        "description": ""
+    }))
+    .unwrap();
+
+    assert_eq!(command.ty, ApplicationCommandType::User);
+    assert!(command.name_localizations.is_none());
+    assert!(command.options.is_empty());
+}
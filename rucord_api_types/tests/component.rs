@@ -0,0 +1,72 @@
+use rucord_api_types::{
+    ActionRowObject, ButtonObject, ButtonStyle, ComponentObject, ComponentType, EmojiObject,
+    TextInputObject, TextInputStyle,
+};
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_action_row_round_trip() {
+    let row = json!({
+        "type": 1,
+        "components": [
+            {
+                "type": 2,
+                "style": 1,
+                "label": "Click me!",
+                "emoji": { "id": null, "name": "👍" },
+                "custom_id": "click_one"
+            },
+            {
+                "type": 4,
+                "custom_id": "name",
+                "style": 1,
+                "label": "Name"
+            }
+        ]
+    });
+
+    let parsed: ActionRowObject = from_value(row.clone()).unwrap();
+
+    assert_eq!(
+        parsed,
+        ActionRowObject::new(vec![
+            ComponentObject::Button(ButtonObject {
+                style: ButtonStyle::Primary,
+                label: Some("Click me!".into()),
+                emoji: Some(EmojiObject::unicode("👍")),
+                custom_id: Some("click_one".into()),
+                url: None,
+                disabled: None,
+            }),
+            ComponentObject::TextInput(TextInputObject {
+                custom_id: "name".into(),
+                style: TextInputStyle::Short,
+                label: "Name".into(),
+                min_length: None,
+                max_length: None,
+                required: None,
+                value: None,
+                placeholder: None,
+            }),
+        ])
+    );
+    assert_eq!(to_value(parsed).unwrap(), row);
+}
+
+#[test]
+fn test_nested_action_row() {
+    assert!(from_value::<ComponentObject>(json!({ "type": 1, "components": [] })).is_err());
+}
+
+#[test]
+fn test_unknown_component_round_trip() {
+    let row = json!({
+        "type": 1,
+        "components": [{ "type": 42, "custom_id": "future" }]
+    });
+
+    let parsed: ActionRowObject = from_value(row.clone()).unwrap();
+
+    assert_eq!(parsed.components[0].ty(), ComponentType::Unknown(42));
+    assert_eq!(to_value(parsed).unwrap(), row);
+}
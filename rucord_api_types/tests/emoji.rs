@@ -0,0 +1,37 @@
+use rucord_api_types::{EmojiObject, Snowflake};
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_guild_emoji() {
+    let emoji: EmojiObject = from_value(json!({
+        "id": "41771983429993937",
+        "name": "LUL",
+        "roles": ["41771983429993000", "41771983429993111"],
+        "user": {
+            "username": "Luigi",
+            "discriminator": "0002",
+            "id": "96008815106887111",
+            "avatar": "5500909a3274e1812beb4e8de6631111"
+        },
+        "require_colons": true,
+        "managed": false,
+        "animated": false
+    }))
+    .unwrap();
+
+    assert_eq!(emoji.roles.len(), 2);
+    assert_eq!(
+        emoji.user.map(|user| user.id()),
+        Some(Snowflake::new(96008815106887111))
+    );
+    assert_eq!(emoji.require_colons, Some(true));
+    assert_eq!(emoji.available, None);
+}
+
+#[test]
+fn test_unicode_emoji() {
+    assert_eq!(
+        to_value(EmojiObject::unicode("🔥")).unwrap(),
+        json!({ "id": null, "name": "🔥" })
+    );
+}
@@ -0,0 +1,77 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use rucord_api_types::{
+    parse_channel_mentions, parse_iso8601, parse_role_mentions, parse_user_mentions,
+    DiscordTimestamp, Snowflake, TimestampStyle,
+};
+
+#[test]
+fn test_timestamp_format() {
+    let timestamp = DiscordTimestamp(1609459200);
+
+    assert_eq!(
+        timestamp.format(TimestampStyle::LongDateTime),
+        "<t:1609459200:F>"
+    );
+    assert_eq!(
+        timestamp.format(TimestampStyle::Relative),
+        "<t:1609459200:R>"
+    );
+}
+
+#[test]
+fn test_timestamp_to_iso8601() {
+    assert_eq!(DiscordTimestamp(0).to_iso8601(), "1970-01-01T00:00:00Z");
+    assert_eq!(
+        DiscordTimestamp(1609459200).to_iso8601(),
+        "2021-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        DiscordTimestamp(1709210096).to_iso8601(),
+        "2024-02-29T12:34:56Z"
+    );
+}
+
+#[test]
+fn test_parse_iso8601() {
+    assert_eq!(parse_iso8601("1970-01-01T00:00:00Z"), Some(UNIX_EPOCH));
+    assert_eq!(
+        parse_iso8601("2024-02-29T12:34:56Z"),
+        Some(UNIX_EPOCH + Duration::from_secs(1709210096))
+    );
+    assert_eq!(
+        parse_iso8601("2015-04-26T06:26:56.936000+00:00"),
+        Some(UNIX_EPOCH + Duration::from_millis(1430029616936))
+    );
+    assert_eq!(
+        parse_iso8601("2021-01-01T02:00:00+02:00"),
+        Some(UNIX_EPOCH + Duration::from_secs(1609459200))
+    );
+    assert_eq!(
+        parse_iso8601("1969-12-31T23:59:59.5Z"),
+        Some(UNIX_EPOCH - Duration::from_millis(500))
+    );
+
+    assert!(parse_iso8601("2021-13-01T00:00:00Z").is_none());
+    assert!(parse_iso8601("2021-01-01 00:00:00").is_none());
+    assert!(parse_iso8601("not a date").is_none());
+}
+
+#[test]
+fn test_timestamp_from_snowflake() {
+    let timestamp = DiscordTimestamp::from_snowflake(&Snowflake::new(175928847299117063));
+
+    assert_eq!(timestamp, DiscordTimestamp(1462015105));
+}
+
+#[test]
+fn test_parse_mentions() {
+    let content = "<@123> <@!456> told <@&789> to check <#1011>, not <#abc> or <@&>";
+
+    assert_eq!(
+        parse_user_mentions(content),
+        vec![Snowflake::new(123), Snowflake::new(456)]
+    );
+    assert_eq!(parse_role_mentions(content), vec![Snowflake::new(789)]);
+    assert_eq!(parse_channel_mentions(content), vec![Snowflake::new(1011)]);
+}
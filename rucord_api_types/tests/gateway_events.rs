@@ -1,6 +1,15 @@
-use std::str::FromStr;
+use std::{
+    str::FromStr,
+    time::{Duration, UNIX_EPOCH},
+};
 
-use rucord_api_types::GatewayDispatchEvents;
+use rucord_api_types::{
+    ApplicationCommandPermissionType, AuditLogEvent, ChannelType, DispatchPayload,
+    GatewayDispatchEvents, GatewayReceivePayload, GuildMemberObject, GuildStickersUpdateObject,
+    MessageObject, MessageUpdateObject, Permissions, PresenceStateType, Snowflake,
+    StickerFormatType,
+};
+use serde_json::{from_value, json, to_value};
 
 #[test]
 fn test_gateway_event() {
@@ -9,3 +18,630 @@ fn test_gateway_event() {
         Ok(GatewayDispatchEvents::Ready)
     )
 }
+
+#[test]
+fn test_webhooks_update() {
+    let d = json!({
+        "guild_id": "197038439483310086",
+        "channel_id": "197038439483310087"
+    });
+
+    let payload = from_value(json!({ "t": "WEBHOOKS_UPDATE", "s": 3, "d": d })).unwrap();
+
+    let (s, DispatchPayload::WebhooksUpdate(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `WebhooksUpdate` payload");
+    };
+
+    assert_eq!(s, 3);
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(data.channel_id, Snowflake::new(197038439483310087));
+    assert_eq!(to_value(data).unwrap(), d);
+}
+
+#[test]
+fn test_unknown_op() {
+    for op in [2, 42] {
+        let payload = json!({ "op": op, "d": { "foo": "bar" } }).to_string();
+
+        let GatewayReceivePayload::UnknownOp(unknown, data) =
+            GatewayReceivePayload::unpack(payload).unwrap()
+        else {
+            panic!("expected an `UnknownOp` payload");
+        };
+
+        assert_eq!(unknown, op);
+        assert_eq!(data["d"], json!({ "foo": "bar" }));
+    }
+}
+
+#[test]
+fn test_invalid_dispatch_payload() {
+    let payload = from_value(json!({ "t": "CHANNEL_CREATE", "s": 1, "d": { "id": "1" } })).unwrap();
+    assert!(DispatchPayload::from_payload(payload).is_err());
+
+    let payload = from_value(json!({ "t": "CHANNEL_CREATE", "d": {} })).unwrap();
+    assert!(DispatchPayload::from_payload(payload).is_err());
+}
+
+#[test]
+fn test_unavailable_guild_create() {
+    let payload = from_value(json!({
+        "t": "GUILD_CREATE",
+        "s": 1,
+        "d": { "id": "41771983423143937", "unavailable": true }
+    }))
+    .unwrap();
+
+    let (_, DispatchPayload::GuildCreate(guild)) = DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `GuildCreate` payload");
+    };
+
+    assert!(!guild.is_available());
+    assert!(!guild.is_large());
+}
+
+#[test]
+fn test_dispatch_payload_name() {
+    let payload = from_value(json!({ "t": "RESUMED", "s": 1, "d": null })).unwrap();
+
+    assert_eq!(
+        DispatchPayload::from_payload(payload).unwrap().1.name(),
+        "RESUMED"
+    );
+}
+
+#[test]
+fn test_channel_create() {
+    let payload = serde_json::from_str(include_str!("fixtures/channel_create.json")).unwrap();
+
+    let (s, DispatchPayload::ChannelCreate(channel)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `ChannelCreate` payload");
+    };
+
+    assert_eq!(s, 7);
+    assert_eq!(channel.name.as_deref(), Some("general-2"));
+    assert_eq!(channel.ty, ChannelType::GuildText);
+    assert_eq!(channel.guild_id, Some(Snowflake::new(290926798626357999)));
+}
+
+#[test]
+fn test_message_delete_bulk() {
+    let payload = serde_json::from_str(include_str!("fixtures/message_delete_bulk.json")).unwrap();
+
+    let (_, DispatchPayload::MessageDeleteBulk(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `MessageDeleteBulk` payload");
+    };
+
+    assert_eq!(data.ids.len(), 3);
+    assert_eq!(data.channel_id, Snowflake::new(381870553235193857));
+}
+
+#[test]
+fn test_guild_ban_events() {
+    let d = json!({
+        "guild_id": "197038439483310086",
+        "user": {
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": null
+        }
+    });
+
+    let payload = from_value(json!({ "t": "GUILD_BAN_ADD", "s": 4, "d": d })).unwrap();
+
+    let (_, DispatchPayload::GuildBanAdd(data)) = DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `GuildBanAdd` payload");
+    };
+
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(data.user.id(), Snowflake::new(80351110224678912));
+
+    let payload = from_value(json!({ "t": "GUILD_BAN_REMOVE", "s": 5, "d": d })).unwrap();
+
+    let (_, DispatchPayload::GuildBanRemove(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `GuildBanRemove` payload");
+    };
+
+    assert_eq!(data.user.username(), "Nelly");
+}
+
+#[test]
+fn test_presence_update() {
+    let payload = serde_json::from_str(include_str!("fixtures/presence_update.json")).unwrap();
+
+    let (_, DispatchPayload::PresenceUpdate(presence)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `PresenceUpdate` payload");
+    };
+
+    assert_eq!(presence.user.id, Snowflake::new(80351110224678912));
+    assert_eq!(presence.guild_id, Some(Snowflake::new(197038439483310086)));
+    assert_eq!(presence.status, PresenceStateType::Dnd);
+    assert_eq!(presence.client_status.desktop, Some(PresenceStateType::Dnd));
+    assert_eq!(presence.client_status.web, None);
+    assert_eq!(presence.activities.len(), 1);
+}
+
+#[test]
+fn test_guild_emojis_update() {
+    let payload = serde_json::from_str(include_str!("fixtures/guild_emojis_update.json")).unwrap();
+
+    let (_, DispatchPayload::GuildEmojisUpdate(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `GuildEmojisUpdate` payload");
+    };
+
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(
+        data.emojis
+            .iter()
+            .map(|emoji| emoji.name.as_deref())
+            .collect::<Vec<_>>(),
+        [Some("LUL"), Some("blobdance")]
+    );
+    assert_eq!(data.emojis[1].animated, Some(true));
+}
+
+#[test]
+fn test_message_reaction_remove_events() {
+    let d = json!({
+        "channel_id": "197038439483310087",
+        "message_id": "197038439483310088",
+        "guild_id": "197038439483310086",
+        "emoji": { "id": null, "name": "🔥" }
+    });
+
+    let payload =
+        from_value(json!({ "t": "MESSAGE_REACTION_REMOVE_ALL", "s": 8, "d": d })).unwrap();
+
+    let (_, DispatchPayload::MessageReactionRemoveAll(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `MessageReactionRemoveAll` payload");
+    };
+
+    assert_eq!(data.message_id, Snowflake::new(197038439483310088));
+
+    let payload =
+        from_value(json!({ "t": "MESSAGE_REACTION_REMOVE_EMOJI", "s": 9, "d": d })).unwrap();
+
+    let (_, DispatchPayload::MessageReactionRemoveEmoji(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `MessageReactionRemoveEmoji` payload");
+    };
+
+    assert_eq!(data.guild_id, Some(Snowflake::new(197038439483310086)));
+    assert_eq!(data.emoji.name.as_deref(), Some("🔥"));
+}
+
+#[test]
+fn test_thread_list_sync() {
+    let payload = serde_json::from_str(include_str!("fixtures/thread_list_sync.json")).unwrap();
+
+    let (_, DispatchPayload::ThreadListSync(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `ThreadListSync` payload");
+    };
+
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(data.members.len(), 1);
+
+    let thread = &data.threads[0];
+
+    assert_eq!(thread.channel.ty, ChannelType::PublicThread);
+    assert_eq!(thread.channel.name.as_deref(), Some("don't buy dota-2"));
+    assert_eq!(thread.message_count, Some(1));
+    assert_eq!(
+        thread
+            .thread_metadata
+            .as_ref()
+            .map(|metadata| metadata.auto_archive_duration),
+        Some(1440)
+    );
+    assert_eq!(
+        thread.member.as_ref().and_then(|member| member.user_id),
+        Some(Snowflake::new(80351110224678912))
+    );
+
+    let d = to_value(thread).unwrap();
+    let payload = from_value(json!({ "t": "THREAD_CREATE", "s": 11, "d": d })).unwrap();
+
+    let (_, DispatchPayload::ThreadCreate(created)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `ThreadCreate` payload");
+    };
+
+    assert_eq!(created.channel.id, thread.channel.id);
+}
+
+#[test]
+fn test_guild_audit_log_entry_create() {
+    let payload =
+        serde_json::from_str(include_str!("fixtures/guild_audit_log_entry_create.json")).unwrap();
+
+    let (_, DispatchPayload::GuildAuditLogEntryCreate(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected a `GuildAuditLogEntryCreate` payload");
+    };
+
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(data.entry.action_type, AuditLogEvent::MemberRoleUpdate);
+    assert_eq!(data.entry.user_id, Some(Snowflake::new(80351110224678912)));
+    assert_eq!(data.entry.reason.as_deref(), Some("promoted"));
+
+    let changes = data.entry.changes.unwrap();
+
+    assert_eq!(changes[0].key, "$add");
+    assert_eq!(changes[0].old_value, None);
+    assert!(changes[0].new_value.is_some());
+}
+
+#[test]
+fn test_application_command_permissions_update() {
+    let payload = serde_json::from_str(include_str!(
+        "fixtures/application_command_permissions_update.json"
+    ))
+    .unwrap();
+
+    let (_, DispatchPayload::ApplicationCommandPermissionsUpdate(data)) =
+        DispatchPayload::from_payload(payload).unwrap()
+    else {
+        panic!("expected an `ApplicationCommandPermissionsUpdate` payload");
+    };
+
+    assert_eq!(data.application_id, Snowflake::new(775799577604522054));
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(data.permissions.len(), 2);
+    assert_eq!(
+        data.permissions[0].ty,
+        ApplicationCommandPermissionType::Role
+    );
+    assert!(data.permissions[0].permission);
+    assert_eq!(
+        data.permissions[1].ty,
+        ApplicationCommandPermissionType::Channel
+    );
+    assert!(!data.permissions[1].permission);
+}
+
+#[test]
+fn test_guild_members_chunk() {
+    let payloads: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_str(include_str!("fixtures/guild_members_chunk.json")).unwrap();
+
+    let chunks: Vec<_> = payloads
+        .into_iter()
+        .map(
+            |payload| match DispatchPayload::from_payload(payload).unwrap() {
+                (_, DispatchPayload::GuildMembersChunk(chunk)) => chunk,
+                _ => panic!("expected a `GuildMembersChunk` payload"),
+            },
+        )
+        .collect();
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks
+        .iter()
+        .all(|chunk| chunk.nonce.as_deref() == Some("fetch-1")));
+
+    let (first, last) = (&chunks[0], &chunks[1]);
+
+    assert!(!first.is_last());
+    assert_eq!(first.members[0].nick.as_deref(), Some("NOT API SUPPORT"));
+    assert_eq!(first.presences.as_ref().map(Vec::len), Some(1));
+
+    assert!(last.is_last());
+    assert!(last.members.is_empty());
+    assert_eq!(
+        last.not_found.as_deref(),
+        Some(&["53908232506183680".to_string()][..])
+    );
+}
+
+#[test]
+fn test_voice_server_update() {
+    let payload = serde_json::from_str(include_str!("fixtures/voice_server_update.json")).unwrap();
+
+    let DispatchPayload::VoiceServerUpdate(data) =
+        DispatchPayload::from_payload(payload).unwrap().1
+    else {
+        panic!("expected a `VoiceServerUpdate` payload");
+    };
+
+    assert_eq!(data.token, "my_token");
+    assert_eq!(data.guild_id, Snowflake::new(41771983423143937));
+    assert_eq!(
+        data.endpoint.as_deref(),
+        Some("sweetwater-12345.discord.media:2048")
+    );
+}
+
+#[test]
+fn test_guild_role_events() {
+    let payloads: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_str(include_str!("fixtures/guild_role_events.json")).unwrap();
+    let mut payloads = payloads
+        .into_iter()
+        .map(|payload| DispatchPayload::from_payload(payload).unwrap().1);
+
+    let Some(DispatchPayload::GuildRoleCreate(create)) = payloads.next() else {
+        panic!("expected a `GuildRoleCreate` payload");
+    };
+
+    assert_eq!(create.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(create.role.name, "Moderators");
+    assert!(create.role.permissions.has(Permissions::Administrator));
+    assert!(create.role.tags.is_none());
+
+    let Some(DispatchPayload::GuildRoleUpdate(update)) = payloads.next() else {
+        panic!("expected a `GuildRoleUpdate` payload");
+    };
+
+    let tags = update.role.tags.as_ref().unwrap();
+    assert!(tags.premium_subscriber);
+    assert!(!tags.guild_connections);
+    assert_eq!(
+        to_value(tags).unwrap(),
+        json!({ "premium_subscriber": null })
+    );
+
+    let Some(DispatchPayload::GuildRoleDelete(delete)) = payloads.next() else {
+        panic!("expected a `GuildRoleDelete` payload");
+    };
+
+    assert_eq!(delete.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(delete.role_id, Snowflake::new(41771983423143936));
+}
+
+#[test]
+fn test_guild_member_update() {
+    let payload = serde_json::from_str(include_str!("fixtures/guild_member_update.json")).unwrap();
+
+    let DispatchPayload::GuildMemberUpdate(update) =
+        DispatchPayload::from_payload(payload).unwrap().1
+    else {
+        panic!("expected a `GuildMemberUpdate` payload");
+    };
+
+    assert_eq!(update.nick.as_deref(), Some("NOT API SUPPORT"));
+    assert_eq!(update.deaf, None);
+
+    let mut member: GuildMemberObject = from_value(json!({
+        "roles": ["41771983423143936", "41771983423143937"],
+        "joined_at": "2015-04-26T06:26:56.936000+00:00",
+        "deaf": true
+    }))
+    .unwrap();
+
+    assert_eq!(
+        update.added_roles(&member),
+        vec![Snowflake::new(41771983423143938)]
+    );
+    assert_eq!(
+        update.removed_roles(&member),
+        vec![Snowflake::new(41771983423143937)]
+    );
+
+    update.apply_to(&mut member);
+
+    assert_eq!(member.roles, update.roles);
+    assert_eq!(member.nick.as_deref(), Some("NOT API SUPPORT"));
+    assert!(member.deaf);
+}
+
+#[test]
+fn test_thread_member_events() {
+    let payloads: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_str(include_str!("fixtures/thread_members_update.json")).unwrap();
+    let mut payloads = payloads
+        .into_iter()
+        .map(|payload| DispatchPayload::from_payload(payload).unwrap().1);
+
+    let Some(DispatchPayload::ThreadMemberUpdate(member)) = payloads.next() else {
+        panic!("expected a `ThreadMemberUpdate` payload");
+    };
+
+    assert_eq!(member.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(
+        member.member.user_id,
+        Some(Snowflake::new(80351110224678912))
+    );
+    assert_eq!(member.member.flags, 1);
+
+    let Some(DispatchPayload::ThreadMembersUpdate(members)) = payloads.next() else {
+        panic!("expected a `ThreadMembersUpdate` payload");
+    };
+
+    assert_eq!(members.id, Snowflake::new(1083425287345029190));
+    assert_eq!(members.member_count, 2);
+    assert_eq!(members.added_members.map(|added| added.len()), Some(1));
+    assert_eq!(
+        members.removed_member_ids,
+        Some(vec![Snowflake::new(80351110224678914)])
+    );
+}
+
+#[test]
+fn test_guild_scheduled_event_user_events() {
+    let payloads: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_str(
+        include_str!("fixtures/guild_scheduled_event_user_events.json"),
+    )
+    .unwrap();
+    let mut payloads = payloads
+        .into_iter()
+        .map(|payload| DispatchPayload::from_payload(payload).unwrap().1);
+
+    let Some(DispatchPayload::GuildScheduledEventUserAdd(added)) = payloads.next() else {
+        panic!("expected a `GuildScheduledEventUserAdd` payload");
+    };
+
+    assert_eq!(
+        added.guild_scheduled_event_id,
+        Snowflake::new(1083425287345029200)
+    );
+    assert_eq!(added.user_id, Snowflake::new(80351110224678912));
+    assert_eq!(added.guild_id, Snowflake::new(197038439483310086));
+
+    let Some(DispatchPayload::GuildScheduledEventUserRemove(removed)) = payloads.next() else {
+        panic!("expected a `GuildScheduledEventUserRemove` payload");
+    };
+
+    assert_eq!(removed.user_id, Snowflake::new(80351110224678913));
+}
+
+#[test]
+fn test_guild_integrations_update() {
+    let payload =
+        serde_json::from_str(include_str!("fixtures/guild_integrations_update.json")).unwrap();
+
+    let DispatchPayload::GuildIntegrationsUpdate(data) =
+        DispatchPayload::from_payload(payload).unwrap().1
+    else {
+        panic!("expected a `GuildIntegrationsUpdate` payload");
+    };
+
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+}
+
+#[test]
+fn test_channel_pins_update() {
+    let payloads: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_str(include_str!("fixtures/channel_pins_update.json")).unwrap();
+    let mut payloads = payloads
+        .into_iter()
+        .map(|payload| DispatchPayload::from_payload(payload).unwrap().1);
+
+    let Some(DispatchPayload::ChannelPinsUpdate(pinned)) = payloads.next() else {
+        panic!("expected a `ChannelPinsUpdate` payload");
+    };
+
+    assert_eq!(pinned.guild_id, Some(Snowflake::new(197038439483310086)));
+    assert_eq!(pinned.channel_id, Snowflake::new(41771983423143937));
+    assert_eq!(
+        pinned.last_pin_time(),
+        Some(UNIX_EPOCH + Duration::from_millis(1609459201500))
+    );
+
+    let Some(DispatchPayload::ChannelPinsUpdate(unpinned)) = payloads.next() else {
+        panic!("expected a `ChannelPinsUpdate` payload");
+    };
+
+    assert!(unpinned.guild_id.is_none());
+    assert!(unpinned.last_pin_timestamp.is_none());
+    assert!(unpinned.last_pin_time().is_none());
+}
+
+#[test]
+fn test_guild_stickers_update() {
+    let payload =
+        serde_json::from_str(include_str!("fixtures/guild_stickers_update.json")).unwrap();
+
+    let DispatchPayload::GuildStickersUpdate(data) =
+        DispatchPayload::from_payload(payload).unwrap().1
+    else {
+        panic!("expected a `GuildStickersUpdate` payload");
+    };
+
+    assert_eq!(data.guild_id, Snowflake::new(197038439483310086));
+    assert_eq!(
+        data.stickers
+            .iter()
+            .map(|sticker| sticker.name.as_str())
+            .collect::<Vec<_>>(),
+        ["Wave", "Dance"]
+    );
+    assert_eq!(data.stickers[1].format_type, StickerFormatType::Gif);
+
+    let value = to_value(&data).unwrap();
+    let round_trip: GuildStickersUpdateObject = from_value(value.clone()).unwrap();
+
+    assert_eq!(to_value(round_trip).unwrap(), value);
+}
+
+#[test]
+fn test_message_update() {
+    let payload = serde_json::from_str(include_str!("fixtures/message_update.json")).unwrap();
+
+    let DispatchPayload::MessageUpdate(data) = DispatchPayload::from_payload(payload).unwrap().1
+    else {
+        panic!("expected a `MessageUpdate` payload");
+    };
+
+    assert_eq!(data.id, Snowflake::new(1067461416862814210));
+    assert_eq!(data.channel_id, Snowflake::new(41771983423143937));
+    assert_eq!(data.content.as_deref(), Some("Hello, edited world!"));
+    assert!(data.guild_id.is_none());
+    assert!(data.author.is_none());
+    assert!(data.edited_timestamp.is_none());
+    assert!(data.mentions.is_none());
+    assert!(data.embeds.is_none());
+    assert!(data.pinned.is_none());
+    assert!(data.message_type.is_none());
+    assert!(data.flags.is_none());
+
+    let mut message: MessageObject = from_value(json!({
+        "id": "1067461416862814210",
+        "channel_id": "41771983423143937",
+        "guild_id": "197038439483310086",
+        "author": {
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": null
+        },
+        "content": "Hello, world!",
+        "timestamp": "2023-01-24T18:30:00.000000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mention_roles": ["41771983423143936"],
+        "pinned": true
+    }))
+    .unwrap();
+
+    data.apply_to(&mut message);
+
+    assert_eq!(message.content, "Hello, edited world!");
+    assert_eq!(message.guild_id, Some(Snowflake::new(197038439483310086)));
+    assert_eq!(message.author.username(), "Nelly");
+    assert_eq!(message.mention_roles, [Snowflake::new(41771983423143936)]);
+    assert!(message.pinned);
+    assert!(message.edited_timestamp.is_none());
+
+    let data: MessageUpdateObject = from_value(json!({
+        "id": "1067461416862814210",
+        "channel_id": "41771983423143937",
+        "edited_timestamp": "2023-01-24T18:35:00.000000+00:00"
+    }))
+    .unwrap();
+    data.apply_to(&mut message);
+
+    assert!(message.edited_timestamp.is_some());
+
+    let data: MessageUpdateObject = from_value(json!({
+        "id": "1067461416862814210",
+        "channel_id": "41771983423143937",
+        "edited_timestamp": null
+    }))
+    .unwrap();
+    data.apply_to(&mut message);
+
+    assert!(message.edited_timestamp.is_none());
+}
@@ -0,0 +1,142 @@
+use rucord_api_types::{
+    events_for_intents, required_intents, GatewayDispatchEvents, GatewayIntentBits, Permissions,
+};
+use serde_json::{from_value, json, to_value};
+use strum::IntoEnumIterator;
+
+#[test]
+fn test_required_intents() {
+    let cases = [
+        (
+            GatewayDispatchEvents::MessageCreate,
+            GatewayIntentBits::GuildMessages | GatewayIntentBits::DirectMessages,
+        ),
+        (
+            GatewayDispatchEvents::ThreadMembersUpdate,
+            GatewayIntentBits::Guilds | GatewayIntentBits::GuildMembers,
+        ),
+        (
+            GatewayDispatchEvents::GuildBanAdd,
+            GatewayIntentBits::GuildModeration,
+        ),
+        (
+            GatewayDispatchEvents::TypingStart,
+            GatewayIntentBits::GuildMessageTyping | GatewayIntentBits::DirectMessageTyping,
+        ),
+        (GatewayDispatchEvents::Ready, GatewayIntentBits::empty()),
+        (
+            GatewayDispatchEvents::InteractionCreate,
+            GatewayIntentBits::empty(),
+        ),
+    ];
+
+    for (event, intents) in cases {
+        assert_eq!(required_intents(event), intents, "{event}");
+    }
+}
+
+#[test]
+fn test_events_for_intents() {
+    let events = events_for_intents(GatewayIntentBits::GuildMessages);
+
+    assert!(events.contains(&GatewayDispatchEvents::MessageDeleteBulk));
+    assert!(events.contains(&GatewayDispatchEvents::Ready));
+    assert!(!events.contains(&GatewayDispatchEvents::GuildCreate));
+    assert!(!events.contains(&GatewayDispatchEvents::ChannelPinsUpdate));
+}
+
+#[test]
+fn test_events_for_intents_is_inverse() {
+    assert_eq!(
+        events_for_intents(GatewayIntentBits::all()),
+        GatewayDispatchEvents::iter().collect::<Vec<_>>()
+    );
+
+    for event in GatewayDispatchEvents::iter() {
+        let required = required_intents(event);
+
+        assert!(events_for_intents(required).contains(&event), "{event}");
+
+        if !required.is_empty() {
+            assert!(
+                !events_for_intents(GatewayIntentBits::all() - required).contains(&event),
+                "{event}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deserialize_intents() {
+    let intents = GatewayIntentBits::Guilds
+        | GatewayIntentBits::GuildMessages
+        | GatewayIntentBits::MessageContent;
+
+    assert_eq!(
+        from_value::<GatewayIntentBits>(json!(intents.bits())).unwrap(),
+        intents
+    );
+    assert_eq!(
+        from_value::<GatewayIntentBits>(json!(["Guilds", "GuildMessages", "MessageContent"]))
+            .unwrap(),
+        intents
+    );
+    assert!(from_value::<GatewayIntentBits>(json!(["Guilds", "Guild"])).is_err());
+    assert_eq!(to_value(intents).unwrap(), json!(intents.bits()));
+}
+
+#[test]
+fn test_intent_names() {
+    assert_eq!(
+        GatewayIntentBits::try_from("GuildModeration"),
+        Ok(GatewayIntentBits::GuildModeration)
+    );
+    assert!(GatewayIntentBits::try_from("guilds").is_err());
+
+    let intents = GatewayIntentBits::DirectMessages | GatewayIntentBits::Guilds;
+
+    assert_eq!(intents.to_name_list(), ["Guilds", "DirectMessages"]);
+    assert_eq!(
+        GatewayIntentBits::all().to_name_list().len(),
+        GatewayIntentBits::all().bits().count_ones() as usize
+    );
+}
+
+#[test]
+fn test_intents_from_permission_bits() {
+    assert_eq!(
+        GatewayIntentBits::from_permission_bits(Permissions::BanMembers),
+        GatewayIntentBits::GuildModeration
+    );
+    assert_eq!(
+        GatewayIntentBits::from_permission_bits(
+            Permissions::ManageGuild | Permissions::ManageMessages
+        ),
+        GatewayIntentBits::GuildIntegrations
+            | GatewayIntentBits::AutoModerationConfiguration
+            | GatewayIntentBits::GuildMessages
+    );
+    assert_eq!(
+        GatewayIntentBits::from_permission_bits(Permissions::empty()),
+        GatewayIntentBits::empty()
+    );
+
+    let all = GatewayIntentBits::from_permission_bits(Permissions::Administrator);
+    assert!(!all.intersects(
+        GatewayIntentBits::GuildMembers
+            | GatewayIntentBits::GuildPresences
+            | GatewayIntentBits::MessageContent
+    ));
+}
+
+#[test]
+fn test_suggested_permissions() {
+    let permissions = GatewayIntentBits::GuildModeration.suggested_permissions();
+    assert!(permissions.contains(Permissions::BanMembers | Permissions::KickMembers));
+    assert!(!permissions.contains(Permissions::SendMessages));
+
+    assert_eq!(
+        GatewayIntentBits::MessageContent.suggested_permissions(),
+        Permissions::empty()
+    );
+}
@@ -0,0 +1,177 @@
+use rucord_api_types::{
+    ChannelType, GuildObject, GuildUpdateObject, IntegrationExpireBehavior, IntegrationObject,
+    Snowflake,
+};
+use serde_json::{from_value, json};
+
+#[test]
+fn test_guild_apply_update() {
+    let mut guild: GuildObject = from_value(json!({
+        "id": "197038439483310086",
+        "name": "Discord Developers",
+        "icon": "f64c482b807da4f539cff778d174971c",
+        "description": "The official place to report Discord bugs!",
+        "features": ["COMMUNITY"],
+        "member_count": 42
+    }))
+    .unwrap();
+
+    let update: GuildUpdateObject = from_value(json!({
+        "id": "197038439483310086",
+        "name": "Discord Testers",
+        "afk_timeout": 300,
+        "features": ["COMMUNITY", "NEWS"]
+    }))
+    .unwrap();
+
+    guild.apply_update(update);
+
+    assert_eq!(guild.id, Snowflake::new(197038439483310086));
+    assert_eq!(guild.name, "Discord Testers");
+    assert_eq!(guild.afk_timeout, Some(300));
+    assert_eq!(guild.features, ["COMMUNITY", "NEWS"]);
+    assert_eq!(
+        guild.icon.as_deref(),
+        Some("f64c482b807da4f539cff778d174971c")
+    );
+    assert_eq!(
+        guild.description.as_deref(),
+        Some("The official place to report Discord bugs!")
+    );
+    assert_eq!(guild.member_count, Some(42));
+
+    let update: GuildUpdateObject = from_value(json!({
+        "id": "197038439483310086",
+        "name": "Discord Testers",
+        "icon": null,
+        "description": null
+    }))
+    .unwrap();
+
+    guild.apply_update(update);
+
+    assert!(guild.icon.is_none());
+    assert!(guild.description.is_none());
+    assert_eq!(guild.afk_timeout, Some(300));
+}
+
+#[test]
+fn test_guild_create_members() {
+    let guild: GuildObject = from_value(json!({
+        "id": "197038439483310086",
+        "name": "Discord Developers",
+        "members": [{
+            "user": {
+                "id": "80351110224678912",
+                "username": "Nelly",
+                "discriminator": "1337",
+                "avatar": null
+            },
+            "nick": "NOT API SUPPORT",
+            "roles": ["41771983423143936"],
+            "joined_at": "2015-04-26T06:26:56.936000+00:00",
+            "deaf": false,
+            "mute": false
+        }]
+    }))
+    .unwrap();
+
+    let [member] = &guild.members[..] else {
+        panic!("expected 1 member");
+    };
+
+    assert_eq!(member.nick.as_deref(), Some("NOT API SUPPORT"));
+    assert_eq!(member.roles, [Snowflake::new(41771983423143936)]);
+}
+
+#[test]
+fn test_integration_deserialize() {
+    let integration: IntegrationObject = from_value(json!({
+        "id": "41771983423143937",
+        "name": "Twitch",
+        "type": "twitch",
+        "enabled": true,
+        "syncing": false,
+        "role_id": "41771983423143938",
+        "expire_behavior": 1,
+        "expire_grace_period": 7,
+        "account": { "id": "twitch-user", "name": "streamer" }
+    }))
+    .unwrap();
+
+    assert_eq!(integration.ty, "twitch");
+    assert_eq!(
+        integration.expire_behavior,
+        Some(IntegrationExpireBehavior::Kick)
+    );
+    assert_eq!(integration.account.id, "twitch-user");
+    assert!(integration.application.is_none());
+}
+
+#[test]
+fn test_guild_find_by_name() {
+    let role = |id: &str, name: &str| {
+        json!({
+            "id": id,
+            "name": name,
+            "color": 0,
+            "hoist": false,
+            "position": 1,
+            "permissions": "0",
+            "managed": false,
+            "mentionable": false
+        })
+    };
+
+    let guild: GuildObject = from_value(json!({
+        "id": "197038439483310086",
+        "name": "Discord Developers",
+        "roles": [
+            role("1", "Moderator"),
+            role("2", "Straße"),
+            role("3", "moderator")
+        ],
+        "channels": [
+            { "id": "10", "type": 0, "name": "General" },
+            { "id": "11", "type": 2, "name": "Voice" },
+            { "id": "12", "type": 2, "name": "AFK" },
+            { "id": "13", "type": 4 }
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        guild.find_role_by_name("MODERATOR").map(|role| role.id),
+        Some(Snowflake::new(1))
+    );
+    assert_eq!(
+        guild
+            .find_roles_by_name("moderator")
+            .iter()
+            .map(|role| role.id)
+            .collect::<Vec<_>>(),
+        [Snowflake::new(1), Snowflake::new(3)]
+    );
+    assert_eq!(
+        guild.find_role_by_name("STRASSE").map(|role| role.id),
+        Some(Snowflake::new(2))
+    );
+    assert!(guild.find_role_by_name("Admin").is_none());
+
+    assert_eq!(
+        guild
+            .find_channel_by_name("general")
+            .map(|channel| channel.id),
+        Some(Snowflake::new(10))
+    );
+    assert!(guild.find_channel_by_name("").is_none());
+
+    assert_eq!(
+        guild
+            .channels_of_type(ChannelType::GuildVoice)
+            .iter()
+            .map(|channel| channel.id)
+            .collect::<Vec<_>>(),
+        [Snowflake::new(11), Snowflake::new(12)]
+    );
+}
@@ -0,0 +1,71 @@
+use rucord_api_types::{
+    CreateGuildScheduledEventBody, GuildScheduledEventEntityMetadata,
+    GuildScheduledEventEntityType, GuildScheduledEventObject, GuildScheduledEventPrivacyLevel,
+    GuildScheduledEventStatus, Snowflake,
+};
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_guild_scheduled_event_deserialize() {
+    let event: GuildScheduledEventObject = from_value(json!({
+        "id": "1083425287345029200",
+        "guild_id": "197038439483310086",
+        "channel_id": null,
+        "creator_id": "80351110224678912",
+        "name": "Game night",
+        "description": "Bring snacks",
+        "scheduled_start_time": "2023-03-10T20:00:00+00:00",
+        "scheduled_end_time": "2023-03-10T23:00:00+00:00",
+        "privacy_level": 2,
+        "status": 1,
+        "entity_type": 3,
+        "entity_id": null,
+        "entity_metadata": { "location": "Wumpus' house" },
+        "user_count": 12,
+        "image": null
+    }))
+    .unwrap();
+
+    assert_eq!(event.id, Snowflake::new(1083425287345029200));
+    assert!(event.channel_id.is_none());
+    assert_eq!(
+        event.privacy_level,
+        GuildScheduledEventPrivacyLevel::GuildOnly
+    );
+    assert_eq!(event.status, GuildScheduledEventStatus::Scheduled);
+    assert_eq!(event.entity_type, GuildScheduledEventEntityType::External);
+    assert_eq!(
+        event.entity_metadata.and_then(|metadata| metadata.location),
+        Some("Wumpus' house".into())
+    );
+    assert_eq!(event.user_count, Some(12));
+}
+
+#[test]
+fn test_create_guild_scheduled_event_body() {
+    let body = CreateGuildScheduledEventBody {
+        channel_id: None,
+        entity_metadata: Some(GuildScheduledEventEntityMetadata {
+            location: Some("Wumpus' house".into()),
+        }),
+        name: "Game night".into(),
+        privacy_level: Default::default(),
+        scheduled_start_time: "2023-03-10T20:00:00+00:00".into(),
+        scheduled_end_time: Some("2023-03-10T23:00:00+00:00".into()),
+        description: None,
+        entity_type: GuildScheduledEventEntityType::External,
+        image: None,
+    };
+
+    assert_eq!(
+        to_value(body).unwrap(),
+        json!({
+            "entity_metadata": { "location": "Wumpus' house" },
+            "name": "Game night",
+            "privacy_level": 2,
+            "scheduled_start_time": "2023-03-10T20:00:00+00:00",
+            "scheduled_end_time": "2023-03-10T23:00:00+00:00",
+            "entity_type": 3
+        })
+    );
+}
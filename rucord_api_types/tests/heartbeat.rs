@@ -0,0 +1,18 @@
+use rucord_api_types::GatewaySendPayload;
+use serde_json::to_string;
+
+#[test]
+fn test_heartbeat_without_sequence() {
+    assert_eq!(
+        to_string(&GatewaySendPayload::Heartbeat(None)).unwrap(),
+        r#"{"op":1,"d":null}"#
+    );
+}
+
+#[test]
+fn test_heartbeat_with_sequence() {
+    assert_eq!(
+        to_string(&GatewaySendPayload::Heartbeat(Some(42))).unwrap(),
+        r#"{"op":1,"d":42}"#
+    );
+}
@@ -0,0 +1,24 @@
+use rucord_api_types::{GatewaySendPayload, IdentifyData, PresenceStateType, UpdatePresenceData};
+use serde_json::{json, to_value};
+
+#[test]
+fn test_identify_presence() {
+    let data = IdentifyData {
+        token: "token".into(),
+        presence: Some(UpdatePresenceData {
+            since: None,
+            activities: vec![json!({ "name": "with Rust", "type": 0 })],
+            status: PresenceStateType::Dnd,
+            afk: false,
+        }),
+        ..Default::default()
+    };
+
+    let payload = to_value(GatewaySendPayload::from(data)).unwrap();
+
+    assert_eq!(payload["d"]["presence"]["status"], "dnd");
+    assert_eq!(
+        payload["d"]["presence"]["activities"],
+        json!([{ "name": "with Rust", "type": 0 }])
+    );
+}
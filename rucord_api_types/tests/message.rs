@@ -0,0 +1,189 @@
+use rucord_api_types::{
+    AllowedMentionType, AllowedMentionsObject, CreateMessageBody, MessageObject, MessageType,
+    Snowflake, ValidationError,
+};
+use serde_json::json;
+
+#[test]
+fn test_allowed_mentions_none() {
+    assert_eq!(
+        serde_json::to_value(AllowedMentionsObject::none()).unwrap(),
+        json!({ "parse": [], "roles": [], "users": [], "replied_user": false })
+    )
+}
+
+#[test]
+fn test_allowed_mentions_deserialize() {
+    let mentions: AllowedMentionsObject =
+        serde_json::from_value(json!({ "parse": ["everyone", "users"] })).unwrap();
+
+    assert_eq!(
+        mentions.parse,
+        vec![AllowedMentionType::Everyone, AllowedMentionType::Users]
+    );
+    assert!(!mentions.replied_user);
+}
+
+#[test]
+fn test_create_message_body_builder() {
+    let body = CreateMessageBody::builder()
+        .content("pong")
+        .reply_to(&Snowflake::new(1234))
+        .suppress_pings()
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(body).unwrap(),
+        json!({
+            "content": "pong",
+            "allowed_mentions": { "parse": [], "roles": [], "users": [], "replied_user": false },
+            "message_reference": { "message_id": "1234" }
+        })
+    )
+}
+
+#[test]
+fn test_create_message_body_builder_empty() {
+    assert_eq!(
+        CreateMessageBody::builder()
+            .suppress_pings()
+            .build()
+            .unwrap_err(),
+        ValidationError::EmptyMessage
+    );
+}
+
+fn message(message_type: u8, bot: bool, referenced: Option<serde_json::Value>) -> MessageObject {
+    serde_json::from_value(json!({
+        "id": "2",
+        "channel_id": "1",
+        "author": { "id": "3", "username": "user", "discriminator": "0001", "avatar": null, "bot": bot },
+        "content": "hi",
+        "timestamp": "2021-01-01T00:00:00.000000+00:00",
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "pinned": false,
+        "type": message_type,
+        "referenced_message": referenced
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_message_helpers() {
+    let default = message(0, false, None);
+    assert!(!default.is_from_bot());
+    assert!(!default.is_system());
+    assert!(!default.is_reply());
+
+    assert!(message(0, true, None).is_from_bot());
+    assert!(message(7, false, None).is_system());
+    assert!(!message(19, false, None).is_reply());
+
+    let original = serde_json::to_value(&default).unwrap();
+    assert!(message(19, false, Some(original)).is_reply());
+}
+
+#[test]
+fn test_message_reply() {
+    let (channel_id, body) = message(0, false, None)
+        .reply("pong")
+        .build_for_channel()
+        .unwrap();
+
+    assert_eq!(channel_id, Some(Snowflake::new(1)));
+    assert_eq!(body.content.as_deref(), Some("pong"));
+    assert_eq!(
+        body.message_reference
+            .and_then(|reference| reference.message_id),
+        Some(Snowflake::new(2))
+    );
+}
+
+#[test]
+fn test_reply_to_message() {
+    let body = CreateMessageBody::builder()
+        .content("pong")
+        .reply_to_message(&message(0, false, None))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(body.message_reference).unwrap(),
+        json!({ "message_id": "2", "channel_id": "1", "fail_if_not_exists": false })
+    );
+}
+
+#[test]
+fn test_message_mentions() {
+    let mut value = serde_json::to_value(message(0, false, None)).unwrap();
+
+    value["mentions"] = json!([
+        {
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": null,
+            "member": { "nick": "nelly", "roles": ["41771983423143936"] }
+        },
+        { "id": "3", "username": "user", "discriminator": "0001", "avatar": null }
+    ]);
+    value["mention_roles"] = json!(["41771983423143936"]);
+
+    let message: MessageObject = serde_json::from_value(value).unwrap();
+
+    assert_eq!(
+        message.mentioned_user_ids(),
+        [&Snowflake::new(80351110224678912), &Snowflake::new(3)]
+    );
+    assert_eq!(
+        message.mentions[0]
+            .member
+            .as_ref()
+            .and_then(|member| member.nick.as_deref()),
+        Some("nelly")
+    );
+    assert!(message.mentions[1].member.is_none());
+    assert_eq!(message.mention_roles, [Snowflake::new(41771983423143936)]);
+}
+
+#[test]
+fn test_strip_mentions() {
+    let mut value = serde_json::to_value(message(0, false, None)).unwrap();
+    value["content"] = json!("<@!3> hi <@&4> in <#5>, <@6>");
+
+    let unresolved: MessageObject = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(unresolved.strip_mentions(), " hi  in , ");
+
+    value["mentions"] = json!([
+        { "id": "3", "username": "user", "discriminator": "0001", "avatar": null }
+    ]);
+    value["mention_roles"] = json!(["4"]);
+
+    let resolved: MessageObject = serde_json::from_value(value).unwrap();
+    assert_eq!(resolved.strip_mentions(), " hi  in , <@6>");
+}
+
+#[test]
+fn test_strip_formatting() {
+    let mut value = serde_json::to_value(message(0, false, None)).unwrap();
+    value["content"] = json!("**bold** *italic* __under__ ~~strike~~ `code` ***both*** <@3>");
+
+    let message: MessageObject = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        message.strip_formatting(),
+        "bold italic under strike code both <@3>"
+    );
+    assert_eq!(message.strip_all(), "bold italic under strike code both ");
+}
+
+#[test]
+fn test_unknown_message_type() {
+    let message = message(255, false, None);
+
+    assert_eq!(message.message_type, MessageType::Unknown(255));
+    assert!(message.is_system());
+    assert_eq!(serde_json::to_value(message).unwrap()["type"], json!(255));
+}
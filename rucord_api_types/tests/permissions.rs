@@ -0,0 +1,32 @@
+use rucord_api_types::Permissions;
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_permissions_serde() {
+    let permissions: Permissions = from_value(json!("2147483648")).unwrap();
+
+    assert_eq!(permissions, Permissions::UseApplicationCommands);
+    assert_eq!(to_value(permissions).unwrap(), json!("2147483648"));
+
+    let permissions: Permissions = from_value(json!(1099511627776u64)).unwrap();
+
+    assert_eq!(permissions, Permissions::ModerateMembers);
+    assert!(from_value::<Permissions>(json!("admin")).is_err());
+}
+
+#[test]
+fn test_permissions_has() {
+    let permissions = Permissions::SendMessages | Permissions::ViewChannel;
+
+    assert!(permissions.has(Permissions::SendMessages));
+    assert!(!permissions.has(Permissions::SendMessages | Permissions::EmbedLinks));
+    assert_eq!(
+        permissions.missing(Permissions::SendMessages | Permissions::EmbedLinks),
+        Permissions::EmbedLinks
+    );
+
+    assert!(Permissions::Administrator.has(Permissions::all()));
+    assert!(Permissions::Administrator
+        .missing(Permissions::BanMembers)
+        .is_empty());
+}
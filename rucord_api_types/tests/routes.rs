@@ -0,0 +1,31 @@
+use rucord_api_types::routes;
+
+#[test]
+fn test_route_name() {
+    assert_eq!(routes::gateway_bot::route_name(), "/gateway/bot");
+    assert_eq!(
+        routes::channel_messages::route_name(),
+        "/channels/{channel_id}/messages"
+    );
+    assert_eq!(
+        routes::channel_messages(&123.into()),
+        "/channels/123/messages"
+    );
+}
+
+#[test]
+fn test_route_name_of() {
+    assert_eq!(
+        routes::route_name_of(&routes::channel_message(&1.into(), &2.into())),
+        Some(routes::channel_message::route_name())
+    );
+    assert_eq!(
+        routes::route_name_of(&routes::guild_members_search(&1.into())),
+        Some(routes::guild_members_search::route_name())
+    );
+    assert_eq!(
+        routes::route_name_of(&routes::guild_member(&1.into(), &2.into())),
+        Some(routes::guild_member::route_name())
+    );
+    assert_eq!(routes::route_name_of("/unknown/1"), None);
+}
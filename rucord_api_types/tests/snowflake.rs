@@ -0,0 +1,37 @@
+use rucord_api_types::{Snowflake, SnowflakeError, WebhooksUpdateObject};
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_snowflake_parse() {
+    assert_eq!(
+        Snowflake::parse("175928847299117063"),
+        Ok(Snowflake::new(175928847299117063))
+    );
+    assert_eq!(Snowflake::parse(""), Err(SnowflakeError::Empty));
+    assert_eq!(Snowflake::parse("12a"), Err(SnowflakeError::NotNumeric));
+    assert_eq!(Snowflake::parse("-1"), Err(SnowflakeError::NotNumeric));
+    assert_eq!(
+        Snowflake::parse("18446744073709551616"),
+        Err(SnowflakeError::TooLarge)
+    );
+    assert_eq!("42".parse(), Ok(Snowflake::new(42)));
+}
+
+#[test]
+fn test_snowflake_serde() {
+    let id = Snowflake::new(175928847299117063);
+
+    assert_eq!(to_value(id).unwrap(), json!("175928847299117063"));
+    assert_eq!(
+        from_value::<Snowflake>(json!("175928847299117063")).unwrap(),
+        id
+    );
+
+    let err = from_value::<WebhooksUpdateObject>(json!({
+        "guild_id": "",
+        "channel_id": "1"
+    }))
+    .unwrap_err();
+
+    assert!(err.to_string().contains("invalid snowflake"));
+}
@@ -0,0 +1,60 @@
+use rucord_api_types::{
+    NitroStickerPacksObject, Snowflake, StickerFormatType, StickerObject, StickerType,
+};
+use serde_json::{from_value, json};
+
+#[test]
+fn test_guild_sticker() {
+    let sticker: StickerObject = from_value(json!({
+        "id": "749054660769218631",
+        "name": "Wave",
+        "tags": "wumpus, hello, sup, hi, oi, heyo, heya, yo, wave",
+        "type": 2,
+        "format_type": 3,
+        "description": "Wumpus waves hello",
+        "asset": "",
+        "available": true,
+        "guild_id": "197038439483310086"
+    }))
+    .unwrap();
+
+    assert_eq!(sticker.id, Snowflake::new(749054660769218631));
+    assert_eq!(sticker.ty, StickerType::Guild);
+    assert_eq!(sticker.format_type, StickerFormatType::Lottie);
+    assert!(sticker.pack_id.is_none());
+    assert!(sticker.user.is_none());
+}
+
+#[test]
+fn test_nitro_sticker_packs() {
+    let packs: NitroStickerPacksObject = from_value(json!({
+        "sticker_packs": [{
+            "id": "847199849233514549",
+            "stickers": [{
+                "id": "749054660769218631",
+                "pack_id": "847199849233514549",
+                "name": "Wave",
+                "description": "Wumpus waves hello",
+                "tags": "wave",
+                "type": 1,
+                "format_type": 3,
+                "sort_value": 12
+            }],
+            "name": "Wumpus Beyond",
+            "sku_id": "847199849233514547",
+            "cover_sticker_id": "749054660769218631",
+            "description": "Say hello to Wumpus!",
+            "banner_asset_id": "761773777976819732"
+        }]
+    }))
+    .unwrap();
+
+    let pack = &packs.sticker_packs[0];
+    assert_eq!(pack.name, "Wumpus Beyond");
+    assert_eq!(
+        pack.cover_sticker_id,
+        Some(Snowflake::new(749054660769218631))
+    );
+    assert_eq!(pack.stickers[0].ty, StickerType::Standard);
+    assert_eq!(pack.stickers[0].sort_value, Some(12));
+}
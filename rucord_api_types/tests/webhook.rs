@@ -0,0 +1,69 @@
+use rucord_api_types::{routes, Snowflake, WebhookObject, WebhookType};
+use serde_json::{from_value, json};
+
+#[test]
+fn test_incoming_webhook() {
+    let webhook: WebhookObject = from_value(json!({
+        "id": "223704706495545344",
+        "type": 1,
+        "guild_id": "199737254929760256",
+        "channel_id": "199737254929760256",
+        "user": {
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": null
+        },
+        "name": "test webhook",
+        "avatar": null,
+        "token": "3d89bb7572e0fb30d8128367b3b1b44fecd1726de135cbe28a41f8b2f777c372ba2939e72279b94526ff5d1bd4358d65cf11",
+        "application_id": null
+    }))
+    .unwrap();
+
+    assert_eq!(webhook.ty, WebhookType::Incoming);
+    assert_eq!(webhook.guild_id, Some(Snowflake::new(199737254929760256)));
+    assert!(webhook.user.is_some());
+    assert!(webhook.token.is_some());
+    assert!(webhook.source_guild.is_none());
+}
+
+#[test]
+fn test_channel_follower_webhook() {
+    let webhook: WebhookObject = from_value(json!({
+        "id": "752831914402115456",
+        "type": 2,
+        "guild_id": "199737254929760256",
+        "channel_id": "561885260615255432",
+        "name": "Guildy name",
+        "avatar": null,
+        "source_guild": {
+            "id": "613425648685547541",
+            "name": "Discord Developers",
+            "icon": "7aeac10f89ee47e1a7ad3b5d1ec8b3bd"
+        },
+        "source_channel": {
+            "id": "697138785317814292",
+            "name": "announcements"
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(webhook.ty, WebhookType::ChannelFollower);
+    assert!(webhook.token.is_none());
+    assert_eq!(webhook.source_guild.unwrap().name, "Discord Developers");
+    assert_eq!(
+        webhook.source_channel.unwrap().id,
+        Snowflake::new(697138785317814292)
+    );
+}
+
+#[test]
+fn test_webhook_routes() {
+    assert_eq!(
+        routes::channel_webhooks(&123.into()),
+        "/channels/123/webhooks"
+    );
+    assert_eq!(routes::guild_webhooks(&456.into()), "/guilds/456/webhooks");
+    assert_eq!(routes::webhook(&789.into()), "/webhooks/789");
+}
@@ -0,0 +1,45 @@
+use reqwest::multipart::Part;
+
+/// A file uploaded with a multipart request.
+#[derive(Debug, Clone)]
+pub struct FileAttachment {
+    pub filename: String,
+
+    pub data: Vec<u8>,
+}
+
+impl FileAttachment {
+    #[inline]
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            filename: filename.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Guesses the MIME type from the file extension, for the formats Discord accepts.
+    pub fn content_type(&self) -> &'static str {
+        let extension = self
+            .filename
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("png" | "apng") => "image/png",
+            Some("gif") => "image/gif",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("webp") => "image/webp",
+            Some("json") => "application/json",
+            _ => "application/octet-stream",
+        }
+    }
+
+    pub(crate) fn into_part(self) -> Part {
+        let content_type = self.content_type();
+
+        Part::bytes(self.data)
+            .file_name(self.filename)
+            .mime_str(content_type)
+            .expect("the guessed MIME types are valid")
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::RouteId;
+
+/// When to stop sending requests to a route that keeps failing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerPolicy {
+    /// How many consecutive failures open the circuit.
+    pub failure_threshold: u32,
+
+    /// How long an open circuit rejects the requests before letting a probe through.
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitBreakerState {
+    /// Requests are sent.
+    Closed,
+
+    /// Requests are rejected, the circuit opened at the given instant.
+    Open(Instant),
+
+    /// A single probe request is in flight, the others are rejected.
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RouteCircuit {
+    state: CircuitBreakerState,
+    failures: u32,
+}
+
+impl Default for RouteCircuit {
+    fn default() -> Self {
+        Self {
+            state: CircuitBreakerState::Closed,
+            failures: 0,
+        }
+    }
+}
+
+/// Tracks the consecutive failures of every route, a failure is a network error or a 5xx.
+/// Routes are keyed by their template, e.g. `/channels/{channel_id}/messages`, so the failures
+/// of every channel count together.
+#[derive(Default)]
+pub struct CircuitBreaker {
+    policy: CircuitBreakerPolicy,
+
+    routes: Mutex<HashMap<RouteId, RouteCircuit>>,
+}
+
+impl CircuitBreaker {
+    pub fn new(policy: CircuitBreakerPolicy) -> Self {
+        Self {
+            policy,
+            routes: Default::default(),
+        }
+    }
+
+    pub fn state(&self, route: &RouteId) -> CircuitBreakerState {
+        self.routes
+            .lock()
+            .unwrap()
+            .get(route)
+            .map_or(CircuitBreakerState::Closed, |circuit| circuit.state)
+    }
+
+    /// A permit to send a request to the route, `None` while the circuit is open. An open
+    /// circuit lets a single probe through once `open_duration` elapsed, the probe counts as a
+    /// failure if its permit is dropped before the outcome is recorded.
+    pub fn try_acquire(&self, route: &RouteId) -> Option<CircuitPermit<'_>> {
+        let mut routes = self.routes.lock().unwrap();
+
+        let probe = match routes.get_mut(route) {
+            None => false,
+            Some(circuit) => match circuit.state {
+                CircuitBreakerState::Closed => false,
+                CircuitBreakerState::Open(opened_at)
+                    if opened_at.elapsed() >= self.policy.open_duration =>
+                {
+                    circuit.state = CircuitBreakerState::HalfOpen;
+                    true
+                }
+                CircuitBreakerState::Open(_) | CircuitBreakerState::HalfOpen => return None,
+            },
+        };
+
+        Some(CircuitPermit {
+            breaker: self,
+            route: route.clone(),
+            probe,
+        })
+    }
+
+    pub fn record_success(&self, route: &RouteId) {
+        // Healthy routes aren't kept, only the map would hold them.
+        self.routes.lock().unwrap().remove(route);
+    }
+
+    pub fn record_failure(&self, route: &RouteId) {
+        let mut routes = self.routes.lock().unwrap();
+        let circuit = routes.entry(route.clone()).or_default();
+
+        circuit.failures += 1;
+
+        if circuit.state == CircuitBreakerState::HalfOpen
+            || circuit.failures >= self.policy.failure_threshold
+        {
+            circuit.state = CircuitBreakerState::Open(Instant::now());
+        }
+    }
+}
+
+/// Allows a request to a route, see [`CircuitBreaker::try_acquire`].
+#[must_use = "a dropped probe permit reopens the circuit"]
+pub struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    route: RouteId,
+    probe: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Whether the request is the probe of an open circuit.
+    #[inline]
+    pub fn is_probe(&self) -> bool {
+        self.probe
+    }
+
+    pub fn record_success(mut self) {
+        self.probe = false;
+        self.breaker.record_success(&self.route);
+    }
+
+    pub fn record_failure(mut self) {
+        self.probe = false;
+        self.breaker.record_failure(&self.route);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        // The probe was cancelled, the route would stay half-open forever otherwise.
+        if self.probe {
+            self.breaker.record_failure(&self.route);
+        }
+    }
+}
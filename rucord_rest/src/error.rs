@@ -0,0 +1,42 @@
+use derive_more::{Display, Error, From};
+use serde::Deserialize;
+
+use rucord_api_types::ValidationError;
+
+use crate::RouteId;
+
+/// The JSON error code Discord sends with a 403 when the bot lacks a permission.
+pub const MISSING_PERMISSIONS_CODE: u32 = 50013;
+
+#[derive(Debug, From, Error, Display)]
+pub enum RequestError {
+    #[display(fmt = "{_0}")]
+    Reqwest(reqwest::Error),
+    /// The route kept failing, so the request wasn't sent. See [`CircuitBreakerPolicy`](crate::CircuitBreakerPolicy).
+    #[display(
+        fmt = "The {} {} route is temporarily disabled after repeated failures",
+        "route.method",
+        "route.path"
+    )]
+    #[from(ignore)]
+    CircuitOpen { route: RouteId },
+    /// The bot lacks a permission required by the endpoint.
+    #[display(fmt = "Missing permissions: {message}")]
+    #[from(ignore)]
+    Forbidden {
+        #[error(not(source))]
+        message: String,
+    },
+    /// An argument Discord would reject, the request wasn't sent.
+    #[display(fmt = "{_0}")]
+    Validation(ValidationError),
+}
+
+/// The body of a Discord error response.
+/// [Discord documentation](https://discord.com/developers/docs/reference#error-messages).
+#[derive(Debug, Deserialize)]
+pub(crate) struct ErrorBody {
+    pub code: u32,
+
+    pub message: String,
+}
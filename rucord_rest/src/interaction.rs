@@ -0,0 +1,54 @@
+use reqwest::multipart::Form;
+use rucord_api_types::{ActionRowObject, AllowedMentionsObject};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::FileAttachment;
+
+/// The body of a Create or Edit Followup Message request.
+/// [Discord documentation](https://discord.com/developers/docs/interactions/receiving-and-responding#create-followup-message).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CreateFollowupBody {
+    /// Message contents (up to 2000 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// true if this is a TTS message, ignored when editing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    // TODO: When write EmbedObject.
+    /// Up to 10 rich embeds (up to 6000 characters).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Value>>,
+
+    /// Allowed mentions for the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsObject>,
+
+    /// Components to include with the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<ActionRowObject>>,
+
+    /// Files to upload with the message, sent as `multipart/form-data`.
+    #[serde(skip)]
+    pub files: Vec<FileAttachment>,
+
+    /// [Message flags](https://discord.com/developers/docs/resources/channel#message-object-message-flags),
+    /// e.g. `1 << 6` for an ephemeral followup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+}
+
+impl CreateFollowupBody {
+    /// The files as `files[n]` parts, with the rest of the body as `payload_json`.
+    pub(crate) fn into_form(mut self) -> Form {
+        let files = std::mem::take(&mut self.files);
+        let payload = serde_json::to_string(&self).expect("the body is valid JSON");
+
+        files.into_iter().enumerate().fold(
+            Form::new().text("payload_json", payload),
+            |form, (i, file)| form.part(format!("files[{i}]"), file.into_part()),
+        )
+    }
+}
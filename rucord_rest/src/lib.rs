@@ -1,8 +1,31 @@
+pub mod attachment;
+pub mod circuit_breaker;
+pub mod error;
+pub mod interaction;
+pub mod message;
+pub mod pagination;
 pub mod request_handler;
 pub mod request_manager;
+pub mod request_queue;
+pub mod response_cache;
+pub mod tls;
 
 pub use reqwest;
 pub use reqwest::Method;
 
+pub use attachment::*;
+pub use circuit_breaker::*;
+pub use error::*;
+pub use interaction::*;
+pub use message::*;
+pub use pagination::*;
+// Empty until the request handler is written.
+#[allow(unused_imports)]
 pub use request_handler::*;
 pub use request_manager::*;
+pub use request_queue::*;
+pub use response_cache::*;
+pub use tls::*;
+
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("either the `native-tls` or the `rustls` feature must be enabled");
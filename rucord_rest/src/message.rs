@@ -0,0 +1,27 @@
+use std::future::Future;
+
+use rucord_api_types::{CreateMessageBodyBuilder, MessageObject, ValidationError};
+
+use crate::{RequestError, RequestManager};
+
+/// Sends a built message without going through [`RequestManager::send_message`], e.g.
+/// `message.reply("pong").send(&rest).await`.
+pub trait SendMessageExt {
+    /// Fails with a [`ValidationError`] if the channel isn't set, or the message has no content,
+    /// embeds or components.
+    fn send(
+        self,
+        rest: &RequestManager,
+    ) -> impl Future<Output = Result<MessageObject, RequestError>> + Send;
+}
+
+impl SendMessageExt for CreateMessageBodyBuilder {
+    async fn send(self, rest: &RequestManager) -> Result<MessageObject, RequestError> {
+        let (channel_id, body) = self.build_for_channel()?;
+        let channel_id = channel_id.ok_or(ValidationError::Missing {
+            field: "channel_id",
+        })?;
+
+        rest.send_message(&channel_id, body).await
+    }
+}
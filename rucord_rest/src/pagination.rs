@@ -0,0 +1,74 @@
+use std::future::Future;
+
+use futures::{stream, Stream};
+use rucord_api_types::Snowflake;
+
+use crate::RequestError;
+
+/// The most items Discord returns in a single page.
+pub const PAGE_LIMIT: usize = 100;
+
+/// Which side of the cursor the pages are fetched from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationDirection {
+    /// Older items, with smaller ids.
+    Before,
+    /// Newer items, with larger ids.
+    After,
+}
+
+impl PaginationDirection {
+    /// The query param holding the cursor.
+    #[inline]
+    pub fn query_key(&self) -> &'static str {
+        match self {
+            Self::Before => "before",
+            Self::After => "after",
+        }
+    }
+
+    /// The cursor of the page following the given ids, regardless of their order.
+    pub fn next_cursor(&self, ids: impl Iterator<Item = Snowflake>) -> Option<Snowflake> {
+        match self {
+            Self::Before => ids.min(),
+            Self::After => ids.max(),
+        }
+    }
+}
+
+/// Fetches the pages until one has less than [`PAGE_LIMIT`] items, or a request fails. The stream
+/// also ends after a page with an item `id` can't tell the id of, since the cursor would be lost.
+pub fn paginate<T, F, Fut>(
+    direction: PaginationDirection,
+    starting_from: Option<Snowflake>,
+    fetch: F,
+    id: fn(&T) -> Option<Snowflake>,
+) -> impl Stream<Item = Result<Vec<T>, RequestError>>
+where
+    F: Fn(Option<Snowflake>) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, RequestError>>,
+{
+    stream::unfold(
+        (fetch, Some(starting_from)),
+        move |(fetch, cursor)| async move {
+            let cursor = cursor?;
+
+            let page = match fetch(cursor).await {
+                Ok(page) if page.is_empty() => return None,
+                Ok(page) => page,
+                Err(err) => return Some((Err(err), (fetch, None))),
+            };
+
+            let ids: Option<Vec<_>> = page.iter().map(id).collect();
+
+            let next = match ids {
+                Some(ids) if page.len() >= PAGE_LIMIT => {
+                    direction.next_cursor(ids.into_iter()).map(Some)
+                }
+                _ => None,
+            };
+
+            Some((Ok(page), (fetch, next)))
+        },
+    )
+}
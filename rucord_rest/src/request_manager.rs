@@ -1,13 +1,59 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::RwLock, time::Duration};
 
-use reqwest::{header::AUTHORIZATION, Client, Method, Response};
-use rucord_api_types::{routes, GatewayBotObject, GatewayObject};
+use futures::Stream;
+use reqwest::{
+    header::AUTHORIZATION, multipart::Form, Client, Method, Proxy, Response, StatusCode,
+};
+use rucord_api_types::{
+    routes, AllowedMentionsObject, ApplicationCommandObject, ApplicationObject, BanObject,
+    BeginPruneBody, ChannelObject, CreateChannelInviteBody, CreateDmBody, CreateEmojiBody,
+    CreateGuildScheduledEventBody, CreateMessageBody, DiscordTimestamp, EditMessageBody,
+    EmojiObject, GatewayBotObject, GatewayObject, GetGuildScheduledEventsQuery, GetPruneCountQuery,
+    GuildMemberObject, GuildScheduledEventEntityType, GuildScheduledEventObject,
+    GuildScheduledEventUserObject, GuildWidgetObject, GuildWidgetSettingsObject, IntegrationObject,
+    InteractionResponseObject, InviteObject, MessageObject, ModifyGuildMemberBody,
+    ModifyStickerBody, NitroStickerPacksObject, PruneCountObject, RoleObject,
+    SearchGuildMembersQuery, Snowflake, StickerObject, UserObject, ValidationError, WebhookObject,
+    DISCORD_API_VERSION,
+};
 use serde::Serialize;
 
+use crate::{
+    paginate, CircuitBreaker, CircuitBreakerPolicy, CreateFollowupBody, ErrorBody, FileAttachment,
+    PaginationDirection, RequestError, RequestQueue, ResponseCache, RouteId, TlsBackend,
+    MISSING_PERMISSIONS_CODE, PAGE_LIMIT,
+};
+
+/// How long the responses of the rarely changing resources are cached.
+const RESPONSE_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// The idle time before the TCP keepalive probes are sent, when `keep_alive` is enabled.
+const TCP_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(60);
+
 #[derive(Serialize)]
 pub struct Dummy;
 pub struct RequestManagerOptions {
     pub global_rate_limit: i32,
+
+    /// How many non-`GET` requests to the same route can be in flight at once.
+    pub max_concurrency_per_route: usize,
+
+    /// When to stop sending requests to a route that keeps failing.
+    pub circuit_breaker: CircuitBreakerPolicy,
+
+    pub tls_backend: TlsBackend,
+
+    /// How many idle connections are kept open to each host.
+    pub pool_max_idle_per_host: usize,
+
+    /// How long an idle connection is kept open before being closed.
+    pub pool_idle_timeout: Duration,
+
+    /// How long to wait for a connection to be established.
+    pub connection_timeout: Duration,
+
+    /// Whether to enable TCP keepalive on the connections.
+    pub keep_alive: bool,
 }
 
 pub struct RequestOptions<T: Serialize = Dummy> {
@@ -17,7 +63,17 @@ pub struct RequestOptions<T: Serialize = Dummy> {
 
     body: Option<T>,
 
+    query: Vec<(&'static str, String)>,
+
+    form: Option<Form>,
+
     extra_headers: Option<HashMap<String, String>>,
+
+    /// Whether to omit the `Authorization` header, for the endpoints that don't need it.
+    no_auth: bool,
+
+    /// How long the response body is cached, see [`ResponseCache`].
+    cached_ttl: Option<Duration>,
 }
 
 impl<T: Serialize> RequestOptions<T> {
@@ -27,7 +83,11 @@ impl<T: Serialize> RequestOptions<T> {
             url,
             method: Method::GET,
             body: None,
+            query: vec![],
+            form: None,
             extra_headers,
+            no_auth: false,
+            cached_ttl: None,
         }
     }
 
@@ -41,9 +101,73 @@ impl<T: Serialize> RequestOptions<T> {
             url,
             method: Method::POST,
             body,
+            query: vec![],
+            form: None,
+            extra_headers,
+            no_auth: false,
+            cached_ttl: None,
+        }
+    }
+
+    #[inline]
+    pub fn patch(
+        url: String,
+        body: Option<T>,
+        extra_headers: Option<HashMap<String, String>>,
+    ) -> Self {
+        Self {
+            url,
+            method: Method::PATCH,
+            body,
+            query: vec![],
+            form: None,
             extra_headers,
+            no_auth: false,
+            cached_ttl: None,
         }
     }
+
+    #[inline]
+    pub fn delete(url: String, extra_headers: Option<HashMap<String, String>>) -> Self {
+        Self {
+            url,
+            method: Method::DELETE,
+            body: None,
+            query: vec![],
+            form: None,
+            extra_headers,
+            no_auth: false,
+            cached_ttl: None,
+        }
+    }
+
+    #[inline]
+    pub fn query(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.query.push((key, value.to_string()));
+        self
+    }
+
+    /// Sends the form as `multipart/form-data`, instead of the JSON body.
+    #[inline]
+    pub fn multipart(mut self, form: Form) -> Self {
+        self.form = Some(form);
+        self
+    }
+
+    /// Sends the request without the bot token.
+    #[inline]
+    pub fn no_auth(mut self) -> Self {
+        self.no_auth = true;
+        self
+    }
+
+    /// Answers from the response cache while the cached body is younger than `ttl`, the body
+    /// of a successful response is cached.
+    #[inline]
+    pub fn cached(mut self, ttl: Duration) -> Self {
+        self.cached_ttl = Some(ttl);
+        self
+    }
 }
 
 #[derive(Default)]
@@ -53,11 +177,28 @@ pub struct RequestManager {
 
     // TODO: Use handler for every route id.
     client: Client,
+
+    /// The proxy set by `set_proxy`, kept when the client is rebuilt.
+    proxy: Option<Proxy>,
+
+    application: RwLock<Option<ApplicationObject>>,
+
+    /// The DM channel id of each user, so DMs don't recreate the channel.
+    dm_channels: RwLock<HashMap<Snowflake, Snowflake>>,
+
+    queue: RequestQueue,
+
+    circuit_breaker: CircuitBreaker,
+
+    cache: ResponseCache,
 }
 
 impl RequestManager {
     pub fn new(options: RequestManagerOptions) -> Self {
         Self {
+            client: Self::client(&options, None).expect("failed to build the HTTP client"),
+            queue: RequestQueue::new(options.max_concurrency_per_route),
+            circuit_breaker: CircuitBreaker::new(options.circuit_breaker),
             options,
             ..Default::default()
         }
@@ -65,15 +206,61 @@ impl RequestManager {
 
     pub fn new_with_token(options: RequestManagerOptions, token: String) -> Self {
         Self {
-            options,
             token: Some(token),
-            ..Default::default()
+            ..Self::new(options)
         }
     }
 
     #[inline]
     fn api(route: String) -> String {
-        format!("https://discord.com/api/v{v}{route}", v = 10)
+        format!("https://discord.com/api/v{DISCORD_API_VERSION}{route}")
+    }
+
+    fn client(
+        options: &RequestManagerOptions,
+        proxy: Option<Proxy>,
+    ) -> Result<Client, reqwest::Error> {
+        let mut builder = options
+            .tls_backend
+            .configure(Client::builder())
+            .pool_max_idle_per_host(options.pool_max_idle_per_host)
+            .pool_idle_timeout(options.pool_idle_timeout)
+            .connect_timeout(options.connection_timeout)
+            .tcp_keepalive(options.keep_alive.then_some(TCP_KEEPALIVE_INTERVAL));
+
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy);
+        }
+
+        builder.build()
+    }
+
+    /// The template of the route `url` was made from, the path itself for unknown routes.
+    fn route_name(url: &str) -> &str {
+        let path = url.strip_prefix(&Self::api(String::new())).unwrap_or(url);
+        routes::route_name_of(path).unwrap_or(path)
+    }
+
+    #[inline]
+    fn audit_log_reason(reason: Option<&str>) -> Option<HashMap<String, String>> {
+        reason.map(|reason| HashMap::from([("X-Audit-Log-Reason".into(), reason.into())]))
+    }
+
+    /// Like [`Response::error_for_status`], but a 403 for a missing permission becomes
+    /// [`RequestError::Forbidden`].
+    async fn error_for_status(response: Response) -> Result<Response, RequestError> {
+        if response.status() != StatusCode::FORBIDDEN {
+            return Ok(response.error_for_status()?);
+        }
+
+        let error = response.error_for_status_ref().unwrap_err();
+
+        match response.json::<ErrorBody>().await {
+            Ok(body) if body.code == MISSING_PERMISSIONS_CODE => Err(RequestError::Forbidden {
+                message: body.message,
+            }),
+            _ => Err(error.into()),
+        }
     }
 }
 
@@ -82,20 +269,57 @@ impl RequestManager {
         self.token = Some(token);
     }
 
+    /// Routes every request through the proxy, `socks5://` proxies require the `socks5` feature.
+    pub fn set_proxy(&mut self, proxy_url: &str) -> Result<(), RequestError> {
+        let proxy = Proxy::all(proxy_url)?;
+
+        self.client = Self::client(&self.options, Some(proxy.clone()))?;
+        self.proxy = Some(proxy);
+
+        Ok(())
+    }
+
+    /// Rebuilds the HTTP client with the new options, the proxy is kept. The route queues and
+    /// circuit breaker are reset, so it's best called while no request is in flight.
+    ///
+    /// `reqwest` doesn't expose the state of its connection pool, the idle connections of the
+    /// previous client are closed once they time out.
+    pub fn reconfigure(&mut self, options: RequestManagerOptions) -> Result<(), RequestError> {
+        self.client = Self::client(&options, self.proxy.clone())?;
+        self.queue = RequestQueue::new(options.max_concurrency_per_route);
+        self.circuit_breaker = CircuitBreaker::new(options.circuit_breaker);
+        self.options = options;
+
+        Ok(())
+    }
+
+    /// Sends the request, the 4xx and 5xx responses are returned as errors, see
+    /// [`RequestError::Forbidden`].
     pub async fn request<T: Serialize>(
         &self,
         options: RequestOptions<T>,
-    ) -> Result<Response, reqwest::Error> {
+    ) -> Result<Response, RequestError> {
         let RequestOptions {
             url,
             method,
             body,
+            query,
+            form,
             extra_headers,
+            no_auth,
+            cached_ttl,
         } = options;
 
+        let route = RouteId::new(method.clone(), url.clone());
+        let breaker_route = RouteId::new(method.clone(), Self::route_name(&url));
+
         let mut builder = self.client.request(method, url);
 
-        if let Some(ref token) = self.token {
+        if !query.is_empty() {
+            builder = builder.query(&query);
+        }
+
+        if let Some(token) = self.token.as_ref().filter(|_| !no_auth) {
             builder = builder.header(AUTHORIZATION, format!("Bot {}", token));
         }
 
@@ -105,23 +329,861 @@ impl RequestManager {
             }
         }
 
-        if let Some(ref body) = body {
+        if let Some(form) = form {
+            builder = builder.multipart(form);
+        } else if let Some(ref body) = body {
             builder = builder.json(body);
         }
 
-        self.client.execute(builder.build()?).await
+        let request = builder.build()?;
+
+        let cache_key = cached_ttl.map(|ttl| (request.url().to_string(), ttl));
+
+        if let Some((ref key, ttl)) = cache_key {
+            if let Some(body) = self.cache.get(key, ttl) {
+                return Ok(http::Response::new(body).into());
+            }
+        }
+
+        let Some(circuit_permit) = self.circuit_breaker.try_acquire(&breaker_route) else {
+            return Err(RequestError::CircuitOpen {
+                route: breaker_route,
+            });
+        };
+
+        let _permit = self.queue.acquire(&route).await;
+
+        let response = self.client.execute(request).await;
+
+        match response {
+            Ok(ref response) if !response.status().is_server_error() => {
+                circuit_permit.record_success()
+            }
+            _ => circuit_permit.record_failure(),
+        }
+
+        let response = Self::error_for_status(response?).await?;
+
+        let Some((key, _)) = cache_key else {
+            return Ok(response);
+        };
+
+        let (status, headers) = (response.status(), response.headers().clone());
+        let body = response.bytes().await?.to_vec();
+
+        self.cache.insert(key, body.clone());
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+
+        Ok(response.into())
+    }
+
+    /// Expires the cached response of the route, e.g. `routes::guild_roles(&guild_id)`.
+    #[inline]
+    pub fn invalidate_cache(&self, route: &str) {
+        self.cache.invalidate(&Self::api(route.to_owned()));
     }
 }
 
 impl RequestManager {
-    pub async fn get_gateway(&self) -> Result<GatewayObject, reqwest::Error> {
-        let options = RequestOptions::<Dummy>::get(Self::api(routes::gateway()), None);
-        self.request(options).await?.json().await
+    pub async fn get_gateway(&self) -> Result<GatewayObject, RequestError> {
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::gateway()), None)
+            .cached(RESPONSE_CACHE_TTL);
+        Ok(self.request(options).await?.json().await?)
     }
 
-    pub async fn get_gateway_bot(&self) -> Result<GatewayBotObject, reqwest::Error> {
+    pub async fn get_gateway_bot(&self) -> Result<GatewayBotObject, RequestError> {
         let options = RequestOptions::<Dummy>::get(Self::api(routes::gateway_bot()), None);
-        self.request(options).await?.json().await
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// Fetches the application of the bot, the result is cached after the first call.
+    pub async fn get_current_application_info(&self) -> Result<ApplicationObject, RequestError> {
+        if let Some(ref application) = *self.application.read().unwrap() {
+            return Ok(application.clone());
+        }
+
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::current_application()), None);
+        let application: ApplicationObject = self.request(options).await?.json().await?;
+
+        *self.application.write().unwrap() = Some(application.clone());
+
+        Ok(application)
+    }
+
+    /// Fetches the global commands of the application, `with_localizations` includes all the
+    /// localized names and descriptions instead of only the ones of the request locale.
+    pub async fn get_global_application_commands(
+        &self,
+        application_id: &Snowflake,
+        with_localizations: bool,
+    ) -> Result<Vec<ApplicationCommandObject>, RequestError> {
+        let options = RequestOptions::<Dummy>::get(
+            Self::api(routes::application_commands(application_id)),
+            None,
+        )
+        .query("with_localizations", with_localizations);
+
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// The bot user, cached for a minute.
+    pub async fn get_current_user(&self) -> Result<UserObject, RequestError> {
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::current_user()), None)
+            .cached(RESPONSE_CACHE_TTL);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// The roles of the guild, cached for a minute, see [`invalidate_cache`](Self::invalidate_cache).
+    pub async fn list_guild_roles(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<Vec<RoleObject>, RequestError> {
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::guild_roles(guild_id)), None)
+            .cached(RESPONSE_CACHE_TTL);
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// Sends a message, nobody is pinged unless `allowed_mentions` is set.
+    pub async fn send_message(
+        &self,
+        channel_id: &Snowflake,
+        mut body: CreateMessageBody,
+    ) -> Result<MessageObject, RequestError> {
+        body.allowed_mentions
+            .get_or_insert_with(AllowedMentionsObject::none);
+
+        let options = RequestOptions::post(
+            Self::api(routes::channel_messages(channel_id)),
+            Some(body),
+            None,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Sends a message to the DM channel of the user, the channel is created once and cached.
+    pub async fn send_dm_message(
+        &self,
+        user_id: &Snowflake,
+        body: CreateMessageBody,
+    ) -> Result<MessageObject, RequestError> {
+        let cached = self.dm_channels.read().unwrap().get(user_id).copied();
+
+        let channel_id = match cached {
+            Some(channel_id) => channel_id,
+            None => {
+                self.create_dm(CreateDmBody {
+                    recipient_id: *user_id,
+                })
+                .await?
+                .id
+            }
+        };
+
+        self.send_message(&channel_id, body).await
+    }
+
+    /// Edits a message, like [`send_message`](Self::send_message) nobody is pinged by the new
+    /// content unless `allowed_mentions` is set.
+    pub async fn edit_message(
+        &self,
+        channel_id: &Snowflake,
+        message_id: &Snowflake,
+        mut body: EditMessageBody,
+    ) -> Result<MessageObject, RequestError> {
+        body.allowed_mentions
+            .get_or_insert_with(AllowedMentionsObject::none);
+
+        let options = RequestOptions::patch(
+            Self::api(routes::channel_message(channel_id, message_id)),
+            Some(body),
+            None,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// Fails with a [`ValidationError`] if `query.days` isn't between 1 and 30.
+    pub async fn get_guild_prune_count(
+        &self,
+        guild_id: &Snowflake,
+        query: GetPruneCountQuery,
+    ) -> Result<PruneCountObject, RequestError> {
+        ValidationError::check_range("days", query.days, 1..=30)?;
+
+        let mut options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_prune(guild_id)), None)
+                .query("days", query.days);
+
+        if !query.include_roles.is_empty() {
+            let include_roles: Vec<_> = query
+                .include_roles
+                .iter()
+                .map(|id| id.to_string())
+                .collect();
+
+            options = options.query("include_roles", include_roles.join(","));
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Fails with a [`ValidationError`] if `body.days` isn't between 1 and 30.
+    pub async fn begin_guild_prune(
+        &self,
+        guild_id: &Snowflake,
+        body: BeginPruneBody,
+    ) -> Result<PruneCountObject, RequestError> {
+        ValidationError::check_range("days", body.days, 1..=30)?;
+
+        let headers = Self::audit_log_reason(body.reason.as_deref());
+
+        let options = RequestOptions::post(
+            Self::api(routes::guild_prune(guild_id)),
+            Some(body),
+            headers,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    pub async fn get_guild_widget_settings(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<GuildWidgetSettingsObject, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_widget_settings(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn modify_guild_widget(
+        &self,
+        guild_id: &Snowflake,
+        settings: GuildWidgetSettingsObject,
+    ) -> Result<GuildWidgetSettingsObject, RequestError> {
+        let options = RequestOptions::patch(
+            Self::api(routes::guild_widget_settings(guild_id)),
+            Some(settings),
+            None,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn get_guild_widget(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<GuildWidgetObject, RequestError> {
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::guild_widget(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    pub async fn get_guild_invites(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<Vec<InviteObject>, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_invites(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn get_channel_invites(
+        &self,
+        channel_id: &Snowflake,
+    ) -> Result<Vec<InviteObject>, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::channel_invites(channel_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn create_channel_invite(
+        &self,
+        channel_id: &Snowflake,
+        body: CreateChannelInviteBody,
+    ) -> Result<InviteObject, RequestError> {
+        let options = RequestOptions::post(
+            Self::api(routes::channel_invites(channel_id)),
+            Some(body),
+            None,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    pub async fn create_dm(&self, body: CreateDmBody) -> Result<ChannelObject, RequestError> {
+        let recipient_id = body.recipient_id;
+
+        let options =
+            RequestOptions::post(Self::api(routes::current_user_channels()), Some(body), None);
+        let channel: ChannelObject = self.request(options).await?.json().await?;
+
+        self.dm_channels
+            .write()
+            .unwrap()
+            .insert(recipient_id, channel.id);
+
+        Ok(channel)
+    }
+}
+
+impl RequestManager {
+    pub async fn get_guild_emojis(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<Vec<EmojiObject>, RequestError> {
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::guild_emojis(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn get_guild_emoji(
+        &self,
+        guild_id: &Snowflake,
+        emoji_id: &Snowflake,
+    ) -> Result<EmojiObject, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_emoji(guild_id, emoji_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn create_guild_emoji(
+        &self,
+        guild_id: &Snowflake,
+        body: CreateEmojiBody,
+    ) -> Result<EmojiObject, RequestError> {
+        let options =
+            RequestOptions::post(Self::api(routes::guild_emojis(guild_id)), Some(body), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// Shows the typing indicator in the channel for 10 seconds, or until a message is sent.
+    pub async fn trigger_typing(&self, channel_id: &Snowflake) -> Result<(), RequestError> {
+        let options = RequestOptions::<Dummy>::post(
+            Self::api(routes::channel_typing(channel_id)),
+            None,
+            None,
+        );
+        self.request(options).await?;
+        Ok(())
+    }
+}
+
+impl RequestManager {
+    pub async fn get_guild_stickers(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<Vec<StickerObject>, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_stickers(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Uploads a PNG, APNG, GIF or Lottie JSON file of at most 512 KiB as a guild sticker.
+    pub async fn create_guild_sticker(
+        &self,
+        guild_id: &Snowflake,
+        name: &str,
+        description: &str,
+        tags: &str,
+        file: FileAttachment,
+    ) -> Result<StickerObject, RequestError> {
+        let form = Form::new()
+            .text("name", name.to_owned())
+            .text("description", description.to_owned())
+            .text("tags", tags.to_owned())
+            .part("file", file.into_part());
+
+        let options =
+            RequestOptions::<Dummy>::post(Self::api(routes::guild_stickers(guild_id)), None, None)
+                .multipart(form);
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    pub async fn get_guild_integrations(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<Vec<IntegrationObject>, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_integrations(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Deletes the integration and kicks its bot if any, requires `MANAGE_GUILD`.
+    pub async fn delete_guild_integration(
+        &self,
+        guild_id: &Snowflake,
+        integration_id: &Snowflake,
+        reason: Option<&str>,
+    ) -> Result<(), RequestError> {
+        let options = RequestOptions::<Dummy>::delete(
+            Self::api(routes::guild_integration(guild_id, integration_id)),
+            Self::audit_log_reason(reason),
+        );
+        self.request(options).await?;
+        Ok(())
+    }
+
+    pub async fn list_guild_scheduled_events(
+        &self,
+        guild_id: &Snowflake,
+        query: GetGuildScheduledEventsQuery,
+    ) -> Result<Vec<GuildScheduledEventObject>, RequestError> {
+        let mut options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_scheduled_events(guild_id)), None);
+
+        if let Some(with_user_count) = query.with_user_count {
+            options = options.query("with_user_count", with_user_count);
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Fails with a [`ValidationError`] if `body` is an `External` event without a location or
+    /// an end time.
+    pub async fn create_guild_scheduled_event(
+        &self,
+        guild_id: &Snowflake,
+        body: CreateGuildScheduledEventBody,
+        reason: Option<&str>,
+    ) -> Result<GuildScheduledEventObject, RequestError> {
+        if body.entity_type == GuildScheduledEventEntityType::External {
+            if body
+                .entity_metadata
+                .as_ref()
+                .is_none_or(|metadata| metadata.location.is_none())
+            {
+                Err(ValidationError::Missing {
+                    field: "entity_metadata.location",
+                })?;
+            }
+
+            if body.scheduled_end_time.is_none() {
+                Err(ValidationError::Missing {
+                    field: "scheduled_end_time",
+                })?;
+            }
+        }
+
+        let options = RequestOptions::post(
+            Self::api(routes::guild_scheduled_events(guild_id)),
+            Some(body),
+            Self::audit_log_reason(reason),
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// The users subscribed to the scheduled event, sorted by user id. `before` and `after`
+    /// page through the users, `with_member` includes their guild member.
+    ///
+    /// Fails with a [`ValidationError`] if `limit` isn't between 1 and 100.
+    pub async fn get_guild_scheduled_event_users(
+        &self,
+        guild_id: &Snowflake,
+        event_id: &Snowflake,
+        limit: Option<u8>,
+        with_member: bool,
+        before: Option<&Snowflake>,
+        after: Option<&Snowflake>,
+    ) -> Result<Vec<GuildScheduledEventUserObject>, RequestError> {
+        if let Some(limit) = limit {
+            ValidationError::check_range("limit", limit, 1..=100)?;
+        }
+
+        let mut options = RequestOptions::<Dummy>::get(
+            Self::api(routes::guild_scheduled_event_users(guild_id, event_id)),
+            None,
+        )
+        .query("with_member", with_member);
+
+        if let Some(limit) = limit {
+            options = options.query("limit", limit);
+        }
+
+        if let Some(before) = before {
+            options = options.query("before", before);
+        }
+
+        if let Some(after) = after {
+            options = options.query("after", after);
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// Fetches any sticker, e.g. a standard sticker from a Nitro pack.
+    pub async fn get_sticker(&self, sticker_id: &Snowflake) -> Result<StickerObject, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::sticker(sticker_id)), None).no_auth();
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn list_nitro_sticker_packs(&self) -> Result<NitroStickerPacksObject, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::sticker_packs()), None).no_auth();
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn get_guild_sticker(
+        &self,
+        guild_id: &Snowflake,
+        sticker_id: &Snowflake,
+    ) -> Result<StickerObject, RequestError> {
+        let options = RequestOptions::<Dummy>::get(
+            Self::api(routes::guild_sticker(guild_id, sticker_id)),
+            None,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn modify_guild_sticker(
+        &self,
+        guild_id: &Snowflake,
+        sticker_id: &Snowflake,
+        body: ModifyStickerBody,
+    ) -> Result<StickerObject, RequestError> {
+        let options = RequestOptions::patch(
+            Self::api(routes::guild_sticker(guild_id, sticker_id)),
+            Some(body),
+            None,
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn delete_guild_sticker(
+        &self,
+        guild_id: &Snowflake,
+        sticker_id: &Snowflake,
+        reason: Option<&str>,
+    ) -> Result<(), RequestError> {
+        let options = RequestOptions::<Dummy>::delete(
+            Self::api(routes::guild_sticker(guild_id, sticker_id)),
+            Self::audit_log_reason(reason),
+        );
+        self.request(options).await?;
+        Ok(())
+    }
+}
+
+impl RequestManager {
+    /// Requires `MANAGE_WEBHOOKS`.
+    pub async fn get_channel_webhooks(
+        &self,
+        channel_id: &Snowflake,
+    ) -> Result<Vec<WebhookObject>, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::channel_webhooks(channel_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Requires `MANAGE_WEBHOOKS`.
+    pub async fn get_guild_webhooks(
+        &self,
+        guild_id: &Snowflake,
+    ) -> Result<Vec<WebhookObject>, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_webhooks(guild_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn get_webhook(&self, webhook_id: &Snowflake) -> Result<WebhookObject, RequestError> {
+        let options = RequestOptions::<Dummy>::get(Self::api(routes::webhook(webhook_id)), None);
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Fetches the webhook with its token instead of the bot token, the returned webhook has no
+    /// `user`.
+    pub async fn get_webhook_with_token(
+        &self,
+        webhook_id: &Snowflake,
+        token: &str,
+    ) -> Result<WebhookObject, RequestError> {
+        let options =
+            RequestOptions::<Dummy>::get(Self::api(routes::webhook_token(webhook_id, token)), None)
+                .no_auth();
+        Ok(self.request(options).await?.json().await?)
+    }
+}
+
+impl RequestManager {
+    /// Responds to the interaction, which must be done within 3 seconds of receiving it.
+    pub async fn create_interaction_response(
+        &self,
+        interaction_id: &Snowflake,
+        token: &str,
+        body: InteractionResponseObject,
+    ) -> Result<(), RequestError> {
+        let options = RequestOptions::post(
+            Self::api(routes::interaction_callback(interaction_id, token)),
+            Some(body),
+            None,
+        );
+        self.request(options).await?;
+        Ok(())
+    }
+
+    /// Sends a followup message to the interaction, e.g. after a deferred response.
+    /// Interaction tokens are valid for 15 minutes.
+    pub async fn create_interaction_followup(
+        &self,
+        application_id: &Snowflake,
+        token: &str,
+        body: CreateFollowupBody,
+    ) -> Result<MessageObject, RequestError> {
+        let url = Self::api(routes::webhook_token(application_id, token));
+
+        let options = if body.files.is_empty() {
+            RequestOptions::post(url, Some(body), None)
+        } else {
+            RequestOptions::post(url, None, None).multipart(body.into_form())
+        };
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Edits a followup message, only its set fields are changed.
+    pub async fn edit_interaction_followup(
+        &self,
+        application_id: &Snowflake,
+        token: &str,
+        message_id: &Snowflake,
+        body: CreateFollowupBody,
+    ) -> Result<MessageObject, RequestError> {
+        let url = Self::api(routes::webhook_token_message(
+            application_id,
+            token,
+            message_id,
+        ));
+
+        let options = if body.files.is_empty() {
+            RequestOptions::patch(url, Some(body), None)
+        } else {
+            RequestOptions::patch(url, None, None).multipart(body.into_form())
+        };
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn delete_interaction_followup(
+        &self,
+        application_id: &Snowflake,
+        token: &str,
+        message_id: &Snowflake,
+    ) -> Result<(), RequestError> {
+        let options = RequestOptions::<Dummy>::delete(
+            Self::api(routes::webhook_token_message(
+                application_id,
+                token,
+                message_id,
+            )),
+            None,
+        );
+        self.request(options).await?;
+        Ok(())
+    }
+}
+
+impl RequestManager {
+    /// A page of messages on the given side of `cursor`, the newest ones if `None`.
+    pub async fn get_channel_messages(
+        &self,
+        channel_id: &Snowflake,
+        direction: PaginationDirection,
+        cursor: Option<&Snowflake>,
+    ) -> Result<Vec<MessageObject>, RequestError> {
+        let mut options =
+            RequestOptions::<Dummy>::get(Self::api(routes::channel_messages(channel_id)), None)
+                .query("limit", PAGE_LIMIT);
+
+        if let Some(cursor) = cursor {
+            options = options.query(direction.query_key(), cursor);
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// A page of members sorted by user id, starting after `after`.
+    pub async fn list_guild_members(
+        &self,
+        guild_id: &Snowflake,
+        after: Option<&Snowflake>,
+    ) -> Result<Vec<GuildMemberObject>, RequestError> {
+        let mut options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_members(guild_id)), None)
+                .query("limit", PAGE_LIMIT);
+
+        if let Some(after) = after {
+            options = options.query("after", after);
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// The members whose username or nickname starts with `query.query`, the query is
+    /// URL-encoded with the other query params.
+    ///
+    /// Fails with a [`ValidationError`] if `query.query` is empty or `query.limit` isn't between
+    /// 1 and 1000.
+    pub async fn search_guild_members(
+        &self,
+        guild_id: &Snowflake,
+        query: SearchGuildMembersQuery,
+    ) -> Result<Vec<GuildMemberObject>, RequestError> {
+        if query.query.is_empty() {
+            Err(ValidationError::Empty { field: "query" })?;
+        }
+
+        if let Some(limit) = query.limit {
+            ValidationError::check_range("limit", limit, 1..=1000)?;
+        }
+
+        let mut options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_members_search(guild_id)), None)
+                .query("query", query.query);
+
+        if let Some(limit) = query.limit {
+            options = options.query("limit", limit);
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    pub async fn modify_guild_member(
+        &self,
+        guild_id: &Snowflake,
+        user_id: &Snowflake,
+        body: ModifyGuildMemberBody,
+        reason: Option<&str>,
+    ) -> Result<GuildMemberObject, RequestError> {
+        let options = RequestOptions::patch(
+            Self::api(routes::guild_member(guild_id, user_id)),
+            Some(body),
+            Self::audit_log_reason(reason),
+        );
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Times the member out for the given duration, Discord allows up to 28 days.
+    pub async fn timeout_member(
+        &self,
+        guild_id: &Snowflake,
+        user_id: &Snowflake,
+        duration: Duration,
+        reason: Option<&str>,
+    ) -> Result<GuildMemberObject, RequestError> {
+        let until = DiscordTimestamp(DiscordTimestamp::now().0 + duration.as_secs());
+
+        let body = ModifyGuildMemberBody {
+            communication_disabled_until: Some(until.to_iso8601()),
+            ..Default::default()
+        };
+
+        self.modify_guild_member(guild_id, user_id, body, reason)
+            .await
+    }
+
+    /// A page of bans on the given side of the `cursor` user id.
+    pub async fn get_guild_bans(
+        &self,
+        guild_id: &Snowflake,
+        direction: PaginationDirection,
+        cursor: Option<&Snowflake>,
+    ) -> Result<Vec<BanObject>, RequestError> {
+        let mut options =
+            RequestOptions::<Dummy>::get(Self::api(routes::guild_bans(guild_id)), None)
+                .query("limit", PAGE_LIMIT);
+
+        if let Some(cursor) = cursor {
+            options = options.query(direction.query_key(), cursor);
+        }
+
+        Ok(self.request(options).await?.json().await?)
+    }
+
+    /// Fetches every message on the given side of `starting_from`, page by page.
+    /// Starts from the newest message going `Before`, or from the oldest one going `After`.
+    pub fn paginate_messages<'a>(
+        &'a self,
+        channel_id: &Snowflake,
+        direction: PaginationDirection,
+        starting_from: Option<&Snowflake>,
+    ) -> impl Stream<Item = Result<Vec<MessageObject>, RequestError>> + 'a {
+        let channel_id = *channel_id;
+        let starting_from = Self::pagination_start(direction, starting_from);
+
+        paginate(
+            direction,
+            starting_from,
+            move |cursor| async move {
+                self.get_channel_messages(&channel_id, direction, cursor.as_ref())
+                    .await
+            },
+            |message| Some(message.id),
+        )
+    }
+
+    /// Fetches every member of the guild, page by page.
+    pub fn paginate_guild_members<'a>(
+        &'a self,
+        guild_id: &Snowflake,
+        starting_after: Option<&Snowflake>,
+    ) -> impl Stream<Item = Result<Vec<GuildMemberObject>, RequestError>> + 'a {
+        let guild_id = *guild_id;
+
+        paginate(
+            PaginationDirection::After,
+            starting_after.copied(),
+            move |cursor| async move { self.list_guild_members(&guild_id, cursor.as_ref()).await },
+            |member| member.user.as_ref().map(|user| user.id()),
+        )
+    }
+
+    /// Fetches every ban of the guild on the given side of `starting_from`, page by page.
+    pub fn paginate_guild_bans<'a>(
+        &'a self,
+        guild_id: &Snowflake,
+        direction: PaginationDirection,
+        starting_from: Option<&Snowflake>,
+    ) -> impl Stream<Item = Result<Vec<BanObject>, RequestError>> + 'a {
+        let guild_id = *guild_id;
+        let starting_from = Self::pagination_start(direction, starting_from);
+
+        paginate(
+            direction,
+            starting_from,
+            move |cursor| async move {
+                self.get_guild_bans(&guild_id, direction, cursor.as_ref())
+                    .await
+            },
+            |ban| Some(ban.user.id()),
+        )
+    }
+
+    /// Going `After` without a cursor starts from the oldest item, rather than the newest one.
+    #[inline]
+    fn pagination_start(
+        direction: PaginationDirection,
+        starting_from: Option<&Snowflake>,
+    ) -> Option<Snowflake> {
+        match (direction, starting_from) {
+            (_, Some(starting_from)) => Some(*starting_from),
+            (PaginationDirection::After, None) => Some(Snowflake::new(0)),
+            (PaginationDirection::Before, None) => None,
+        }
     }
 }
 
@@ -129,6 +1191,13 @@ impl Default for RequestManagerOptions {
     fn default() -> Self {
         Self {
             global_rate_limit: 50,
+            max_concurrency_per_route: 1,
+            circuit_breaker: Default::default(),
+            tls_backend: Default::default(),
+            pool_max_idle_per_host: 10,
+            pool_idle_timeout: Duration::from_secs(90),
+            connection_timeout: Duration::from_secs(10),
+            keep_alive: true,
         }
     }
 }
@@ -0,0 +1,72 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::Method;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::RequestManagerOptions;
+
+/// Identifies a route by its method and path, requests to the same route share a queue.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RouteId {
+    pub method: Method,
+    pub path: String,
+}
+
+impl RouteId {
+    #[inline]
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+        }
+    }
+}
+
+/// Limits how many requests to the same route are in flight, requests to different routes
+/// aren't limited. `GET` requests are never limited.
+pub struct RequestQueue {
+    max_concurrency_per_route: usize,
+
+    routes: Mutex<HashMap<RouteId, Arc<Semaphore>>>,
+}
+
+impl RequestQueue {
+    pub fn new(max_concurrency_per_route: usize) -> Self {
+        Self {
+            max_concurrency_per_route: max_concurrency_per_route.max(1),
+            routes: Default::default(),
+        }
+    }
+
+    /// Waits for a free slot of the route, the slot is released when the permit is dropped.
+    pub async fn acquire(&self, route: &RouteId) -> Option<OwnedSemaphorePermit> {
+        if route.method == Method::GET {
+            return None;
+        }
+
+        let semaphore = {
+            let mut routes = self.routes.lock().unwrap();
+
+            // Forget the idle routes, only the map holds their semaphore.
+            routes.retain(|_, s| {
+                Arc::strong_count(s) > 1 || s.available_permits() < self.max_concurrency_per_route
+            });
+
+            routes
+                .entry(route.clone())
+                .or_insert_with(|| Arc::new(Semaphore::new(self.max_concurrency_per_route)))
+                .clone()
+        };
+
+        semaphore.acquire_owned().await.ok()
+    }
+}
+
+impl Default for RequestQueue {
+    fn default() -> Self {
+        Self::new(RequestManagerOptions::default().max_concurrency_per_route)
+    }
+}
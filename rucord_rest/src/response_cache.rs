@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+/// Keeps the bodies of the successful responses by URL, for the resources that rarely change.
+#[derive(Default)]
+pub struct ResponseCache {
+    entries: DashMap<String, (Instant, Vec<u8>)>,
+}
+
+impl ResponseCache {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached body of the URL, unless it was stored `ttl` or more ago.
+    pub fn get(&self, url: &str, ttl: Duration) -> Option<Vec<u8>> {
+        let expired = {
+            let entry = self.entries.get(url)?;
+
+            if entry.0.elapsed() < ttl {
+                return Some(entry.1.clone());
+            }
+
+            true
+        };
+
+        if expired {
+            self.entries.remove(url);
+        }
+
+        None
+    }
+
+    #[inline]
+    pub fn insert(&self, url: impl Into<String>, body: Vec<u8>) {
+        self.entries.insert(url.into(), (Instant::now(), body));
+    }
+
+    #[inline]
+    pub fn invalidate(&self, url: &str) {
+        self.entries.remove(url);
+    }
+}
@@ -0,0 +1,27 @@
+use reqwest::ClientBuilder;
+
+/// The TLS implementation used for HTTPS, each backend requires its feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TlsBackend {
+    /// The platform TLS (OpenSSL, Secure Transport or SChannel), requires the `native-tls` feature.
+    #[cfg(feature = "native-tls")]
+    #[default]
+    Native,
+
+    /// Rustls with the webpki root certificates, requires the `rustls` feature.
+    #[cfg(feature = "rustls")]
+    #[cfg_attr(not(feature = "native-tls"), default)]
+    Rustls,
+}
+
+impl TlsBackend {
+    /// Configures the client to use this backend.
+    pub fn configure(self, builder: ClientBuilder) -> ClientBuilder {
+        match self {
+            #[cfg(feature = "native-tls")]
+            Self::Native => builder.use_native_tls(),
+            #[cfg(feature = "rustls")]
+            Self::Rustls => builder.use_rustls_tls(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use rucord_rest::FileAttachment;
+
+#[test]
+fn test_attachment_content_type() {
+    for (filename, content_type) in [
+        ("sticker.png", "image/png"),
+        ("sticker.APNG", "image/png"),
+        ("sticker.json", "application/json"),
+        ("sticker.gif", "image/gif"),
+        ("sticker", "application/octet-stream"),
+    ] {
+        assert_eq!(
+            FileAttachment::new(filename, vec![]).content_type(),
+            content_type
+        );
+    }
+}
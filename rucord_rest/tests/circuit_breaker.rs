@@ -0,0 +1,90 @@
+use std::{thread::sleep, time::Duration};
+
+use rucord_rest::{CircuitBreaker, CircuitBreakerPolicy, CircuitBreakerState, Method, RouteId};
+
+fn breaker() -> CircuitBreaker {
+    CircuitBreaker::new(CircuitBreakerPolicy {
+        failure_threshold: 2,
+        open_duration: Duration::from_millis(50),
+    })
+}
+
+#[test]
+fn test_circuit_opens_after_threshold() {
+    let breaker = breaker();
+    let route = RouteId::new(Method::GET, "/gateway/bot");
+
+    breaker.record_failure(&route);
+    assert!(breaker.try_acquire(&route).is_some());
+
+    breaker.record_failure(&route);
+    assert!(matches!(
+        breaker.state(&route),
+        CircuitBreakerState::Open(_)
+    ));
+    assert!(breaker.try_acquire(&route).is_none());
+    assert!(breaker
+        .try_acquire(&RouteId::new(Method::GET, "/gateway"))
+        .is_some());
+}
+
+#[test]
+fn test_circuit_half_open_probe() {
+    let breaker = breaker();
+    let route = RouteId::new(Method::GET, "/gateway/bot");
+
+    breaker.record_failure(&route);
+    breaker.record_failure(&route);
+
+    sleep(Duration::from_millis(60));
+
+    let probe = breaker
+        .try_acquire(&route)
+        .expect("expected a probe to be let through");
+    assert!(probe.is_probe());
+    assert_eq!(breaker.state(&route), CircuitBreakerState::HalfOpen);
+    assert!(
+        breaker.try_acquire(&route).is_none(),
+        "expected a single probe"
+    );
+
+    probe.record_failure();
+    assert!(matches!(
+        breaker.state(&route),
+        CircuitBreakerState::Open(_)
+    ));
+
+    sleep(Duration::from_millis(60));
+
+    breaker.try_acquire(&route).unwrap().record_success();
+    assert_eq!(breaker.state(&route), CircuitBreakerState::Closed);
+
+    let permit = breaker.try_acquire(&route).unwrap();
+    assert!(!permit.is_probe());
+}
+
+#[test]
+fn test_circuit_dropped_probe_reopens() {
+    let breaker = breaker();
+    let route = RouteId::new(Method::GET, "/gateway/bot");
+
+    breaker.record_failure(&route);
+    breaker.record_failure(&route);
+
+    sleep(Duration::from_millis(60));
+
+    // E.g. the request future was cancelled before the response arrived.
+    drop(breaker.try_acquire(&route).unwrap());
+
+    assert!(matches!(
+        breaker.state(&route),
+        CircuitBreakerState::Open(_)
+    ));
+
+    sleep(Duration::from_millis(60));
+
+    assert!(
+        breaker.try_acquire(&route).unwrap().is_probe(),
+        "expected a new probe once the circuit reopened"
+    );
+}
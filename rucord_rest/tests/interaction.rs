@@ -0,0 +1,16 @@
+use rucord_rest::{CreateFollowupBody, FileAttachment};
+
+#[test]
+fn test_followup_body_skips_files() {
+    let body = CreateFollowupBody {
+        content: Some("Done!".into()),
+        flags: Some(1 << 6),
+        files: vec![FileAttachment::new("report.json", "{}")],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        serde_json::to_value(&body).unwrap(),
+        serde_json::json!({ "content": "Done!", "flags": 64 })
+    );
+}
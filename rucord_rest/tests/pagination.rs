@@ -0,0 +1,84 @@
+use std::sync::Mutex;
+
+use futures::StreamExt;
+use rucord_api_types::Snowflake;
+use rucord_rest::{paginate, PaginationDirection, PAGE_LIMIT};
+
+#[tokio::test]
+async fn test_paginate_until_short_page() {
+    let cursors = Mutex::new(vec![]);
+
+    let pages: Vec<_> = paginate(
+        PaginationDirection::Before,
+        None,
+        |cursor: Option<Snowflake>| {
+            cursors.lock().unwrap().push(cursor);
+
+            // Two full pages of descending ids, then a short one.
+            let end = cursor.map_or(1000, |cursor| cursor.get());
+            let len = if end > 850 { PAGE_LIMIT as u64 } else { 10 };
+
+            async move { Ok((end - len..end).rev().map(Snowflake::new).collect()) }
+        },
+        |id| Some(*id),
+    )
+    .map(|page| page.unwrap().len())
+    .collect()
+    .await;
+
+    assert_eq!(pages, [PAGE_LIMIT, PAGE_LIMIT, 10]);
+    assert_eq!(
+        *cursors.lock().unwrap(),
+        [None, Some(Snowflake::new(900)), Some(Snowflake::new(800))]
+    );
+}
+
+#[tokio::test]
+async fn test_paginate_empty() {
+    let pages = paginate(
+        PaginationDirection::After,
+        Some(Snowflake::new(0)),
+        |_| async { Ok(vec![]) },
+        |id: &Snowflake| Some(*id),
+    )
+    .count()
+    .await;
+
+    assert_eq!(pages, 0);
+}
+
+#[tokio::test]
+async fn test_paginate_item_without_id() {
+    let cursors = Mutex::new(vec![]);
+
+    // A full page whose last item has no id, it would restart from the beginning.
+    let pages = paginate(
+        PaginationDirection::After,
+        None,
+        |cursor: Option<Snowflake>| {
+            cursors.lock().unwrap().push(cursor);
+
+            async { Ok((1..=PAGE_LIMIT as u64).collect::<Vec<_>>()) }
+        },
+        |id: &u64| (*id != PAGE_LIMIT as u64).then(|| Snowflake::new(*id)),
+    )
+    .count()
+    .await;
+
+    assert_eq!(pages, 1);
+    assert_eq!(*cursors.lock().unwrap(), [None]);
+}
+
+#[test]
+fn test_next_cursor() {
+    let ids = || [3, 1, 2].into_iter().map(Snowflake::new);
+
+    assert_eq!(
+        PaginationDirection::Before.next_cursor(ids()),
+        Some(Snowflake::new(1))
+    );
+    assert_eq!(
+        PaginationDirection::After.next_cursor(ids()),
+        Some(Snowflake::new(3))
+    );
+}
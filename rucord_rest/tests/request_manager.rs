@@ -0,0 +1,168 @@
+use std::time::Duration;
+
+use rucord_api_types::{
+    CreateGuildScheduledEventBody, CreateMessageBody, GetPruneCountQuery,
+    GuildScheduledEventEntityMetadata, GuildScheduledEventEntityType,
+    GuildScheduledEventPrivacyLevel, SearchGuildMembersQuery, Snowflake, ValidationError,
+};
+use rucord_rest::{RequestError, RequestManager, RequestManagerOptions, SendMessageExt};
+
+#[test]
+fn test_default_pool_options() {
+    let options = RequestManagerOptions::default();
+
+    assert_eq!(options.pool_max_idle_per_host, 10);
+    assert_eq!(options.pool_idle_timeout, Duration::from_secs(90));
+    assert_eq!(options.connection_timeout, Duration::from_secs(10));
+    assert!(options.keep_alive);
+}
+
+#[test]
+fn test_reconfigure_keeps_token() {
+    let mut manager = RequestManager::new_with_token(Default::default(), "token".into());
+
+    manager
+        .reconfigure(RequestManagerOptions {
+            pool_max_idle_per_host: 2,
+            keep_alive: false,
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(manager.options.pool_max_idle_per_host, 2);
+    assert!(!manager.options.keep_alive);
+    assert_eq!(manager.token.as_deref(), Some("token"));
+}
+
+#[tokio::test]
+async fn test_prune_days_validation() {
+    let manager = RequestManager::new_with_token(Default::default(), "token".into());
+
+    let error = manager
+        .get_guild_prune_count(
+            &Snowflake::new(197038439483310086),
+            GetPruneCountQuery {
+                days: 31,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RequestError::Validation(ValidationError::OutOfRange {
+            field: "days",
+            value: 31,
+            min: 1,
+            max: 30,
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_send_message_without_channel() {
+    let manager = RequestManager::new_with_token(Default::default(), "token".into());
+
+    let error = CreateMessageBody::builder()
+        .content("pong")
+        .send(&manager)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RequestError::Validation(ValidationError::Missing {
+            field: "channel_id"
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_search_guild_members_validation() {
+    let manager = RequestManager::new_with_token(Default::default(), "token".into());
+    let guild_id = Snowflake::new(197038439483310086);
+
+    let error = manager
+        .search_guild_members(&guild_id, SearchGuildMembersQuery::new(""))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RequestError::Validation(ValidationError::Empty { field: "query" })
+    ));
+
+    let error = manager
+        .search_guild_members(
+            &guild_id,
+            SearchGuildMembersQuery {
+                query: "Nel".into(),
+                limit: Some(0),
+            },
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RequestError::Validation(ValidationError::OutOfRange { field: "limit", .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_scheduled_event_users_limit_validation() {
+    let manager = RequestManager::new_with_token(Default::default(), "token".into());
+
+    let error = manager
+        .get_guild_scheduled_event_users(
+            &Snowflake::new(197038439483310086),
+            &Snowflake::new(1083425287345029200),
+            Some(101),
+            false,
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RequestError::Validation(ValidationError::OutOfRange {
+            field: "limit",
+            value: 101,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_external_scheduled_event_validation() {
+    let manager = RequestManager::new_with_token(Default::default(), "token".into());
+
+    let body = CreateGuildScheduledEventBody {
+        channel_id: None,
+        entity_metadata: Some(GuildScheduledEventEntityMetadata {
+            location: Some("Wumpus' house".into()),
+        }),
+        name: "Game night".into(),
+        privacy_level: GuildScheduledEventPrivacyLevel::GuildOnly,
+        scheduled_start_time: "2023-03-10T20:00:00+00:00".into(),
+        scheduled_end_time: None,
+        description: None,
+        entity_type: GuildScheduledEventEntityType::External,
+        image: None,
+    };
+
+    let error = manager
+        .create_guild_scheduled_event(&Snowflake::new(197038439483310086), body, None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RequestError::Validation(ValidationError::Missing {
+            field: "scheduled_end_time"
+        })
+    ));
+}
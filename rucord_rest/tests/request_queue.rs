@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use rucord_rest::{Method, RequestQueue, RouteId};
+use tokio::time::timeout;
+
+#[tokio::test]
+async fn test_request_queue_same_route() {
+    let queue = RequestQueue::new(1);
+    let route = RouteId::new(Method::PATCH, "/channels/1");
+
+    let permit = queue.acquire(&route).await;
+    assert!(permit.is_some());
+
+    assert!(
+        timeout(Duration::from_millis(50), queue.acquire(&route))
+            .await
+            .is_err(),
+        "expected the second request to the route to wait"
+    );
+
+    assert!(queue
+        .acquire(&RouteId::new(Method::PATCH, "/channels/2"))
+        .await
+        .is_some());
+
+    drop(permit);
+
+    assert!(timeout(Duration::from_millis(50), queue.acquire(&route))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_request_queue_get_unlimited() {
+    let queue = RequestQueue::new(1);
+    let route = RouteId::new(Method::GET, "/channels/1");
+
+    assert!(queue.acquire(&route).await.is_none());
+    assert!(queue.acquire(&route).await.is_none());
+}
@@ -0,0 +1,96 @@
+use std::{thread::sleep, time::Duration};
+
+use rucord_rest::{Dummy, RequestManager, RequestOptions, ResponseCache};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+#[test]
+fn test_response_cache_ttl() {
+    let cache = ResponseCache::new();
+    cache.insert("https://discord.com/api/v10/gateway", b"{}".to_vec());
+
+    assert_eq!(
+        cache.get(
+            "https://discord.com/api/v10/gateway",
+            Duration::from_secs(60)
+        ),
+        Some(b"{}".to_vec())
+    );
+
+    sleep(Duration::from_millis(20));
+    assert_eq!(
+        cache.get(
+            "https://discord.com/api/v10/gateway",
+            Duration::from_millis(10)
+        ),
+        None
+    );
+    assert_eq!(
+        cache.get(
+            "https://discord.com/api/v10/gateway",
+            Duration::from_secs(60)
+        ),
+        None
+    );
+}
+
+#[test]
+fn test_response_cache_invalidate() {
+    let cache = ResponseCache::new();
+    cache.insert("https://discord.com/api/v10/users/@me", b"{}".to_vec());
+    cache.invalidate("https://discord.com/api/v10/users/@me");
+
+    assert_eq!(
+        cache.get(
+            "https://discord.com/api/v10/users/@me",
+            Duration::from_secs(60)
+        ),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_cached_request() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/gateway", listener.local_addr().unwrap());
+
+    // Answers a single request, the second one has to come from the cache.
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut request = vec![0; 1024];
+        let _ = stream.read(&mut request).await.unwrap();
+
+        let body = r#"{"url":"wss://gateway.discord.gg"}"#;
+        stream
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                    Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+    });
+
+    let rest = RequestManager::default();
+
+    for _ in 0..2 {
+        let response = rest
+            .request(
+                RequestOptions::<Dummy>::get(url.clone(), None).cached(Duration::from_secs(60)),
+            )
+            .await
+            .unwrap();
+
+        assert!(response.status().is_success());
+        assert_eq!(
+            response.text().await.unwrap(),
+            r#"{"url":"wss://gateway.discord.gg"}"#
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use futures::{
+    future::{select, Either},
+    pin_mut,
+};
+use rucord_api_types::Snowflake;
+use rucord_rest::{RequestError, RequestManager};
+use tokio::{sync::Mutex, time::sleep};
+
+/// How often the typing indicator is re-sent, it expires after 10 seconds.
+const TYPING_INTERVAL: Duration = Duration::from_secs(9);
+
+/// Common bot actions, built on top of the REST manager.
+#[derive(Clone)]
+pub struct BotActions {
+    rest: Arc<Mutex<RequestManager>>,
+}
+
+impl BotActions {
+    #[inline]
+    pub fn new(rest: Arc<Mutex<RequestManager>>) -> Self {
+        Self { rest }
+    }
+
+    #[inline]
+    pub fn rest(&self) -> &Arc<Mutex<RequestManager>> {
+        &self.rest
+    }
+
+    /// Shows the typing indicator in the channel for 10 seconds, or until a message is sent.
+    pub async fn send_typing(&self, channel_id: &Snowflake) -> Result<(), RequestError> {
+        self.rest.lock().await.trigger_typing(channel_id).await?;
+        Ok(())
+    }
+
+    /// Keeps the typing indicator in the channel until the future completes. The indicator is
+    /// sent from the returned future, so it stops as soon as that future is dropped.
+    pub async fn send_typing_until<F: Future>(
+        &self,
+        channel_id: &Snowflake,
+        future: F,
+    ) -> F::Output {
+        let typing = async {
+            loop {
+                // A failed indicator isn't worth interrupting the work for.
+                let _ = self.send_typing(channel_id).await;
+                sleep(TYPING_INTERVAL).await;
+            }
+        };
+
+        pin_mut!(future, typing);
+
+        match select(future, typing).await {
+            Either::Left((output, _)) => output,
+            Either::Right((never, _)) => never,
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    collections::VecDeque,
+    sync::atomic::{AtomicI64, Ordering},
+};
+
+use dashmap::DashMap;
+use rucord_api_types::{GuildObject, MessageObject, MessageUpdateObject, Snowflake};
+
+/// Stores the state received by the shards, shared by every shard of the manager.
+pub trait Cache: Send + Sync {
+    /// Stores the guild, replacing the cached one.
+    fn insert_guild(&self, guild: &GuildObject);
+
+    fn get_guild(&self, guild_id: &Snowflake) -> Option<GuildObject>;
+
+    /// Counts a member that joined the guild, does nothing for guilds that aren't cached.
+    fn increment_member_count(&self, guild_id: &Snowflake);
+
+    /// Counts a member that left the guild, does nothing for guilds that aren't cached.
+    fn decrement_member_count(&self, guild_id: &Snowflake);
+
+    fn get_member_count(&self, guild_id: &Snowflake) -> Option<u64>;
+
+    /// Stores the message, replacing the cached one.
+    fn insert_message(&self, message: &MessageObject);
+
+    fn get_message(&self, channel_id: &Snowflake, message_id: &Snowflake) -> Option<MessageObject>;
+
+    /// Merges the edit into the cached message, does nothing for messages that aren't cached
+    /// since the update only contains the changed fields.
+    fn update_message(&self, update: &MessageUpdateObject);
+}
+
+/// A [`Cache`] kept in memory. The member counts are tracked apart from the guilds, so joins
+/// and leaves don't lock the guild. Only the latest [`MESSAGES_PER_CHANNEL`](Self::MESSAGES_PER_CHANNEL)
+/// messages of each channel are kept.
+#[derive(Default)]
+pub struct MemoryCache {
+    guilds: DashMap<Snowflake, GuildObject>,
+
+    member_counts: DashMap<Snowflake, AtomicI64>,
+
+    messages: DashMap<Snowflake, VecDeque<MessageObject>>,
+}
+
+impl MemoryCache {
+    pub const MESSAGES_PER_CHANNEL: usize = 100;
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Cache for MemoryCache {
+    fn insert_guild(&self, guild: &GuildObject) {
+        if let Some(member_count) = guild.member_count {
+            self.member_counts
+                .insert(guild.id, AtomicI64::new(member_count as i64));
+        }
+
+        self.guilds.insert(guild.id, guild.clone());
+    }
+
+    fn get_guild(&self, guild_id: &Snowflake) -> Option<GuildObject> {
+        let mut guild = self.guilds.get(guild_id)?.clone();
+
+        if let Some(member_count) = self.get_member_count(guild_id) {
+            guild.member_count = Some(member_count);
+        }
+
+        Some(guild)
+    }
+
+    fn increment_member_count(&self, guild_id: &Snowflake) {
+        if let Some(count) = self.member_counts.get(guild_id) {
+            count.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn decrement_member_count(&self, guild_id: &Snowflake) {
+        if let Some(count) = self.member_counts.get(guild_id) {
+            count.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    fn get_member_count(&self, guild_id: &Snowflake) -> Option<u64> {
+        self.member_counts
+            .get(guild_id)
+            .map(|count| count.load(Ordering::Relaxed).max(0) as u64)
+    }
+
+    fn insert_message(&self, message: &MessageObject) {
+        let mut messages = self.messages.entry(message.channel_id).or_default();
+
+        if let Some(cached) = messages.iter_mut().find(|cached| cached.id == message.id) {
+            *cached = message.clone();
+            return;
+        }
+
+        if messages.len() == Self::MESSAGES_PER_CHANNEL {
+            messages.pop_front();
+        }
+
+        messages.push_back(message.clone());
+    }
+
+    fn get_message(&self, channel_id: &Snowflake, message_id: &Snowflake) -> Option<MessageObject> {
+        self.messages
+            .get(channel_id)?
+            .iter()
+            .find(|message| message.id == *message_id)
+            .cloned()
+    }
+
+    fn update_message(&self, update: &MessageUpdateObject) {
+        let Some(mut messages) = self.messages.get_mut(&update.channel_id) else {
+            return;
+        };
+
+        if let Some(message) = messages.iter_mut().find(|message| message.id == update.id) {
+            update.apply_to(message);
+        }
+    }
+}
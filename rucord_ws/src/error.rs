@@ -1,12 +1,16 @@
-use async_tungstenite::tungstenite::{protocol::CloseFrame, Error as TungsteniteError};
+use std::time::Duration;
+
+use async_tungstenite::tungstenite::{
+    error::ProtocolError, protocol::CloseFrame, Error as TungsteniteError,
+};
 use derive_more::{Display, Error, From};
-use rucord_rest::reqwest::Error as RegError;
+use rucord_rest::{RequestError, TlsBackend};
 use serde_json::Error as JsonError;
 
 #[derive(Debug, From, Error, Display)]
 pub enum WebSocketError {
     #[display(fmt = "{_0}")]
-    Request(RegError),
+    Request(RequestError),
     #[display(fmt = "{_0}")]
     Shard(ShardError),
     #[display(fmt = "There are only {_0} sessions available, \
@@ -14,19 +18,72 @@ pub enum WebSocketError {
     NotEnoughSessionsRemaining(u64, u64),
     #[display(fmt = "{_0}")]
     Json(JsonError),
+    #[display(fmt = "The gateway URL uses API version {_0}, but version {_1} is required.")]
+    ApiVersionMismatch(u8, u8),
+    #[display(fmt = "Invalid proxy configuration: {_0}")]
+    ProxyConfiguration(#[error(not(source))] String),
+    #[display(fmt = "The {_0:?} TLS backend isn't available for the gateway connections.")]
+    TlsBackendUnavailable(#[error(not(source))] TlsBackend),
+    #[display(fmt = "The new shards weren't ready after {_0:?}, the reshard was rolled back.")]
+    #[from(ignore)]
+    ReshardTimedOut(#[error(not(source))] Duration),
+    #[display(fmt = "A new shard exited before it was ready, the reshard was rolled back.")]
+    ReshardShardExited,
 }
 
-#[derive(Debug, Error, From, Display)]
+#[derive(Debug, From, Display)]
 pub enum ShardError {
     #[display(fmt = "attempting to establish a connection with a non-idle shard")]
     NotIdle,
+    /// The shard has no gateway connection to send the payload through.
+    #[display(fmt = "the shard isn't connected to the gateway")]
+    NotConnected,
+    /// Transport errors, e.g. IO or TLS failures.
     #[display(fmt = "{_0}")]
-    Tungstenite(TungsteniteError),
+    #[from(ignore)]
+    Tungstenite(Box<TungsteniteError>),
+    /// The gateway broke the WebSocket protocol, e.g. reset the connection without a close frame.
+    #[display(fmt = "WebSocket protocol error: {_0}")]
+    TungsteniteProtocol(Box<ProtocolError>),
     #[display(
         fmt = "{}",
         "_0.as_ref()
         .map_or_else(|| \"Gateway Closed without reason\".into(),
         |e| format!(\"Gateway Closed: {}({})\", e.code, e.reason))"
     )]
-    Closed(#[error(not(source))] Option<CloseFrame<'static>>),
+    Closed(Option<CloseFrame<'static>>),
+}
+
+#[derive(Debug, Error, From, Display)]
+pub enum CommandError {
+    /// No handler is registered for the command, the user is answered with an ephemeral message.
+    #[display(fmt = "No handler is registered for the `{_0}` command")]
+    #[from(ignore)]
+    UnknownCommand(#[error(not(source))] String),
+    #[display(fmt = "{_0}")]
+    Request(RequestError),
+    /// Reported by the command handler.
+    #[display(fmt = "{_0}")]
+    #[from(ignore)]
+    Failed(#[error(not(source))] String),
+}
+
+/// Implemented by hand so the boxed errors are reported as their source instead of the box.
+impl std::error::Error for ShardError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Tungstenite(error) => Some(&**error),
+            Self::TungsteniteProtocol(error) => Some(&**error),
+            _ => None,
+        }
+    }
+}
+
+impl From<TungsteniteError> for ShardError {
+    fn from(error: TungsteniteError) -> Self {
+        match error {
+            TungsteniteError::Protocol(error) => Self::TungsteniteProtocol(Box::new(error)),
+            error => Self::Tungstenite(Box::new(error)),
+        }
+    }
 }
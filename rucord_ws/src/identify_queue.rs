@@ -4,13 +4,15 @@ use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 
 pub struct IdentifyQueue {
-    identify_state: Mutex<IdentifyState>,
+    identify_state: Arc<Mutex<IdentifyState>>,
     gateway_info: Arc<Mutex<GatewayBotObject>>,
+    session_low_water_mark: f64,
 }
 
 struct IdentifyState {
     remaining: u64,
     reset_time: Instant,
+    max_concurrency: Option<u64>,
 }
 
 impl IdentifyQueue {
@@ -18,14 +20,33 @@ impl IdentifyQueue {
 
     pub fn new(gateway_info: Arc<Mutex<GatewayBotObject>>) -> Self {
         IdentifyQueue {
-            identify_state: Mutex::new(IdentifyState {
+            identify_state: Arc::new(Mutex::new(IdentifyState {
                 remaining: 0,
                 reset_time: Instant::now().checked_sub(Self::FIVE_SECOND).unwrap(),
-            }),
+                max_concurrency: None,
+            })),
             gateway_info,
+            session_low_water_mark: 0.1,
         }
     }
 
+    /// A queue counting the session starts against `gateway_info`, which shares the identify rate
+    /// limit of this queue. Used while two sets of shards identify, e.g. during a reshard.
+    pub fn share_rate_limit(&self, gateway_info: Arc<Mutex<GatewayBotObject>>) -> Self {
+        IdentifyQueue {
+            identify_state: self.identify_state.clone(),
+            gateway_info,
+            session_low_water_mark: self.session_low_water_mark,
+        }
+    }
+
+    /// The fraction of the total session starts below which `session_started` warns,
+    /// clamped to `[0.0, 1.0]`, defaults to `0.1`.
+    pub fn with_session_low_water_mark(mut self, ratio: f64) -> Self {
+        self.session_low_water_mark = ratio.clamp(0.0, 1.0);
+        self
+    }
+
     pub async fn wait_for_identify(&self) {
         let mut identify_state = self.identify_state.lock().await;
 
@@ -36,16 +57,60 @@ impl IdentifyQueue {
                 tokio::time::sleep(Self::FIVE_SECOND - elapsed_since_reset).await;
             }
 
-            identify_state.remaining = self
-                .gateway_info
-                .lock()
-                .await
-                .session_start_limit
-                .max_concurrency;
+            identify_state.remaining = match identify_state.max_concurrency {
+                Some(max_concurrency) => max_concurrency,
+                None => {
+                    let max_concurrency = self
+                        .gateway_info
+                        .lock()
+                        .await
+                        .session_start_limit
+                        .max_concurrency;
+
+                    *identify_state.max_concurrency.insert(max_concurrency)
+                }
+            };
 
             identify_state.reset_time = Instant::now();
         }
 
         identify_state.remaining -= 1;
     }
+
+    /// Waits for `reset_after` when there are no session starts remaining, before the connection
+    /// is opened since the wait can last until the daily limit resets.
+    pub async fn wait_for_session_start(&self) {
+        let reset_after = {
+            let info = self.gateway_info.lock().await;
+
+            if info.session_start_limit.remaining > 0 {
+                return;
+            }
+
+            Duration::from_millis(info.session_start_limit.reset_after)
+        };
+
+        tokio::time::sleep(reset_after).await;
+
+        let mut info = self.gateway_info.lock().await;
+        info.session_start_limit.remaining = info.session_start_limit.total;
+    }
+
+    /// Counts an identify against the session start limit, returns the remaining and total
+    /// session starts once the remaining ones reach the low-water mark.
+    pub async fn session_started(&self) -> Option<(u64, u64)> {
+        let mut info = self.gateway_info.lock().await;
+        let limit = &mut info.session_start_limit;
+
+        limit.remaining = limit.remaining.saturating_sub(1);
+
+        let low_water_mark = (limit.total as f64 * self.session_low_water_mark) as u64;
+
+        (limit.remaining <= low_water_mark).then_some((limit.remaining, limit.total))
+    }
+
+    /// Clears the cached `max_concurrency`, so the next identify re-reads it from the gateway info.
+    pub async fn reset(&self) {
+        self.identify_state.lock().await.max_concurrency = None;
+    }
 }
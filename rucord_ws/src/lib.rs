@@ -1,23 +1,43 @@
 #[macro_use]
-
 mod macros;
 
+pub mod bot_actions;
+pub mod cache;
 pub mod error;
 pub mod identify_queue;
+pub mod metrics;
+pub mod multicast_event_handler;
+pub mod proxy;
+pub mod request_guild_members_queue;
+pub mod router;
+pub mod session_store;
 pub mod shard_bucket;
+pub mod typed_event_handler;
+pub mod voice;
 pub mod websocket;
 pub mod websocket_manager;
 pub mod websocket_shard;
 pub mod websocket_worker;
 
+pub use bot_actions::*;
+pub use cache::*;
 pub use error::*;
 pub use identify_queue::*;
+pub use metrics::*;
+pub use multicast_event_handler::*;
+pub use proxy::*;
+pub use request_guild_members_queue::*;
+pub use router::*;
+pub use session_store::*;
 pub use shard_bucket::*;
+pub use typed_event_handler::*;
+pub use voice::*;
 pub use websocket::*;
 pub use websocket_manager::*;
 pub use websocket_shard::*;
 pub use websocket_worker::*;
 
+pub use async_tungstenite::tungstenite;
 pub use rucord_api_types as api_types;
 
 pub type Result<T> = core::result::Result<T, WebSocketError>;
@@ -1 +1,93 @@
+/// Calls `$callback!` with the tokens in the brackets followed by the signature of every
+/// [`WebSocketEventHandler`](crate::WebSocketEventHandler) method, keep the list in sync with the
+/// trait or the forwarding handlers silently fall back to the default methods.
+macro_rules! with_event_handler_methods {
+    ($callback:ident!($($prefix:tt)*)) => {
+        $callback! {
+            $($prefix)*
+            debug(shard_id: ShardId, message: String);
+            shard_error(shard_id: ShardId, error: &ShardError);
+            dispatch(shard_id: ShardId, data: &DispatchPayload);
+            channel_create(shard_id: ShardId, channel: &ChannelObject);
+            channel_pins_update(shard_id: ShardId, data: &ChannelPinsUpdateObject);
+            message_delete(shard_id: ShardId, data: &MessageDeleteObject);
+            message_delete_bulk(shard_id: ShardId, data: &MessageDeleteBulkObject);
+            message_reaction_remove_all(shard_id: ShardId, data: &MessageReactionRemoveAllObject);
+            message_reaction_remove_emoji(
+                shard_id: ShardId,
+                data: &MessageReactionRemoveEmojiObject
+            );
+            application_command_permissions_update(
+                shard_id: ShardId,
+                data: &ApplicationCommandPermissionsUpdateObject
+            );
+            guild_audit_log_entry_create(shard_id: ShardId, data: &GuildAuditLogEntryCreateObject);
+            guild_member_update(shard_id: ShardId, data: &GuildMemberUpdateObject);
+            guild_members_chunk(shard_id: ShardId, data: &GuildMembersChunkObject);
+            thread_member_update(shard_id: ShardId, data: &ThreadMemberUpdateObject);
+            thread_members_update(shard_id: ShardId, data: &ThreadMembersUpdateObject);
+            guild_ban_add(shard_id: ShardId, data: &GuildBanAddObject);
+            guild_ban_remove(shard_id: ShardId, data: &GuildBanRemoveObject);
+            guild_role_create(shard_id: ShardId, data: &GuildRoleCreateObject);
+            guild_role_update(shard_id: ShardId, data: &GuildRoleUpdateObject);
+            guild_role_delete(shard_id: ShardId, data: &GuildRoleDeleteObject);
+            guild_scheduled_event_user_add(
+                shard_id: ShardId,
+                data: &GuildScheduledEventUserAddObject
+            );
+            guild_scheduled_event_user_remove(
+                shard_id: ShardId,
+                data: &GuildScheduledEventUserRemoveObject
+            );
+            guild_emojis_update(shard_id: ShardId, data: &GuildEmojisUpdateObject);
+            guild_stickers_update(shard_id: ShardId, data: &GuildStickersUpdateObject);
+            guild_integrations_update(shard_id: ShardId, guild_id: &Snowflake);
+            guild_update(shard_id: ShardId, old: Option<GuildObject>, new: &GuildObject);
+            user_update(shard_id: ShardId, user: &UserObject);
+            voice_server_update(shard_id: ShardId, data: &VoiceServerUpdateObject);
+            ready(shard_id: ShardId, data: &ReadyData);
+            session_start_limit_warning(remaining: u64, total: u64);
+            resumed(shard_id: ShardId);
+            shard_reconnecting(shard_id: ShardId, reason: ReconnectReason);
+            shard_reconnected(shard_id: ShardId, resumed: bool);
+            shard_destroyed(shard_id: ShardId, reason: DestroyReason);
+            reshard_started(old_total: u64, new_total: u64);
+            reshard_completed(old_total: u64, new_total: u64);
+            watchdog_triggered(shard_id: ShardId, elapsed: Duration);
+            unknown_dispatch_event(shard_id: ShardId, event_name: &str, data: &Map<String, Value>);
+            unknown_op(shard_id: ShardId, op: u64, data: &Map<String, Value>);
+        }
+    };
+}
 
+/// Implements [`WebSocketEventHandler`](crate::WebSocketEventHandler) for `$Type` by forwarding
+/// every method, either to the single handler `$target` evaluates to, or to each handler `$targets`
+/// iterates over, in order. Used through [`with_event_handler_methods`].
+macro_rules! forward_event_handler {
+    (
+        impl[$($generics:tt)*] $Type:ty => |$this:ident| for $targets:expr;
+        $($method:ident($($arg:ident: $ty:ty),* $(,)?);)+
+    ) => {
+        #[async_trait]
+        impl<$($generics)*> WebSocketEventHandler for $Type {
+            $(async fn $method(&self, $($arg: $ty),*) {
+                let $this = self;
+                for handler in $targets {
+                    handler.$method($(Clone::clone(&$arg)),*).await;
+                }
+            })+
+        }
+    };
+    (
+        impl[$($generics:tt)*] $Type:ty => |$this:ident| $target:expr;
+        $($method:ident($($arg:ident: $ty:ty),* $(,)?);)+
+    ) => {
+        #[async_trait]
+        impl<$($generics)*> WebSocketEventHandler for $Type {
+            $(async fn $method(&self, $($arg: $ty),*) {
+                let $this = self;
+                $target.$method($($arg),*).await
+            })+
+        }
+    };
+}
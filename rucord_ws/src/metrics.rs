@@ -0,0 +1,23 @@
+/// Receives the metrics of the shards, so they can be exported to any metrics library.
+///
+/// Every metric has a `shard` label, the recorded metrics are:
+/// - `gateway_events_received_total` counter, with an `event` label.
+/// - `gateway_heartbeat_latency_seconds` histogram.
+/// - `gateway_reconnects_total` counter, with a `reason` label.
+/// - `gateway_identifies_total` counter.
+/// - `gateway_resumes_total` counter.
+pub trait MetricsSink: Send + Sync {
+    fn increment_counter(&self, name: &str, labels: &[(&str, &str)]);
+    fn set_gauge(&self, name: &str, value: f64, labels: &[(&str, &str)]);
+    fn observe_histogram(&self, name: &str, value: f64, labels: &[(&str, &str)]);
+}
+
+/// Discards every metric.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopMetricsSink;
+
+impl MetricsSink for NoopMetricsSink {
+    fn increment_counter(&self, _name: &str, _labels: &[(&str, &str)]) {}
+    fn set_gauge(&self, _name: &str, _value: f64, _labels: &[(&str, &str)]) {}
+    fn observe_histogram(&self, _name: &str, _value: f64, _labels: &[(&str, &str)]) {}
+}
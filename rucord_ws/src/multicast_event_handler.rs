@@ -0,0 +1,51 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use rucord_api_types::{
+    ApplicationCommandPermissionsUpdateObject, ChannelObject, ChannelPinsUpdateObject,
+    DispatchPayload, GuildAuditLogEntryCreateObject, GuildBanAddObject, GuildBanRemoveObject,
+    GuildEmojisUpdateObject, GuildMemberUpdateObject, GuildMembersChunkObject, GuildObject,
+    GuildRoleCreateObject, GuildRoleDeleteObject, GuildRoleUpdateObject,
+    GuildScheduledEventUserAddObject, GuildScheduledEventUserRemoveObject,
+    GuildStickersUpdateObject, MessageDeleteBulkObject, MessageDeleteObject,
+    MessageReactionRemoveAllObject, MessageReactionRemoveEmojiObject, ReadyData, Snowflake,
+    ThreadMemberUpdateObject, ThreadMembersUpdateObject, UserObject, VoiceServerUpdateObject,
+};
+use serde_json::{Map, Value};
+
+use crate::{DestroyReason, ReconnectReason, ShardError, ShardId, WebSocketEventHandler};
+
+/// Fans every event out to several handlers, they are called one after the other in the order
+/// they were added, so a handler only sees an event once the previous ones are done with it.
+#[derive(Default, Clone)]
+pub struct MulticastEventHandler {
+    handlers: Vec<Arc<dyn WebSocketEventHandler>>,
+}
+
+impl MulticastEventHandler {
+    #[inline]
+    pub fn new(handlers: Vec<Arc<dyn WebSocketEventHandler>>) -> Self {
+        Self { handlers }
+    }
+
+    /// Adds a handler called after the current ones.
+    #[inline]
+    pub fn with<T: WebSocketEventHandler + 'static>(mut self, handler: Arc<T>) -> Self {
+        self.push(handler);
+        self
+    }
+
+    #[inline]
+    pub fn push<T: WebSocketEventHandler + 'static>(&mut self, handler: Arc<T>) {
+        self.handlers.push(handler);
+    }
+
+    #[inline]
+    pub fn handlers(&self) -> &[Arc<dyn WebSocketEventHandler>] {
+        &self.handlers
+    }
+}
+
+with_event_handler_methods!(forward_event_handler!(
+    impl[] MulticastEventHandler => |this| for &this.handlers;
+));
@@ -0,0 +1,144 @@
+use async_tungstenite::tungstenite::Error as TungsteniteError;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use url::Url;
+
+use crate::{Result, ShardError, WebSocketError};
+
+/// The longest proxy response accepted while opening a tunnel.
+const MAX_CONNECT_RESPONSE: usize = 8192;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyScheme {
+    Http,
+    Https,
+    Socks5,
+}
+
+/// A proxy to route the gateway and REST traffic through.
+///
+/// `socks5://` proxies require the `socks5` feature, and `https://` proxies are only
+/// supported for REST requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proxy {
+    url: Url,
+    scheme: ProxyScheme,
+}
+
+impl Proxy {
+    pub fn parse(url: &str) -> Result<Self> {
+        let url = Url::parse(url).map_err(|e| WebSocketError::ProxyConfiguration(e.to_string()))?;
+
+        let scheme = match url.scheme() {
+            "http" => ProxyScheme::Http,
+            "https" => ProxyScheme::Https,
+            "socks5" => ProxyScheme::Socks5,
+            scheme => Err(WebSocketError::ProxyConfiguration(format!(
+                "unsupported proxy scheme `{scheme}`"
+            )))?,
+        };
+
+        if url.host_str().is_none() {
+            Err(WebSocketError::ProxyConfiguration(
+                "the proxy URL has no host".into(),
+            ))?;
+        }
+
+        Ok(Self { url, scheme })
+    }
+
+    #[inline]
+    pub fn scheme(&self) -> ProxyScheme {
+        self.scheme
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.url.as_str()
+    }
+
+    fn address(&self) -> (&str, u16) {
+        let port = self.url.port_or_known_default().unwrap_or(1080);
+
+        (self.url.host_str().unwrap_or_default(), port)
+    }
+
+    /// Opens a TCP tunnel to the given host through the proxy.
+    pub(crate) async fn tunnel(&self, host: &str, port: u16) -> Result<TcpStream> {
+        match self.scheme {
+            ProxyScheme::Http => self.http_tunnel(host, port).await,
+            ProxyScheme::Https => Err(WebSocketError::ProxyConfiguration(
+                "`https://` proxies are only supported for REST requests".into(),
+            )),
+            ProxyScheme::Socks5 => self.socks5_tunnel(host, port).await,
+        }
+    }
+
+    async fn http_tunnel(&self, host: &str, port: u16) -> Result<TcpStream> {
+        let mut stream = TcpStream::connect(self.address()).await.map_err(io_error)?;
+
+        stream
+            .write_all(
+                format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n\r\n").as_bytes(),
+            )
+            .await
+            .map_err(io_error)?;
+
+        let mut response = Vec::new();
+
+        while !response.ends_with(b"\r\n\r\n") {
+            if response.len() > MAX_CONNECT_RESPONSE {
+                Err(WebSocketError::ProxyConfiguration(
+                    "the proxy response is too large".into(),
+                ))?;
+            }
+
+            response.push(stream.read_u8().await.map_err(io_error)?);
+        }
+
+        let response = String::from_utf8_lossy(&response);
+        let status = response.lines().next().unwrap_or_default();
+
+        if status.split_whitespace().nth(1) != Some("200") {
+            Err(WebSocketError::ProxyConfiguration(format!(
+                "the proxy refused the tunnel: {status}"
+            )))?;
+        }
+
+        Ok(stream)
+    }
+
+    #[cfg(feature = "socks5")]
+    async fn socks5_tunnel(&self, host: &str, port: u16) -> Result<TcpStream> {
+        use tokio_socks::tcp::Socks5Stream;
+
+        let stream = match self.url.username() {
+            "" => Socks5Stream::connect(self.address(), (host, port)).await,
+            username => {
+                Socks5Stream::connect_with_password(
+                    self.address(),
+                    (host, port),
+                    username,
+                    self.url.password().unwrap_or_default(),
+                )
+                .await
+            }
+        }
+        .map_err(|e| WebSocketError::ProxyConfiguration(e.to_string()))?;
+
+        Ok(stream.into_inner())
+    }
+
+    #[cfg(not(feature = "socks5"))]
+    async fn socks5_tunnel(&self, _host: &str, _port: u16) -> Result<TcpStream> {
+        Err(WebSocketError::ProxyConfiguration(
+            "`socks5://` proxies require the `socks5` feature".into(),
+        ))
+    }
+}
+
+fn io_error(error: std::io::Error) -> WebSocketError {
+    ShardError::from(TungsteniteError::Io(error)).into()
+}
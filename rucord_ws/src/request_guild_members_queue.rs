@@ -0,0 +1,99 @@
+use std::{collections::VecDeque, time::Duration};
+
+use rucord_api_types::RequestGuildMembersData;
+use tokio::time::Instant;
+
+/// Holds back the `REQUEST_GUILD_MEMBERS` payloads of a shard, they count against the gateway
+/// rate limit of its connection. The shard sends the requests the window allows from its event
+/// loop, so waiting for the window never blocks the events or the heartbeats.
+pub struct RequestGuildMembersQueue {
+    pending: VecDeque<RequestGuildMembersData>,
+    remaining: u32,
+    reset_time: Instant,
+    limit: u32,
+    window: Duration,
+}
+
+impl RequestGuildMembersQueue {
+    /// The documented gateway rate limit, 120 payloads per minute.
+    pub const LIMIT: u32 = 120;
+    pub const WINDOW: Duration = Duration::from_secs(60);
+
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_limit(Self::LIMIT, Self::WINDOW)
+    }
+
+    /// Allows `limit` requests in each `window` instead of the documented gateway rate limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    pub fn with_limit(limit: u32, window: Duration) -> Self {
+        assert!(
+            limit > 0,
+            "the request guild members limit must be positive"
+        );
+
+        RequestGuildMembersQueue {
+            pending: VecDeque::new(),
+            remaining: limit,
+            reset_time: Instant::now(),
+            limit,
+            window,
+        }
+    }
+
+    /// Queues the request after the ones already waiting for the window.
+    #[inline]
+    pub fn push(&mut self, data: RequestGuildMembersData) {
+        self.pending.push_back(data);
+    }
+
+    /// The next queued request, counted against the window. `None` when nothing is queued or no
+    /// request is remaining until the window resets.
+    pub fn pop_ready(&mut self) -> Option<RequestGuildMembersData> {
+        if self.pending.is_empty() {
+            return None;
+        }
+
+        if self.reset_time.elapsed() >= self.window {
+            self.remaining = self.limit;
+            self.reset_time = Instant::now();
+        }
+
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.pending.pop_front()
+    }
+
+    /// The requests that can be sent before the queue starts holding them back.
+    pub fn remaining(&self) -> u32 {
+        if self.reset_time.elapsed() >= self.window {
+            self.limit
+        } else {
+            self.remaining
+        }
+    }
+
+    /// How many requests are waiting for the window.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+impl Default for RequestGuildMembersQueue {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,124 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_trait::async_trait;
+use rucord_api_types::{InteractionCallbackData, InteractionObject, InteractionResponseObject};
+use rucord_rest::RequestManager;
+use tokio::sync::Mutex;
+
+use crate::{CommandError, ShardId};
+
+#[async_trait]
+pub trait ComponentHandler: Send + Sync {
+    async fn handle(
+        &self,
+        shard_id: ShardId,
+        interaction: &InteractionObject,
+        rest: Arc<Mutex<RequestManager>>,
+    );
+}
+
+/// Routes component interactions to the handler registered for their `custom_id`.
+#[derive(Default, Clone)]
+pub struct ComponentRouter {
+    handlers: HashMap<String, Arc<dyn ComponentHandler>>,
+}
+
+impl ComponentRouter {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler of the given `custom_id`, replacing the previous one.
+    pub fn register(
+        &mut self,
+        custom_id: impl Into<String>,
+        handler: impl ComponentHandler + 'static,
+    ) -> &mut Self {
+        self.handlers.insert(custom_id.into(), Arc::new(handler));
+        self
+    }
+
+    /// Calls the handler registered for the interaction's `custom_id`, returns whether one was
+    /// found.
+    pub async fn route(
+        &self,
+        shard_id: ShardId,
+        interaction: &InteractionObject,
+        rest: Arc<Mutex<RequestManager>>,
+    ) -> bool {
+        let Some(handler) = interaction
+            .custom_id()
+            .and_then(|custom_id| self.handlers.get(custom_id))
+        else {
+            return false;
+        };
+
+        handler.handle(shard_id, interaction, rest).await;
+
+        true
+    }
+}
+
+#[async_trait]
+pub trait ApplicationCommandHandler: Send + Sync {
+    /// The name of the command, as registered with Discord.
+    fn name() -> &'static str
+    where
+        Self: Sized;
+
+    async fn execute(
+        &self,
+        shard_id: ShardId,
+        interaction: &InteractionObject,
+        rest: Arc<Mutex<RequestManager>>,
+    ) -> Result<(), CommandError>;
+}
+
+/// Routes application command interactions to the handler registered for their command name.
+#[derive(Default, Clone)]
+pub struct CommandRegistry {
+    handlers: HashMap<&'static str, Arc<dyn ApplicationCommandHandler>>,
+}
+
+impl CommandRegistry {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the handler under its [`name`](ApplicationCommandHandler::name), replacing the
+    /// previous handler of the command.
+    pub fn register<T: ApplicationCommandHandler + 'static>(&mut self, handler: T) -> &mut Self {
+        self.handlers.insert(T::name(), Arc::new(handler));
+        self
+    }
+
+    /// Executes the handler registered for the interaction's command. Without one, the user is
+    /// told the command is unknown with an ephemeral message.
+    pub async fn route(
+        &self,
+        shard_id: ShardId,
+        interaction: &InteractionObject,
+        rest: Arc<Mutex<RequestManager>>,
+    ) -> Result<(), CommandError> {
+        let name = interaction.command_name().unwrap_or_default();
+
+        let Some(handler) = self.handlers.get(name) else {
+            rest.lock()
+                .await
+                .create_interaction_response(
+                    &interaction.id,
+                    &interaction.token,
+                    InteractionResponseObject::message(InteractionCallbackData::ephemeral(
+                        "This command is unknown.",
+                    )),
+                )
+                .await?;
+
+            return Err(CommandError::UnknownCommand(name.to_owned()));
+        };
+
+        handler.execute(shard_id, interaction, rest).await
+    }
+}
@@ -0,0 +1,133 @@
+use async_trait::async_trait;
+use dashmap::DashMap;
+use derive_more::{Display, Error, From};
+use serde_json::Error as JsonError;
+
+use crate::{SessionSnapshot, ShardId};
+
+#[derive(Debug, From, Error, Display)]
+pub enum StoreError {
+    #[display(fmt = "{_0}")]
+    Json(JsonError),
+    #[cfg(feature = "redis")]
+    #[display(fmt = "{_0}")]
+    Redis(redis::RedisError),
+}
+
+/// Persists the shard sessions, so a new process resumes them instead of identifying.
+///
+/// The shards save their session when they are destroyed for good, load it on their first
+/// connection, and remove it once the gateway invalidated it.
+#[async_trait]
+pub trait SessionStore: Send + Sync {
+    async fn save(&self, snapshot: &SessionSnapshot) -> Result<(), StoreError>;
+
+    async fn load(&self, shard_id: ShardId) -> Result<Option<SessionSnapshot>, StoreError>;
+
+    async fn remove(&self, shard_id: ShardId) -> Result<(), StoreError>;
+}
+
+/// A [`SessionStore`] kept in memory, only useful to move sessions between managers of the
+/// same process.
+#[derive(Default)]
+pub struct MemorySessionStore {
+    snapshots: DashMap<ShardId, SessionSnapshot>,
+}
+
+impl MemorySessionStore {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SessionStore for MemorySessionStore {
+    async fn save(&self, snapshot: &SessionSnapshot) -> Result<(), StoreError> {
+        self.snapshots.insert(snapshot.shard_id, snapshot.clone());
+        Ok(())
+    }
+
+    async fn load(&self, shard_id: ShardId) -> Result<Option<SessionSnapshot>, StoreError> {
+        Ok(self
+            .snapshots
+            .get(&shard_id)
+            .map(|snapshot| snapshot.clone()))
+    }
+
+    async fn remove(&self, shard_id: ShardId) -> Result<(), StoreError> {
+        self.snapshots.remove(&shard_id);
+        Ok(())
+    }
+}
+
+/// A [`SessionStore`] saving each session as JSON under `{prefix}:{shard_id}`.
+#[cfg(feature = "redis")]
+pub struct RedisSessionStore {
+    client: redis::Client,
+
+    prefix: String,
+}
+
+#[cfg(feature = "redis")]
+impl RedisSessionStore {
+    #[inline]
+    pub fn new(client: redis::Client) -> Self {
+        Self {
+            client,
+            prefix: "rucord:session".into(),
+        }
+    }
+
+    /// Separates the sessions of several bots sharing the same Redis.
+    #[inline]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    #[inline]
+    fn key(&self, shard_id: ShardId) -> String {
+        format!("{}:{shard_id}", self.prefix)
+    }
+}
+
+#[cfg(feature = "redis")]
+#[async_trait]
+impl SessionStore for RedisSessionStore {
+    async fn save(&self, snapshot: &SessionSnapshot) -> Result<(), StoreError> {
+        let mut connection = self.client.get_async_connection().await?;
+
+        redis::cmd("SET")
+            .arg(self.key(snapshot.shard_id))
+            .arg(serde_json::to_string(snapshot)?)
+            .query_async::<_, ()>(&mut connection)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn load(&self, shard_id: ShardId) -> Result<Option<SessionSnapshot>, StoreError> {
+        let mut connection = self.client.get_async_connection().await?;
+
+        let snapshot: Option<String> = redis::cmd("GET")
+            .arg(self.key(shard_id))
+            .query_async(&mut connection)
+            .await?;
+
+        Ok(snapshot
+            .map(|snapshot| serde_json::from_str(&snapshot))
+            .transpose()?)
+    }
+
+    async fn remove(&self, shard_id: ShardId) -> Result<(), StoreError> {
+        let mut connection = self.client.get_async_connection().await?;
+
+        redis::cmd("DEL")
+            .arg(self.key(shard_id))
+            .query_async::<_, ()>(&mut connection)
+            .await?;
+
+        Ok(())
+    }
+}
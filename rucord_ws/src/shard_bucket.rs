@@ -1,12 +1,55 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use async_tungstenite::tungstenite::protocol::CloseFrame;
-use futures::future::join_all;
+use futures::future::{join_all, pending, select_all};
+use tokio::time::sleep;
 
-use crate::{ShardId, WebSocketWorker, WebSocketWorkerOptions};
+use crate::{
+    SessionSnapshot, ShardId, WebSocketShardStatus, WebSocketWorker, WebSocketWorkerOptions,
+};
 
+/// How often [`ShardBucket::wait_until_ready`] checks the status of the shards.
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The delay before the first respawn of a shard.
+const RESPAWN_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// The longest delay before a respawn, a shard that stayed up for longer starts over from
+/// [`RESPAWN_BASE_DELAY`].
+const RESPAWN_MAX_DELAY: Duration = Duration::from_secs(60);
+
+/// The delay before respawning a shard that already exited `attempts` times in a row, doubled on
+/// every attempt up to a minute.
+pub fn respawn_delay(attempts: u32) -> Duration {
+    RESPAWN_BASE_DELAY
+        .saturating_mul(1 << attempts.min(16))
+        .min(RESPAWN_MAX_DELAY)
+}
+
+/// Groups the shard ids by their identify rate limit key, `shard_id % max_concurrency`.
+pub fn bucket_shard_ids(ids: &[ShardId], max_concurrency: u64) -> Vec<Vec<ShardId>> {
+    let max_concurrency = max_concurrency.max(1) as usize;
+
+    let mut buckets = vec![Vec::new(); max_concurrency];
+
+    for id in ids {
+        buckets[id % max_concurrency].push(*id);
+    }
+
+    buckets.retain(|ids| !ids.is_empty());
+    buckets
+}
+
+/// The shards sharing an identify rate limit key, they are connected one after another.
 pub struct ShardBucket {
     pub workers: HashMap<ShardId, WebSocketWorker>,
+
+    /// How many times each shard was respawned in a row, and when it was last started.
+    respawns: HashMap<ShardId, (u32, Instant)>,
 }
 
 impl ShardBucket {
@@ -18,14 +61,162 @@ impl ShardBucket {
         .await;
 
         Self {
-            workers: workers.into_iter().enumerate().collect(),
+            workers: workers.into_iter().map(|w| (w.id, w)).collect(),
+            respawns: HashMap::new(),
         }
     }
 
     #[inline]
     pub async fn connect(&self) {
-        join_all(self.workers.values().map(|w| w.connect())).await;
+        self.connect_with_snapshots(HashMap::new()).await
+    }
+
+    /// Connects the workers in shard id order, resuming the sessions of the given snapshots.
+    pub async fn connect_with_snapshots(&self, mut snapshots: HashMap<ShardId, SessionSnapshot>) {
+        let mut ids: Vec<_> = self.workers.keys().copied().collect();
+        ids.sort_unstable();
+
+        for id in ids {
+            let worker = &self.workers[&id];
+
+            match snapshots.remove(&id) {
+                Some(snapshot) => worker.resume(snapshot).await,
+                None => worker.connect().await,
+            }
+        }
+    }
+
+    #[inline]
+    pub async fn save_sessions(&self) -> Vec<SessionSnapshot> {
+        join_all(self.workers.values().map(|w| w.save_session()))
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Waits for every shard to be ready, returns `false` as soon as a shard task exits instead.
+    pub async fn wait_until_ready(&self) -> bool {
+        for worker in self.workers.values() {
+            loop {
+                let Some(info) = worker.debug_info().await else {
+                    return false;
+                };
+
+                if info.status == WebSocketShardStatus::Ready {
+                    break;
+                }
+
+                sleep(READY_POLL_INTERVAL).await;
+            }
+        }
+
+        true
+    }
+
+    /// Waits for a shard task to exit without being destroyed, because of a panic or an
+    /// unhandled error. Returns the shard id and whether it panicked.
+    pub async fn wait_for_unexpected_exit(&self) -> (ShardId, bool) {
+        loop {
+            let exits: Vec<_> = self
+                .workers
+                .values()
+                .filter(|w| !w.is_destroyed())
+                .map(|w| {
+                    Box::pin(async move {
+                        let panicked =
+                            matches!(w.wait_for_exit().await, Err(err) if err.is_panic());
+                        (w.id, panicked)
+                    })
+                })
+                .collect();
+
+            if exits.is_empty() {
+                return pending().await;
+            }
+
+            let ((id, panicked), ..) = select_all(exits).await;
+
+            if !self.workers[&id].is_destroyed() {
+                return (id, panicked);
+            }
+        }
     }
+
+    /// The identify rate limit key shared by the shards of this bucket.
+    #[inline]
+    pub fn rate_limit_key(&self, max_concurrency: u64) -> Option<usize> {
+        let id = self.workers.keys().next()?;
+
+        Some(id % max_concurrency.max(1) as usize)
+    }
+
+    /// Adds a worker for the shard and connects it, replacing the previous worker of the shard.
+    /// The previous worker is destroyed first, so the shard never has two sessions at once.
+    pub async fn add_shard(&mut self, id: ShardId, worker_options: Arc<WebSocketWorkerOptions>) {
+        if let Some(previous) = self.workers.remove(&id) {
+            previous.destroy(None).await;
+        }
+
+        self.respawns.remove(&id);
+
+        let worker = WebSocketWorker::new(id, worker_options).await;
+
+        worker.connect().await;
+
+        self.workers.insert(id, worker);
+    }
+
+    /// Closes the shard cleanly and removes its worker, returns whether the shard was found.
+    pub async fn remove_shard(&mut self, id: ShardId) -> bool {
+        let Some(worker) = self.workers.remove(&id) else {
+            return false;
+        };
+
+        worker.close_cleanly().await;
+        worker.destroy(None).await;
+
+        true
+    }
+
+    /// Replaces the worker of an exited shard and connects it, after a [`respawn_delay`] so a shard
+    /// that keeps exiting doesn't spin.
+    pub async fn respawn(&mut self, id: ShardId) {
+        let Some(options) = self.workers.get(&id).map(|w| w.options.clone()) else {
+            return;
+        };
+
+        let now = Instant::now();
+        let (attempts, started_at) = self.respawns.entry(id).or_insert((0, now));
+
+        if now.saturating_duration_since(*started_at) > RESPAWN_MAX_DELAY {
+            *attempts = 0;
+        }
+
+        let delay = respawn_delay(*attempts);
+
+        *attempts += 1;
+        *started_at = now + delay;
+
+        sleep(delay).await;
+
+        let worker = WebSocketWorker::new(id, options).await;
+
+        worker.connect().await;
+
+        self.workers.insert(id, worker);
+    }
+
+    /// Closes the shards cleanly one after another, in shard id order.
+    pub async fn close_cleanly(&self) {
+        let mut ids: Vec<_> = self.workers.keys().copied().collect();
+        ids.sort_unstable();
+
+        for id in ids {
+            self.workers[&id].close_cleanly().await;
+        }
+    }
+
     #[inline]
     pub async fn destroy(&self, info: &Option<CloseFrame<'static>>) {
         join_all(self.workers.values().map(|w| w.destroy(info.clone()))).await;
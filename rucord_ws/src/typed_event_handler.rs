@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use rucord_api_types::{
+    ApplicationCommandPermissionsUpdateObject, ChannelObject, ChannelPinsUpdateObject,
+    DispatchPayload, GuildAuditLogEntryCreateObject, GuildBanAddObject, GuildBanRemoveObject,
+    GuildEmojisUpdateObject, GuildIntegrationsUpdateObject, GuildMemberAddObject,
+    GuildMemberRemoveObject, GuildMemberUpdateObject, GuildMembersChunkObject, GuildObject,
+    GuildRoleCreateObject, GuildRoleDeleteObject, GuildRoleUpdateObject,
+    GuildScheduledEventUserAddObject, GuildScheduledEventUserRemoveObject,
+    GuildStickersUpdateObject, GuildUpdateObject, InteractionObject, MessageDeleteBulkObject,
+    MessageDeleteObject, MessageReactionRemoveAllObject, MessageReactionRemoveEmojiObject,
+    MessageUpdateObject, PartialChannelObject, PresenceObject, ReadyData, ThreadListSyncObject,
+    ThreadMemberUpdateObject, ThreadMembersUpdateObject, ThreadObject, UserUpdateObject,
+    VoiceServerUpdateObject, WebhooksUpdateObject,
+};
+use serde_json::{Map, Value};
+
+use crate::{ShardId, WebSocketEventHandler};
+
+type JsonMap = Map<String, Value>;
+
+macro_rules! typed_event_handler {
+    ($($Name:ident => $method:ident($ty:ty)),+ $(,)?) => {
+        /// An event handler with one method per dispatch event, implementing it implements
+        /// [`WebSocketEventHandler::dispatch`] by calling the method of each event.
+        ///
+        /// The other [`WebSocketEventHandler`] methods keep their defaults, implement
+        /// [`WebSocketEventHandler`] directly to override them.
+        #[async_trait]
+        pub trait TypedEventHandler: Send + Sync {
+            async fn resumed(&self, _shard_id: ShardId) {}
+            $(async fn $method(&self, _shard_id: ShardId, _data: &$ty) {})+
+            /// An event this version doesn't know about.
+            async fn unknown(&self, _shard_id: ShardId, _name: &str, _data: &JsonMap) {}
+        }
+
+        #[async_trait]
+        impl<T: TypedEventHandler> WebSocketEventHandler for T {
+            async fn dispatch(&self, shard_id: ShardId, payload: &DispatchPayload) {
+                match payload {
+                    DispatchPayload::Resume => TypedEventHandler::resumed(self, shard_id).await,
+                    $(DispatchPayload::$Name(data) => {
+                        TypedEventHandler::$method(self, shard_id, data).await
+                    })+
+                    DispatchPayload::Unknown(name, data) => {
+                        TypedEventHandler::unknown(self, shard_id, name, data).await
+                    }
+                }
+            }
+        }
+    };
+}
+
+typed_event_handler! {
+    Ready => ready(ReadyData),
+    ApplicationCommandPermissionsUpdate => application_command_permissions_update(ApplicationCommandPermissionsUpdateObject),
+    AutoModerationRuleCreate => auto_moderation_rule_create(JsonMap),
+    AutoModerationRuleUpdate => auto_moderation_rule_update(JsonMap),
+    AutoModerationRuleDelete => auto_moderation_rule_delete(JsonMap),
+    AutoModerationActionExecution => auto_moderation_action_execution(JsonMap),
+    ChannelCreate => channel_create(ChannelObject),
+    ChannelUpdate => channel_update(ChannelObject),
+    ChannelDelete => channel_delete(PartialChannelObject),
+    ChannelPinsUpdate => channel_pins_update(ChannelPinsUpdateObject),
+    ThreadCreate => thread_create(ThreadObject),
+    ThreadUpdate => thread_update(ThreadObject),
+    ThreadDelete => thread_delete(PartialChannelObject),
+    ThreadListSync => thread_list_sync(ThreadListSyncObject),
+    ThreadMemberUpdate => thread_member_update(ThreadMemberUpdateObject),
+    ThreadMembersUpdate => thread_members_update(ThreadMembersUpdateObject),
+    GuildCreate => guild_create(GuildObject),
+    GuildUpdate => guild_update(GuildUpdateObject),
+    GuildDelete => guild_delete(JsonMap),
+    GuildAuditLogEntryCreate => guild_audit_log_entry_create(GuildAuditLogEntryCreateObject),
+    GuildBanAdd => guild_ban_add(GuildBanAddObject),
+    GuildBanRemove => guild_ban_remove(GuildBanRemoveObject),
+    GuildEmojisUpdate => guild_emojis_update(GuildEmojisUpdateObject),
+    GuildStickersUpdate => guild_stickers_update(GuildStickersUpdateObject),
+    GuildIntegrationsUpdate => guild_integrations_update(GuildIntegrationsUpdateObject),
+    GuildMemberAdd => guild_member_add(GuildMemberAddObject),
+    GuildMemberRemove => guild_member_remove(GuildMemberRemoveObject),
+    GuildMemberUpdate => guild_member_update(GuildMemberUpdateObject),
+    GuildMembersChunk => guild_members_chunk(GuildMembersChunkObject),
+    GuildRoleCreate => guild_role_create(GuildRoleCreateObject),
+    GuildRoleUpdate => guild_role_update(GuildRoleUpdateObject),
+    GuildRoleDelete => guild_role_delete(GuildRoleDeleteObject),
+    GuildScheduledEventCreate => guild_scheduled_event_create(JsonMap),
+    GuildScheduledEventUpdate => guild_scheduled_event_update(JsonMap),
+    GuildScheduledEventDelete => guild_scheduled_event_delete(JsonMap),
+    GuildScheduledEventUserAdd => guild_scheduled_event_user_add(GuildScheduledEventUserAddObject),
+    GuildScheduledEventUserRemove => guild_scheduled_event_user_remove(GuildScheduledEventUserRemoveObject),
+    InteractionCreate => interaction_create(InteractionObject),
+    IntegrationCreate => integration_create(JsonMap),
+    IntegrationUpdate => integration_update(JsonMap),
+    IntegrationDelete => integration_delete(JsonMap),
+    InviteCreate => invite_create(JsonMap),
+    InviteDelete => invite_delete(JsonMap),
+    MessageCreate => message_create(JsonMap),
+    MessageUpdate => message_update(MessageUpdateObject),
+    MessageDelete => message_delete(MessageDeleteObject),
+    MessageDeleteBulk => message_delete_bulk(MessageDeleteBulkObject),
+    MessageReactionAdd => message_reaction_add(JsonMap),
+    MessageReactionRemove => message_reaction_remove(JsonMap),
+    MessageReactionRemoveAll => message_reaction_remove_all(MessageReactionRemoveAllObject),
+    MessageReactionRemoveEmoji => message_reaction_remove_emoji(MessageReactionRemoveEmojiObject),
+    PresenceUpdate => presence_update(PresenceObject),
+    StageInstanceCreate => stage_instance_create(JsonMap),
+    StageInstanceUpdate => stage_instance_update(JsonMap),
+    StageInstanceDelete => stage_instance_delete(JsonMap),
+    TypingStart => typing_start(JsonMap),
+    UserUpdate => user_update(UserUpdateObject),
+    VoiceStateUpdate => voice_state_update(JsonMap),
+    VoiceServerUpdate => voice_server_update(VoiceServerUpdateObject),
+    WebhooksUpdate => webhooks_update(WebhooksUpdateObject),
+}
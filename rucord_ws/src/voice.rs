@@ -0,0 +1,30 @@
+use rucord_api_types::{Snowflake, VoiceServerUpdateObject};
+
+/// Everything needed to open a voice connection, see [`WebSocketShard::wait_for_voice_params`].
+///
+/// [`WebSocketShard::wait_for_voice_params`]: crate::WebSocketShard::wait_for_voice_params
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceConnectionParams {
+    pub guild_id: Snowflake,
+
+    /// From the bot's Voice State Update.
+    pub session_id: String,
+
+    /// From the Voice Server Update.
+    pub token: String,
+
+    /// From the Voice Server Update, `None` while the voice server is being reallocated.
+    pub endpoint: Option<String>,
+}
+
+impl VoiceConnectionParams {
+    #[inline]
+    pub fn new(session_id: String, server: VoiceServerUpdateObject) -> Self {
+        Self {
+            guild_id: server.guild_id,
+            session_id,
+            token: server.token,
+            endpoint: server.endpoint,
+        }
+    }
+}
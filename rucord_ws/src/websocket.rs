@@ -1,26 +1,60 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use async_tungstenite::{
-    tokio::{connect_async, ConnectStream},
-    tungstenite::Message,
+    tokio::{client_async_tls, connect_async, ConnectStream},
+    tungstenite::{client::IntoClientRequest, Message},
     WebSocketStream,
 };
 use futures::{SinkExt, StreamExt};
-use rucord_api_types::{DispatchPayload, GatewayReceivePayload, GatewaySendPayload, ReadyData};
-use serde_json::to_string;
+use rucord_api_types::{
+    ApplicationCommandPermissionsUpdateObject, ChannelObject, ChannelPinsUpdateObject,
+    DispatchPayload, GatewayReceivePayload, GatewaySendPayload, GuildAuditLogEntryCreateObject,
+    GuildBanAddObject, GuildBanRemoveObject, GuildEmojisUpdateObject, GuildMemberUpdateObject,
+    GuildMembersChunkObject, GuildObject, GuildRoleCreateObject, GuildRoleDeleteObject,
+    GuildRoleUpdateObject, GuildScheduledEventUserAddObject, GuildScheduledEventUserRemoveObject,
+    GuildStickersUpdateObject, MessageDeleteBulkObject, MessageDeleteObject,
+    MessageReactionRemoveAllObject, MessageReactionRemoveEmojiObject, ReadyData, Snowflake,
+    ThreadMemberUpdateObject, ThreadMembersUpdateObject, UserObject, VoiceServerUpdateObject,
+    DISCORD_API_VERSION,
+};
+use rucord_rest::TlsBackend;
+use serde_json::{to_string, Map, Value};
 use tokio::time::timeout;
 
-use crate::{Result, ShardError, ShardId};
+use crate::{DestroyReason, Proxy, ReconnectReason, Result, ShardError, ShardId, WebSocketError};
 
 pub type WebSocket = WebSocketStream<ConnectStream>;
 
 #[async_trait]
 pub trait WebSocketExt {
     async fn create<T: AsRef<str> + Send + Sync>(url: T) -> Result<WebSocket> {
-        let (ws, _) = connect_async(url.as_ref())
+        let (ws, _) = connect_async(gateway_url(url.as_ref())?)
+            .await
+            .map_err(ShardError::from)?;
+
+        Ok(ws)
+    }
+    async fn create_with_proxy<T: AsRef<str> + Send + Sync>(
+        url: T,
+        proxy: Option<&Proxy>,
+    ) -> Result<WebSocket> {
+        let Some(proxy) = proxy else {
+            return Self::create(url).await;
+        };
+
+        let request = gateway_url(url.as_ref())?
+            .into_client_request()
+            .map_err(ShardError::from)?;
+
+        let host = request.uri().host().unwrap_or_default().to_owned();
+        let port = request.uri().port_u16().unwrap_or(443);
+
+        let stream = proxy.tunnel(&host, port).await?;
+
+        let (ws, _) = client_async_tls(request, stream)
             .await
-            .map_err(ShardError::Tungstenite)?;
+            .map_err(ShardError::from)?;
 
         Ok(ws)
     }
@@ -34,8 +68,10 @@ impl WebSocketExt for WebSocket {
         const TIME: Duration = Duration::from_millis(500);
 
         match timeout(TIME, self.next()).await {
-            Ok(Some(Ok(v))) => Ok(get_text(v)?.map(GatewayReceivePayload::unpack)),
-            Ok(Some(Err(e))) => Err(ShardError::Tungstenite(e))?,
+            Ok(Some(Ok(v))) => Ok(get_text(v)?
+                .map(GatewayReceivePayload::unpack)
+                .transpose()?),
+            Ok(Some(Err(e))) => Err(ShardError::from(e))?,
             Ok(None) | Err(_) => Ok(None),
         }
     }
@@ -43,11 +79,73 @@ impl WebSocketExt for WebSocket {
     async fn send_op(&mut self, op: GatewaySendPayload) -> Result<()> {
         self.send(Message::Text(to_string(&op)?))
             .await
-            .map_err(ShardError::Tungstenite)?;
+            .map_err(ShardError::from)?;
         Ok(())
     }
 }
 
+/// Checks that the gateway connections can use the backend, `async-tungstenite` picks a single
+/// TLS implementation at compile time and prefers `native-tls` when both features are enabled.
+pub fn check_tls_backend(backend: TlsBackend) -> Result<()> {
+    match backend {
+        #[cfg(feature = "native-tls")]
+        TlsBackend::Native => Ok(()),
+        #[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+        TlsBackend::Rustls => Ok(()),
+        #[cfg(all(feature = "rustls", feature = "native-tls"))]
+        TlsBackend::Rustls => Err(WebSocketError::TlsBackendUnavailable(backend)),
+    }
+}
+
+/// Adds the `v` and `encoding` query params to the gateway URL when they're missing.
+///
+/// Returns [`WebSocketError::ApiVersionMismatch`] if the URL asks for another API version.
+pub fn gateway_url(url: &str) -> Result<String> {
+    let (base, query) = url.split_once('?').unwrap_or((url, ""));
+
+    let default_version = DISCORD_API_VERSION.to_string();
+
+    let mut params: Vec<_> = query
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .map(|p| p.split_once('=').unwrap_or((p, "")))
+        .collect();
+
+    match params.iter().find(|(k, _)| *k == "v") {
+        Some((_, v)) => {
+            let version = v.parse().unwrap_or(0);
+
+            if version != DISCORD_API_VERSION {
+                Err(WebSocketError::ApiVersionMismatch(
+                    version,
+                    DISCORD_API_VERSION,
+                ))?;
+            }
+        }
+        None => params.push(("v", &default_version)),
+    }
+
+    if !params.iter().any(|(k, _)| *k == "encoding") {
+        params.push(("encoding", "json"));
+    }
+
+    let has_path = base
+        .split_once("://")
+        .map_or(base, |(_, rest)| rest)
+        .contains('/');
+
+    let query = params
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    Ok(format!(
+        "{base}{slash}?{query}",
+        slash = if has_path { "" } else { "/" }
+    ))
+}
+
 fn get_text(msg: Message) -> Result<Option<String>> {
     match msg {
         Message::Text(txt) => Ok(Some(txt)),
@@ -66,6 +164,116 @@ pub trait WebSocketEventHandler: Send + Sync {
     async fn debug(&self, _shard_id: ShardId, _message: String) {}
     async fn shard_error(&self, _shard_id: ShardId, _error: &ShardError) {}
     async fn dispatch(&self, _shard_id: ShardId, _data: &DispatchPayload) {}
+    async fn channel_create(&self, _shard_id: ShardId, _channel: &ChannelObject) {}
+    /// `data.last_pin_timestamp` is `None` when the last pin was removed.
+    async fn channel_pins_update(&self, _shard_id: ShardId, _data: &ChannelPinsUpdateObject) {}
+    async fn message_delete(&self, _shard_id: ShardId, _data: &MessageDeleteObject) {}
+    async fn message_delete_bulk(&self, _shard_id: ShardId, _data: &MessageDeleteBulkObject) {}
+    /// Every reaction of the message was removed.
+    async fn message_reaction_remove_all(
+        &self,
+        _shard_id: ShardId,
+        _data: &MessageReactionRemoveAllObject,
+    ) {
+    }
+    /// Only the reactions of `data.emoji` were removed from the message.
+    async fn message_reaction_remove_emoji(
+        &self,
+        _shard_id: ShardId,
+        _data: &MessageReactionRemoveEmojiObject,
+    ) {
+    }
+    async fn application_command_permissions_update(
+        &self,
+        _shard_id: ShardId,
+        _data: &ApplicationCommandPermissionsUpdateObject,
+    ) {
+    }
+    async fn guild_audit_log_entry_create(
+        &self,
+        _shard_id: ShardId,
+        _data: &GuildAuditLogEntryCreateObject,
+    ) {
+    }
+    async fn guild_member_update(&self, _shard_id: ShardId, _data: &GuildMemberUpdateObject) {}
+    async fn guild_members_chunk(&self, _shard_id: ShardId, _data: &GuildMembersChunkObject) {}
+    async fn thread_member_update(&self, _shard_id: ShardId, _data: &ThreadMemberUpdateObject) {}
+    async fn thread_members_update(&self, _shard_id: ShardId, _data: &ThreadMembersUpdateObject) {}
+    async fn guild_ban_add(&self, _shard_id: ShardId, _data: &GuildBanAddObject) {}
+    async fn guild_ban_remove(&self, _shard_id: ShardId, _data: &GuildBanRemoveObject) {}
+    async fn guild_role_create(&self, _shard_id: ShardId, _data: &GuildRoleCreateObject) {}
+    async fn guild_role_update(&self, _shard_id: ShardId, _data: &GuildRoleUpdateObject) {}
+    async fn guild_role_delete(&self, _shard_id: ShardId, _data: &GuildRoleDeleteObject) {}
+    async fn guild_scheduled_event_user_add(
+        &self,
+        _shard_id: ShardId,
+        _data: &GuildScheduledEventUserAddObject,
+    ) {
+    }
+    async fn guild_scheduled_event_user_remove(
+        &self,
+        _shard_id: ShardId,
+        _data: &GuildScheduledEventUserRemoveObject,
+    ) {
+    }
+    /// `data.emojis` is the complete new list, not a diff.
+    async fn guild_emojis_update(&self, _shard_id: ShardId, _data: &GuildEmojisUpdateObject) {}
+    /// `data.stickers` is the complete new list, not a diff, compare it with the cached
+    /// stickers to find the added and removed ones.
+    async fn guild_stickers_update(&self, _shard_id: ShardId, _data: &GuildStickersUpdateObject) {}
+    /// The event doesn't include the integrations, re-fetch them with
+    /// `RequestManager::get_guild_integrations` to see what changed.
+    async fn guild_integrations_update(&self, shard_id: ShardId, guild_id: &Snowflake) {
+        self.debug(
+            shard_id,
+            format!("The integrations of the guild {guild_id} were updated"),
+        )
+        .await;
+    }
+    /// `old` is the cached guild before the update, `None` without a cache.
+    async fn guild_update(
+        &self,
+        _shard_id: ShardId,
+        _old: Option<GuildObject>,
+        _new: &GuildObject,
+    ) {
+    }
+    async fn user_update(&self, _shard_id: ShardId, _user: &UserObject) {}
+    async fn voice_server_update(&self, _shard_id: ShardId, _data: &VoiceServerUpdateObject) {}
     async fn ready(&self, _shard_id: ShardId, _data: &ReadyData) {}
+    /// Called when the remaining session starts reach the low-water mark, identifies pause
+    /// once none are left until the limit resets.
+    async fn session_start_limit_warning(&self, _remaining: u64, _total: u64) {}
     async fn resumed(&self, _shard_id: ShardId) {}
+    async fn shard_reconnecting(&self, _shard_id: ShardId, _reason: ReconnectReason) {}
+    async fn shard_reconnected(&self, _shard_id: ShardId, _resumed: bool) {}
+    /// Called once the connection is closed, before reconnecting for the `Resume` and
+    /// `Reconnect` reasons.
+    async fn shard_destroyed(&self, _shard_id: ShardId, _reason: DestroyReason) {}
+    /// Called before spawning the shards of the new count, the old shards keep receiving events
+    /// until every new shard is ready.
+    async fn reshard_started(&self, _old_total: u64, _new_total: u64) {}
+    /// Called once the old shards are destroyed.
+    async fn reshard_completed(&self, _old_total: u64, _new_total: u64) {}
+    /// Called before reconnecting a shard that stopped sending heartbeats for `elapsed`.
+    async fn watchdog_triggered(&self, _shard_id: ShardId, _elapsed: Duration) {}
+    /// Called for the dispatch events this version doesn't know about, reported to
+    /// [`debug`](Self::debug) by default.
+    async fn unknown_dispatch_event(
+        &self,
+        shard_id: ShardId,
+        event_name: &str,
+        _data: &Map<String, Value>,
+    ) {
+        self.debug(
+            shard_id,
+            format!("Received an unknown dispatch event: {event_name}"),
+        )
+        .await;
+    }
+    async fn unknown_op(&self, _shard_id: ShardId, _op: u64, _data: &Map<String, Value>) {}
 }
+
+with_event_handler_methods!(forward_event_handler!(
+    impl[T: WebSocketEventHandler + ?Sized] Arc<T> => |this| (**this);
+));
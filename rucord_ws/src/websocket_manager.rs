@@ -1,21 +1,31 @@
 use std::{
-    sync::Arc,
+    collections::HashMap,
+    future::Future,
+    mem::{replace, take},
+    sync::{Arc, RwLock},
     time::{Duration, Instant},
 };
 
 use async_tungstenite::tungstenite::protocol::CloseFrame;
-use futures::future::join_all;
-use rucord_api_types::{GatewayBotObject, GatewayIntentBits, SessionStartLimitObject};
-use rucord_rest::RequestManager;
-use tokio::sync::Mutex;
+use futures::future::{join_all, pending, select_all};
+use rucord_api_types::{
+    GatewayBotObject, GatewayIntentBits, SessionStartLimitObject, Snowflake, UpdatePresenceData,
+    UserObject, VoiceStateUpdateData,
+};
+use rucord_rest::{RequestError, RequestManager, TlsBackend};
+use serde::{Deserialize, Serialize};
+use tokio::{sync::Mutex, time::timeout};
 
 use crate::{
-    IdentifyQueue, Result, ShardBucket, WebSocketError, WebSocketEventHandler,
+    bucket_shard_ids, Cache, CommandRegistry, ComponentRouter, IdentifyQueue, MetricsSink,
+    MulticastEventHandler, NoopMetricsSink, Proxy, Result, SessionStore, ShardBucket,
+    ShardDebugInfo, ShardError, VoiceConnectionParams, WebSocketError, WebSocketEventHandler,
     WebSocketWorkerOptions,
 };
 
 pub type ShardId = usize;
 
+#[derive(Clone)]
 pub struct Session {
     pub id: String,
 
@@ -25,7 +35,31 @@ pub struct Session {
 
     pub shard_count: u64,
 
-    pub sequence: u64,
+    pub sequence: i64,
+}
+
+impl Session {
+    /// Keeps the highest sequence received, events may arrive out of order.
+    #[inline]
+    pub fn update_sequence(&mut self, sequence: i64) {
+        if sequence > self.sequence {
+            self.sequence = sequence;
+        }
+    }
+}
+
+/// A serializable [`Session`], can be persisted between deployments to resume instead of identifying.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSnapshot {
+    pub id: String,
+
+    pub resume_url: String,
+
+    pub sequence: i64,
+
+    pub shard_id: ShardId,
+
+    pub shard_count: u64,
 }
 
 pub struct WebSocketManagerOptions {
@@ -34,6 +68,92 @@ pub struct WebSocketManagerOptions {
     pub intents: GatewayIntentBits,
 
     pub rest: Arc<Mutex<RequestManager>>,
+
+    /// How long the fetched gateway information is considered fresh.
+    pub gateway_cache_ttl: Duration,
+
+    /// How long the new shards of a reshard have to be ready, the reshard is rolled back after it.
+    pub reshard_timeout: Duration,
+
+    /// Called when a shard task panics, before the shard is respawned.
+    pub on_shard_panic: Option<Box<dyn Fn(ShardId) + Send + Sync>>,
+
+    /// Receives the component interactions before they are dispatched to the event handler.
+    pub component_router: Option<Arc<ComponentRouter>>,
+
+    /// Receives the application command interactions before they are dispatched to the event
+    /// handler.
+    pub command_registry: Option<Arc<CommandRegistry>>,
+
+    /// Receives the shards metrics, discarded by default.
+    pub metrics_sink: Arc<dyn MetricsSink>,
+
+    /// Fixed jitter applied to the first heartbeat, clamped to `[0.0, 1.0]`. Random if `None`.
+    pub jitter: Option<f64>,
+
+    /// The `http://`, `https://` or `socks5://` proxy the gateway and REST traffic go through.
+    /// `socks5://` requires the `socks5` feature, and the gateway doesn't support `https://`.
+    pub proxy_url: Option<String>,
+
+    /// The fraction of the total session starts below which the shards warn and refresh the
+    /// session start limit, defaults to `0.1`.
+    pub session_low_water_mark: f64,
+
+    /// The TLS backend of the gateway connections, the rest manager is configured separately.
+    pub tls_backend: TlsBackend,
+
+    /// Stores the guilds received by the shards, nothing is cached by default.
+    pub cache: Option<Arc<dyn Cache>>,
+
+    /// The presence the shards identify with, instead of `online` without activities.
+    pub initial_presence: Option<UpdatePresenceData>,
+
+    /// Persists the sessions of the destroyed shards, which resume them on their next start.
+    pub session_store: Option<Arc<dyn SessionStore>>,
+
+    /// Requests the offline members of the large guilds when they are created, which requires
+    /// the `GuildMembers` intent. The members arrive in `GUILD_MEMBERS_CHUNK` events.
+    pub fetch_all_members: bool,
+
+    /// How many large guilds each shard requests the members of, all of them if `None`. The
+    /// gateway rate limits the requests of each connection, so this keeps the shards from
+    /// exhausting it on startup.
+    pub max_large_guilds_to_prefetch: Option<usize>,
+}
+
+impl WebSocketManagerOptions {
+    pub fn new(
+        token: String,
+        intents: GatewayIntentBits,
+        rest: Arc<Mutex<RequestManager>>,
+    ) -> Self {
+        Self {
+            token,
+            intents,
+            rest,
+            gateway_cache_ttl: Duration::from_secs(60),
+            reshard_timeout: Duration::from_secs(600),
+            on_shard_panic: None,
+            component_router: None,
+            command_registry: None,
+            metrics_sink: Arc::new(NoopMetricsSink),
+            jitter: None,
+            proxy_url: None,
+            session_low_water_mark: 0.1,
+            tls_backend: Default::default(),
+            cache: None,
+            initial_presence: None,
+            session_store: None,
+            fetch_all_members: false,
+            max_large_guilds_to_prefetch: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_initial_presence(mut self, presence: UpdatePresenceData) -> Self {
+        self.initial_presence = Some(presence);
+        self
+    }
 }
 
 #[derive(Clone)]
@@ -42,6 +162,18 @@ struct GatewayInfo {
     pub created_at: Instant,
 }
 
+/// Replaces the event handler of the shards of a [`WebSocketManager`], see
+/// [`WebSocketManager::event_handler_handle`].
+#[derive(Clone)]
+pub struct EventHandlerHandle(Arc<RwLock<Arc<dyn WebSocketEventHandler>>>);
+
+impl EventHandlerHandle {
+    /// The shards use the new handler from their next event.
+    pub fn replace<T: WebSocketEventHandler + 'static>(&self, handler: T) {
+        *self.0.write().unwrap() = Arc::new(handler);
+    }
+}
+
 pub struct WebSocketManager {
     options: WebSocketManagerOptions,
 
@@ -50,6 +182,13 @@ pub struct WebSocketManager {
     shard_ids: Option<Vec<ShardId>>,
 
     buckets: Vec<ShardBucket>,
+
+    worker_options: Option<Arc<WebSocketWorkerOptions>>,
+
+    snapshots: HashMap<ShardId, SessionSnapshot>,
+
+    /// Shared with the worker options, so replacing it reaches the running shards.
+    event_handler: EventHandlerHandle,
 }
 
 impl WebSocketManager {
@@ -59,35 +198,88 @@ impl WebSocketManager {
             gateway_info: None,
             shard_ids: None,
             buckets: vec![],
+            worker_options: None,
+            snapshots: HashMap::new(),
+            event_handler: EventHandlerHandle(Arc::new(RwLock::new(Arc::new(
+                MulticastEventHandler::default(),
+            )))),
         }
     }
 }
 
 impl WebSocketManager {
+    /// The id of the bot's application, fetched once and cached by the rest manager.
+    pub async fn application_id(&self) -> core::result::Result<Snowflake, RequestError> {
+        let application = self
+            .options
+            .rest
+            .lock()
+            .await
+            .get_current_application_info()
+            .await?;
+
+        Ok(application.id)
+    }
+
     pub async fn fetch_gateway_info(&mut self) -> Result<Arc<Mutex<GatewayBotObject>>> {
-        match self.gateway_info {
-            Some(GatewayInfo {
-                ref info,
-                created_at,
-            }) if (created_at.elapsed().as_millis() as u64)
-                < info.lock().await.session_start_limit.reset_after =>
-            {
+        if let Some(GatewayInfo {
+            ref info,
+            created_at,
+        }) = self.gateway_info
+        {
+            let reset_after =
+                Duration::from_millis(info.lock().await.session_start_limit.reset_after);
+
+            if created_at.elapsed() < reset_after.min(self.options.gateway_cache_ttl) {
                 return Ok(info.clone());
             }
-            _ => (),
         }
 
         let info = self.options.rest.lock().await.get_gateway_bot().await?;
 
-        if let Some(ref mut gateway_info) = self.gateway_info {
-            *gateway_info.info.lock().await = info;
-        } else {
-            self.gateway_info = Some(info.into());
-        }
+        self.set_gateway_info(info).await?;
 
         Ok(self.gateway_info.as_ref().unwrap().info.clone())
     }
 
+    /// Replaces the cached gateway information, which is considered fresh from now on. If the
+    /// gateway url changed, every connected shard is destroyed and spawned again on the new url.
+    ///
+    /// Once the shard ids are set, by starting, adding a shard or resharding, the recommended
+    /// shard count of `info` is ignored and the shards keep identifying with the current one.
+    pub async fn set_gateway_info(&mut self, info: GatewayBotObject) -> Result<()> {
+        let url_changed = match self.gateway_info {
+            Some(ref mut gateway_info) => {
+                let mut cached = gateway_info.info.lock().await;
+                let url_changed = cached.url != info.url;
+
+                if self.shard_ids.is_some() {
+                    cached.url = info.url;
+                    cached.session_start_limit = info.session_start_limit;
+                } else {
+                    *cached = info;
+                }
+                gateway_info.created_at = Instant::now();
+
+                url_changed
+            }
+            None => {
+                self.gateway_info = Some(info.into());
+                false
+            }
+        };
+
+        if let Some(ref worker_options) = self.worker_options {
+            worker_options.identify_queue.reset().await;
+        }
+
+        if url_changed {
+            self.reconnect().await?;
+        }
+
+        Ok(())
+    }
+
     pub async fn shard_ids(&mut self) -> Result<&Vec<usize>> {
         if let Some(ref shard_ids) = self.shard_ids {
             return Ok(shard_ids);
@@ -102,10 +294,32 @@ impl WebSocketManager {
         Ok(self.shard_ids.as_ref().unwrap())
     }
 
+    /// Spawns the shards and respawns the ones that exit unexpectedly, never returns unless
+    /// spawning fails. Use [`start`](Self::start) and [`run`](Self::run) to keep calling the
+    /// manager while the shards run.
     pub async fn connect<T: WebSocketEventHandler + 'static>(
         &mut self,
-        event_handler: T,
+        event_handler: Arc<T>,
     ) -> Result<()> {
+        self.start(event_handler).await?;
+        self.run().await;
+
+        Ok(())
+    }
+
+    /// Spawns and connects the shards, and returns once they are all identifying. They all share
+    /// `event_handler`, so keep a clone of the `Arc` to reach its state afterwards.
+    ///
+    /// Nothing respawns the shards that exit unexpectedly until [`run`](Self::run) is called.
+    pub async fn start<T: WebSocketEventHandler + 'static>(
+        &mut self,
+        event_handler: Arc<T>,
+    ) -> Result<()> {
+        if let Some(ref proxy_url) = self.options.proxy_url {
+            let proxy = Proxy::parse(proxy_url)?;
+            self.options.rest.lock().await.set_proxy(proxy.as_str())?;
+        }
+
         let GatewayBotObject {
             shards,
             session_start_limit: SessionStartLimitObject { remaining, .. },
@@ -118,54 +332,487 @@ impl WebSocketManager {
             ))?;
         };
 
+        *self.event_handler.0.write().unwrap() = event_handler;
+
         self.shard_ids().await?;
-        self.spawn(event_handler).await?;
+        self.spawn().await?;
 
-        for bucket in self.buckets.iter() {
-            bucket.connect().await;
-        }
+        let mut snapshots = take(&mut self.snapshots);
+
+        join_all(self.buckets.iter().map(|bucket| {
+            let snapshots = bucket
+                .workers
+                .keys()
+                .filter_map(|id| snapshots.remove_entry(id))
+                .collect();
+
+            bucket.connect_with_snapshots(snapshots)
+        }))
+        .await;
 
+        Ok(())
+    }
+
+    /// Respawns the shards that exit unexpectedly, forever.
+    ///
+    /// The manager stays borrowed meanwhile, to keep calling it race
+    /// [`wait_for_unexpected_exit`](Self::wait_for_unexpected_exit) against the other calls
+    /// instead, and [`respawn`](Self::respawn) the exited shards:
+    ///
+    /// ```ignore
+    /// loop {
+    ///     tokio::select! {
+    ///         (id, panicked) = manager.wait_for_unexpected_exit() => {
+    ///             manager.respawn(id, panicked).await;
+    ///         }
+    ///         Some(total) = reshard_requests.recv() => manager.reshard(total).await?,
+    ///     }
+    /// }
+    /// ```
+    pub async fn run(&mut self) {
         loop {
-            tokio::time::sleep(Duration::from_secs(10)).await;
+            let (id, panicked) = self.wait_for_unexpected_exit().await;
+
+            self.respawn(id, panicked).await;
         }
     }
 
+    /// Waits for a shard task to exit without being destroyed, because of a panic or an
+    /// unhandled error. Returns the shard id and whether it panicked, never returns while no
+    /// shard is spawned.
+    ///
+    /// Cancelling the wait doesn't miss the exit, the next wait returns it.
+    pub async fn wait_for_unexpected_exit(&self) -> (ShardId, bool) {
+        if self.buckets.is_empty() {
+            return pending().await;
+        }
+
+        let (exit, ..) = select_all(
+            self.buckets
+                .iter()
+                .map(|b| Box::pin(b.wait_for_unexpected_exit())),
+        )
+        .await;
+
+        exit
+    }
+
+    /// Spawns the exited shard again, after calling `on_shard_panic` if it panicked. Does nothing
+    /// if the shard was removed meanwhile.
+    pub async fn respawn(&mut self, shard_id: ShardId, panicked: bool) {
+        let Some(bucket) = self
+            .buckets
+            .iter_mut()
+            .find(|b| b.workers.contains_key(&shard_id))
+        else {
+            return;
+        };
+
+        if panicked {
+            if let Some(ref on_shard_panic) = self.options.on_shard_panic {
+                on_shard_panic(shard_id);
+            }
+        }
+
+        bucket.respawn(shard_id).await;
+    }
+
     pub async fn destroy(&self, info: Option<CloseFrame<'static>>) {
         join_all(self.buckets.iter().map(|b| b.destroy(&info))).await;
     }
 
-    async fn spawn<T: WebSocketEventHandler + 'static>(&mut self, event_handler: T) -> Result<()> {
-        let event_handler = Arc::new(event_handler);
+    /// Spawns and connects the shard at runtime, `total_shards` becomes the shard count sent when
+    /// identifying every shard. If the manager isn't started yet the shard is spawned on start,
+    /// along with the rest of `0..total_shards` unless the shard ids were set before.
+    pub async fn add_shard(&mut self, shard_id: ShardId, total_shards: u64) -> Result<()> {
+        let gateway_info = self.fetch_gateway_info().await?;
+
+        let max_concurrency = {
+            let mut gateway_info = gateway_info.lock().await;
+            gateway_info.shards = total_shards;
+            gateway_info.session_start_limit.max_concurrency
+        };
+
+        // Without a shard list, starting would have spawned every shard of the new count.
+        let shard_ids = self
+            .shard_ids
+            .get_or_insert_with(|| (0..total_shards as usize).collect());
+
+        if !shard_ids.contains(&shard_id) {
+            shard_ids.push(shard_id);
+        }
+
+        let Some(ref worker_options) = self.worker_options else {
+            return Ok(());
+        };
+
+        let key = shard_id % max_concurrency.max(1) as usize;
+
+        let index = match self
+            .buckets
+            .iter()
+            .position(|b| b.rate_limit_key(max_concurrency) == Some(key))
+        {
+            Some(index) => index,
+            None => {
+                self.buckets
+                    .push(ShardBucket::new(&[], worker_options.clone()).await);
+                self.buckets.len() - 1
+            }
+        };
+
+        self.buckets[index]
+            .add_shard(shard_id, worker_options.clone())
+            .await;
+
+        Ok(())
+    }
+
+    /// Closes the shard cleanly and removes it, so it isn't spawned again.
+    pub async fn remove_shard(&mut self, shard_id: ShardId) -> Result<()> {
+        if let Some(ref mut shard_ids) = self.shard_ids {
+            shard_ids.retain(|id| *id != shard_id);
+        }
+
+        for bucket in self.buckets.iter_mut() {
+            if bucket.remove_shard(shard_id).await {
+                break;
+            }
+        }
+
+        self.buckets.retain(|b| !b.workers.is_empty());
+
+        Ok(())
+    }
+
+    /// Closes every shard with a normal closure one after another, waiting for the gateway to
+    /// confirm each close.
+    pub async fn shutdown_gracefully(&self) {
+        for bucket in self.buckets.iter() {
+            bucket.close_cleanly().await;
+        }
+    }
+
+    /// Resumes the session of the snapshot, if the shard isn't spawned yet the session is
+    /// resumed on start.
+    pub async fn resume_from_snapshot(&mut self, snapshot: SessionSnapshot) -> Result<()> {
+        let worker = self
+            .buckets
+            .iter()
+            .find_map(|b| b.workers.get(&snapshot.shard_id));
+
+        match worker {
+            Some(worker) => worker.resume(snapshot).await,
+            None => {
+                self.snapshots.insert(snapshot.shard_id, snapshot);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The user object of the bot, `None` until a shard is ready.
+    pub fn self_user(&self) -> Option<UserObject> {
+        self.buckets
+            .iter()
+            .flat_map(|bucket| bucket.workers.values())
+            .find_map(|worker| worker.self_user())
+    }
+
+    /// The connection details of the shard, `None` if it isn't spawned or its task exited.
+    pub async fn debug_info(&self, shard_id: ShardId) -> Option<ShardDebugInfo> {
+        let worker = self
+            .buckets
+            .iter()
+            .find_map(|bucket| bucket.workers.get(&shard_id))?;
+
+        worker.debug_info().await
+    }
+
+    /// Joins or moves the bot to the voice channel through the shard of the guild, use
+    /// [`wait_for_voice_params`](Self::wait_for_voice_params) beforehand to connect to it.
+    pub async fn join_voice_channel(
+        &self,
+        shard_id: ShardId,
+        guild_id: &Snowflake,
+        channel_id: &Snowflake,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Result<()> {
+        self.update_voice_state(
+            shard_id,
+            VoiceStateUpdateData {
+                guild_id: *guild_id,
+                channel_id: Some(*channel_id),
+                self_mute,
+                self_deaf,
+            },
+        )
+        .await
+    }
+
+    /// Disconnects the bot from the voice channel it is in within the guild.
+    pub async fn leave_voice_channel(&self, shard_id: ShardId, guild_id: &Snowflake) -> Result<()> {
+        self.update_voice_state(
+            shard_id,
+            VoiceStateUpdateData {
+                guild_id: *guild_id,
+                channel_id: None,
+                self_mute: false,
+                self_deaf: false,
+            },
+        )
+        .await
+    }
+
+    /// Registers a wait for the voice connection details of the guild on its shard, call it
+    /// before [`join_voice_channel`](Self::join_voice_channel) and await the returned future
+    /// afterwards. The future resolves to `None` if the shard exits, or a later wait for the same
+    /// guild replaces this one, first.
+    pub async fn wait_for_voice_params(
+        &self,
+        shard_id: ShardId,
+        guild_id: &Snowflake,
+    ) -> Result<impl Future<Output = Option<VoiceConnectionParams>>> {
+        let Some(params) = self
+            .buckets
+            .iter()
+            .find_map(|bucket| bucket.workers.get(&shard_id))
+            .map(|worker| worker.wait_for_voice_params(*guild_id))
+        else {
+            Err(ShardError::NotConnected)?
+        };
+
+        match params.await {
+            Some(params) => Ok(params),
+            None => Err(ShardError::NotConnected)?,
+        }
+    }
+
+    async fn update_voice_state(
+        &self,
+        shard_id: ShardId,
+        data: VoiceStateUpdateData,
+    ) -> Result<()> {
+        let Some(worker) = self
+            .buckets
+            .iter()
+            .find_map(|bucket| bucket.workers.get(&shard_id))
+        else {
+            Err(ShardError::NotConnected)?
+        };
+
+        worker.update_voice_state(data).await
+    }
+
+    pub async fn save_sessions(&self) -> Vec<SessionSnapshot> {
+        join_all(self.buckets.iter().map(|b| b.save_sessions()))
+            .await
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Swaps the event handler of every shard without reconnecting, the shards use the new
+    /// handler from their next event. Before `start` the handler is stored, until `start` replaces
+    /// it with the one it takes.
+    pub fn replace_event_handler<T: WebSocketEventHandler + 'static>(&self, handler: T) {
+        self.event_handler.replace(handler);
+    }
+
+    /// A handle replacing the event handler like
+    /// [`replace_event_handler`](Self::replace_event_handler), usable while [`run`](Self::run)
+    /// borrows the manager.
+    #[inline]
+    pub fn event_handler_handle(&self) -> EventHandlerHandle {
+        self.event_handler.clone()
+    }
+
+    /// Spawns the shards of the new shard count, and destroys the current shards once every new
+    /// shard is ready, so no event is missed in between. Both shard sets receive events during
+    /// the migration, see [`WebSocketEventHandler::reshard_started`].
+    ///
+    /// If the new shards aren't all ready within `reshard_timeout`, or one of them exits first,
+    /// they are closed and the current shards keep running, which fails with
+    /// [`WebSocketError::ReshardTimedOut`] or [`WebSocketError::ReshardShardExited`].
+    ///
+    /// Before `start`, only the shard count used when starting changes.
+    pub async fn reshard(&mut self, new_total: u64) -> Result<()> {
+        let gateway_info = self.fetch_gateway_info().await?;
+
+        let (remaining, max_concurrency) = {
+            let limit = &gateway_info.lock().await.session_start_limit;
+            (limit.remaining, limit.max_concurrency)
+        };
+
+        if new_total > remaining {
+            Err(WebSocketError::NotEnoughSessionsRemaining(
+                remaining, new_total,
+            ))?;
+        }
+
+        let shard_ids: Vec<_> = (0..new_total as usize).collect();
+
+        let Some(old_options) = self.worker_options.clone() else {
+            gateway_info.lock().await.shards = new_total;
+            self.shard_ids = Some(shard_ids);
+            return Ok(());
+        };
+
+        // The count the running shards identified with, the fetched one may be a recommendation.
+        let old_total = old_options.gateway_info.lock().await.shards;
+
+        let event_handler = old_options.event_handler.read().unwrap().clone();
+        event_handler.reshard_started(old_total, new_total).await;
+
+        // The new shards identify with the new count, while the old ones keep running.
+        let mut new_info = gateway_info.lock().await.clone();
+        new_info.shards = new_total;
+        let new_info = Arc::new(Mutex::new(new_info));
+
+        let options = self.worker_options(new_info.clone()).await?;
+
+        let buckets = join_all(
+            bucket_shard_ids(&shard_ids, max_concurrency)
+                .iter()
+                .map(|ids| ShardBucket::new(ids, options.clone())),
+        )
+        .await;
+
+        let ready = timeout(self.options.reshard_timeout, async {
+            join_all(buckets.iter().map(|bucket| bucket.connect())).await;
+            join_all(buckets.iter().map(|bucket| bucket.wait_until_ready())).await
+        })
+        .await;
+
+        let error = match ready {
+            Ok(ready) if ready.iter().all(|ready| *ready) => None,
+            Ok(_) => Some(WebSocketError::ReshardShardExited),
+            Err(_) => Some(WebSocketError::ReshardTimedOut(
+                self.options.reshard_timeout,
+            )),
+        };
+
+        if let Some(error) = error {
+            // Closing cleanly discards the sessions, so they aren't stored over the current ones.
+            for bucket in buckets.iter() {
+                bucket.close_cleanly().await;
+                bucket.destroy(&None).await;
+            }
+
+            Err(error)?;
+        }
+
+        let old_buckets = replace(&mut self.buckets, buckets);
+
+        for bucket in old_buckets {
+            bucket.close_cleanly().await;
+            bucket.destroy(&None).await;
+        }
+
+        if let Some(ref mut info) = self.gateway_info {
+            info.info = new_info;
+        }
+        self.worker_options = Some(options);
+        self.shard_ids = Some(shard_ids);
+
+        let event_handler = old_options.event_handler.read().unwrap().clone();
+        event_handler.reshard_completed(old_total, new_total).await;
+
+        Ok(())
+    }
+
+    /// Destroys every shard and spawns them again with the current event handler, does nothing
+    /// before `start`.
+    async fn reconnect(&mut self) -> Result<()> {
+        if self.worker_options.is_none() {
+            return Ok(());
+        }
 
-        let WebSocketManagerOptions { token, intents, .. } = &self.options;
+        self.destroy(None).await;
+        self.spawn().await?;
 
+        join_all(self.buckets.iter().map(|bucket| bucket.connect())).await;
+
+        Ok(())
+    }
+
+    async fn spawn(&mut self) -> Result<()> {
         let gateway_info = self.gateway_info.as_ref().unwrap().info.clone();
 
-        let bucket_size = gateway_info
+        let max_concurrency = gateway_info
             .lock()
             .await
             .session_start_limit
             .max_concurrency;
 
-        let options = Arc::new(WebSocketWorkerOptions {
-            identify_queue: IdentifyQueue::new(gateway_info.clone()),
-            gateway_info,
-            event_handler,
-            token: token.clone(),
-            identify_properties: Default::default(),
-            intents: *intents,
-        });
+        let options = self.worker_options(gateway_info).await?;
+
+        self.worker_options = Some(options.clone());
+
         self.buckets = join_all(
-            self.shard_ids
-                .as_ref()
-                .unwrap()
-                .chunks(bucket_size as usize)
+            bucket_shard_ids(self.shard_ids.as_ref().unwrap(), max_concurrency)
+                .iter()
                 .map(|ids| ShardBucket::new(ids, options.clone())),
         )
         .await;
 
         Ok(())
     }
+
+    async fn worker_options(
+        &self,
+        gateway_info: Arc<Mutex<GatewayBotObject>>,
+    ) -> Result<Arc<WebSocketWorkerOptions>> {
+        let WebSocketManagerOptions {
+            token,
+            intents,
+            rest,
+            component_router,
+            command_registry,
+            metrics_sink,
+            jitter,
+            proxy_url,
+            session_low_water_mark,
+            tls_backend,
+            cache,
+            initial_presence,
+            session_store,
+            fetch_all_members,
+            max_large_guilds_to_prefetch,
+            ..
+        } = &self.options;
+
+        // The shards spawned again share the identify rate limit with the current ones, which may
+        // still be identifying.
+        let identify_queue = match self.worker_options {
+            Some(ref options) => options
+                .identify_queue
+                .share_rate_limit(gateway_info.clone()),
+            None => IdentifyQueue::new(gateway_info.clone()),
+        };
+
+        Ok(Arc::new(WebSocketWorkerOptions {
+            identify_queue: identify_queue.with_session_low_water_mark(*session_low_water_mark),
+            gateway_info,
+            event_handler: self.event_handler.0.clone(),
+            token: token.clone(),
+            identify_properties: Default::default(),
+            intents: *intents,
+            rest: rest.clone(),
+            component_router: component_router.clone(),
+            command_registry: command_registry.clone(),
+            metrics_sink: metrics_sink.clone(),
+            jitter: *jitter,
+            proxy: proxy_url.as_deref().map(Proxy::parse).transpose()?,
+            tls_backend: *tls_backend,
+            cache: cache.clone(),
+            initial_presence: initial_presence.clone(),
+            session_store: session_store.clone(),
+            fetch_all_members: *fetch_all_members,
+            max_large_guilds_to_prefetch: *max_large_guilds_to_prefetch,
+        }))
+    }
 }
 
 impl From<GatewayBotObject> for GatewayInfo {
@@ -177,3 +824,29 @@ impl From<GatewayBotObject> for GatewayInfo {
         }
     }
 }
+
+impl From<Session> for SessionSnapshot {
+    #[inline]
+    fn from(session: Session) -> Self {
+        Self {
+            id: session.id,
+            resume_url: session.resume_url,
+            sequence: session.sequence,
+            shard_id: session.shard_id,
+            shard_count: session.shard_count,
+        }
+    }
+}
+
+impl From<SessionSnapshot> for Session {
+    #[inline]
+    fn from(snapshot: SessionSnapshot) -> Self {
+        Self {
+            id: snapshot.id,
+            shard_id: snapshot.shard_id,
+            resume_url: snapshot.resume_url,
+            shard_count: snapshot.shard_count,
+            sequence: snapshot.sequence,
+        }
+    }
+}
@@ -1,21 +1,39 @@
 use std::{
-    sync::Arc,
-    time::{Duration, Instant},
+    collections::{HashMap, VecDeque},
+    fmt,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::Duration,
 };
 
 use crate::{
-    Result, Session, ShardError, ShardId, WebSocket, WebSocketError, WebSocketEventHandler,
-    WebSocketExt, WebSocketWorkerOptions, WorkerMessage,
+    check_tls_backend, RequestGuildMembersQueue, Result, Session, SessionSnapshot, ShardError,
+    ShardId, VoiceConnectionParams, WebSocket, WebSocketError, WebSocketEventHandler, WebSocketExt,
+    WebSocketWorkerOptions, WorkerMessage,
 };
 use async_recursion::async_recursion;
-use async_tungstenite::tungstenite::protocol::CloseFrame;
-use kanal::{AsyncReceiver, AsyncSender};
-use rand::Rng;
+use async_tungstenite::tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+use futures::StreamExt;
+use kanal::AsyncReceiver;
+use num_traits::FromPrimitive;
+use rand::{rngs::SmallRng, Rng, SeedableRng};
 use rucord_api_types::{
-    DispatchPayload, GatewayReceivePayload, GatewaySendPayload, IdentifyData, ResumeData,
+    DispatchPayload, GatewayCloseCode, GatewayIntentBits, GatewayReceivePayload,
+    GatewaySendPayload, GuildObject, IdentifyData, InteractionType, RequestGuildMembersData,
+    ResumeData, Snowflake, UserObject, VoiceServerUpdateObject, VoiceStateUpdateData,
+};
+use serde_json::Value;
+use tokio::{
+    spawn,
+    sync::oneshot,
+    task::JoinHandle,
+    time::{interval, timeout, Instant},
 };
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WebSocketShardStatus {
     Ready,
     Resuming,
@@ -23,15 +41,137 @@ pub enum WebSocketShardStatus {
     Idle,
 }
 
-pub enum ShardMessage {
-    Connected,
-    Destroyed,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconnectReason {
+    /// The gateway didn't acknowledge the last heartbeat.
+    ZombieConnection,
+    /// The gateway asked for a reconnect.
+    GatewayReconnectOpcode,
+    /// The gateway invalidated the session.
+    InvalidSession { resumable: bool },
+    /// The gateway closed the connection with a reconnectable close code.
+    CloseCode(GatewayCloseCode),
+    /// The connection was lost.
+    NetworkError,
+    /// No heartbeat was sent for too long, see [`WebSocketShard::watchdog_expired`].
+    Watchdog,
+}
+
+impl ReconnectReason {
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ZombieConnection => "zombie_connection",
+            Self::GatewayReconnectOpcode => "gateway_reconnect_opcode",
+            Self::InvalidSession { .. } => "invalid_session",
+            Self::CloseCode(_) => "close_code",
+            Self::NetworkError => "network_error",
+            Self::Watchdog => "watchdog",
+        }
+    }
+
+    /// Whether the session can be resumed after reconnecting.
+    pub fn can_resume(&self) -> bool {
+        match self {
+            Self::InvalidSession { resumable } => *resumable,
+            Self::CloseCode(code) => !matches!(
+                code,
+                GatewayCloseCode::InvalidSeq | GatewayCloseCode::SessionTimedOut
+            ),
+            _ => true,
+        }
+    }
+}
+
+/// Why a shard is destroyed, decides whether its session is kept and whether it reconnects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DestroyReason {
+    /// Asked by the worker, the session is stored and the shard doesn't reconnect.
+    Intentional,
+    /// Reconnects with a new session.
+    Reconnect,
+    /// Reconnects and resumes the session.
+    Resume,
+    /// The gateway closed the connection with a close code that can't be recovered from, the
+    /// session is cleared and the shard doesn't reconnect.
+    FatalCloseCode(GatewayCloseCode),
+}
+
+impl DestroyReason {
+    #[inline]
+    pub fn keeps_session(&self) -> bool {
+        matches!(self, Self::Intentional | Self::Resume)
+    }
+
+    #[inline]
+    pub fn reconnects(&self) -> bool {
+        matches!(self, Self::Reconnect | Self::Resume)
+    }
+}
+
+impl From<ReconnectReason> for DestroyReason {
+    #[inline]
+    fn from(reason: ReconnectReason) -> Self {
+        if reason.can_resume() {
+            Self::Resume
+        } else {
+            Self::Reconnect
+        }
+    }
+}
+
+/// How late a heartbeat can be, on top of the watchdog period, before the watchdog fires.
+const WATCHDOG_GRACE: Duration = Duration::from_millis(5000);
+
+/// The connection details of a shard, to include in bug reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShardDebugInfo {
+    pub shard_id: ShardId,
+    pub status: WebSocketShardStatus,
+    pub has_connection: bool,
+    pub has_session: bool,
+    pub sequence: Option<i64>,
+    pub heartbeat_interval_ms: i64,
+    pub next_heartbeat_in: Duration,
+    pub is_ack: bool,
+    pub started_at_elapsed: Duration,
+    pub last_heartbeat_elapsed: Duration,
+    /// Reconnects since the shard was last ready.
+    pub reconnect_attempts: u32,
+}
+
+impl fmt::Display for ShardDebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Shard {}:", self.shard_id)?;
+        writeln!(f, "  status: {:?}", self.status)?;
+        writeln!(f, "  connection: {}", self.has_connection)?;
+        writeln!(f, "  session: {}", self.has_session)?;
+        match self.sequence {
+            Some(sequence) => writeln!(f, "  sequence: {sequence}")?,
+            None => writeln!(f, "  sequence: none")?,
+        }
+        writeln!(f, "  heartbeat interval: {} ms", self.heartbeat_interval_ms)?;
+        writeln!(f, "  next heartbeat in: {:?}", self.next_heartbeat_in)?;
+        writeln!(f, "  last heartbeat acknowledged: {}", self.is_ack)?;
+        writeln!(f, "  started: {:?} ago", self.started_at_elapsed)?;
+        writeln!(f, "  last heartbeat: {:?} ago", self.last_heartbeat_elapsed)?;
+        write!(f, "  reconnect attempts: {}", self.reconnect_attempts)
+    }
 }
 
 pub enum ShardSendMessage {
     Debug(ShardId, String),
     Error(ShardError),
 }
+/// The voice connection details of a guild received so far, sent once both arrived.
+struct VoiceParamsWaiter {
+    session_id: Option<String>,
+
+    server: Option<VoiceServerUpdateObject>,
+
+    sender: oneshot::Sender<VoiceConnectionParams>,
+}
+
 pub struct WebSocketShard {
     id: ShardId,
 
@@ -45,19 +185,49 @@ pub struct WebSocketShard {
 
     receiver: AsyncReceiver<WorkerMessage>,
 
-    sender: AsyncSender<ShardMessage>,
+    pending: VecDeque<WorkerMessage>,
 
     connection: Option<WebSocket>,
 
     started_at: Instant,
 
-    last_heartbeat: Instant,
+    /// When the last heartbeat was sent, shared with the watchdog task.
+    last_heartbeat: Arc<Mutex<Instant>>,
+
+    /// Set by the watchdog task once the heartbeats stopped, the event loop reconnects when it
+    /// sees it.
+    watchdog_fired: Arc<AtomicBool>,
+
+    /// The watchdog task of the current connection.
+    watchdog: Option<JoinHandle<()>>,
 
     heartbeat_interval: i64,
 
     next_heartbeat: Duration,
 
     is_ack: bool,
+
+    reconnecting: bool,
+
+    rng: SmallRng,
+
+    self_user: Option<UserObject>,
+
+    /// A copy of `self_user` shared with the worker, which can't borrow the shard.
+    shared_self_user: Arc<RwLock<Option<UserObject>>>,
+
+    reconnect_attempts: u32,
+
+    voice_waiters: HashMap<Snowflake, VoiceParamsWaiter>,
+
+    /// Whether the session store was already checked, only the first connection resumes from it.
+    session_loaded: bool,
+
+    /// How many large guilds the members were requested for, kept across reconnections.
+    prefetched_large_guilds: usize,
+
+    /// The `REQUEST_GUILD_MEMBERS` payloads waiting for the rate limit, sent from the event loop.
+    request_guild_members_queue: RequestGuildMembersQueue,
 }
 
 impl WebSocketShard {
@@ -65,32 +235,167 @@ impl WebSocketShard {
         id: ShardId,
         options: Arc<WebSocketWorkerOptions>,
         receiver: AsyncReceiver<WorkerMessage>,
-        sender: AsyncSender<ShardMessage>,
     ) -> Self {
+        let event_handler = options.event_handler.read().unwrap().clone();
+
         Self {
             id,
-            event_handler: options.event_handler.clone(),
+            event_handler,
             options,
             receiver,
-            sender,
+            pending: VecDeque::new(),
             status: WebSocketShardStatus::Idle,
             connection: None,
             started_at: Instant::now(),
-            last_heartbeat: Instant::now(),
+            last_heartbeat: Arc::new(Mutex::new(Instant::now())),
+            watchdog_fired: Default::default(),
+            watchdog: None,
             heartbeat_interval: -1,
             next_heartbeat: Duration::default(),
             session: None,
             is_ack: true,
+            reconnecting: false,
+            rng: SmallRng::from_entropy(),
+            self_user: None,
+            shared_self_user: Default::default(),
+            reconnect_attempts: 0,
+            voice_waiters: HashMap::new(),
+            session_loaded: false,
+            prefetched_large_guilds: 0,
+            request_guild_members_queue: RequestGuildMembersQueue::new(),
         }
     }
 }
 
+/// Closing the channel drops the queued messages, so their reply senders are dropped and the
+/// worker stops waiting once the shard exited.
+impl Drop for WebSocketShard {
+    fn drop(&mut self) {
+        let _ = self.receiver.close();
+        self.stop_watchdog();
+    }
+}
+
 impl WebSocketShard {
     #[inline]
     pub fn status(&self) -> WebSocketShardStatus {
         self.status
     }
 
+    /// The user object of the bot, set on `READY` and updated on `USER_UPDATE`.
+    #[inline]
+    pub fn self_user(&self) -> Option<&UserObject> {
+        self.self_user.as_ref()
+    }
+
+    /// The user object of the bot, shared with the threads that don't own the shard.
+    #[inline]
+    pub fn shared_self_user(&self) -> Arc<RwLock<Option<UserObject>>> {
+        self.shared_self_user.clone()
+    }
+
+    fn set_self_user(&mut self, user: &UserObject) {
+        *self.shared_self_user.write().unwrap() = Some(user.clone());
+        self.self_user = Some(user.clone());
+    }
+
+    /// Waits for the bot's Voice State Update and the Voice Server Update of `guild_id`,
+    /// call it before sending the Voice State Update that joins the channel.
+    ///
+    /// The returned future resolves to `None` if the shard is dropped, or a later call for the
+    /// same guild replaces this one, before both events arrive.
+    pub fn wait_for_voice_params(
+        &mut self,
+        guild_id: &Snowflake,
+    ) -> impl Future<Output = Option<VoiceConnectionParams>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.add_voice_waiter(*guild_id, sender);
+
+        async move { receiver.await.ok() }
+    }
+
+    fn add_voice_waiter(
+        &mut self,
+        guild_id: Snowflake,
+        sender: oneshot::Sender<VoiceConnectionParams>,
+    ) {
+        self.voice_waiters.insert(
+            guild_id,
+            VoiceParamsWaiter {
+                session_id: None,
+                server: None,
+                sender,
+            },
+        );
+    }
+
+    /// Completes the wait of the guild once both events arrived.
+    fn resolve_voice_waiter(&mut self, guild_id: &Snowflake) {
+        let Some(waiter) = self.voice_waiters.remove(guild_id) else {
+            return;
+        };
+
+        match waiter {
+            VoiceParamsWaiter {
+                session_id: Some(session_id),
+                server: Some(server),
+                sender,
+            } => {
+                let _ = sender.send(VoiceConnectionParams::new(session_id, server));
+            }
+            waiter => {
+                self.voice_waiters.insert(*guild_id, waiter);
+            }
+        }
+    }
+
+    fn resolve_voice_state(&mut self, data: &serde_json::Map<String, Value>) {
+        let field = |name: &str| data.get(name).and_then(Value::as_str);
+
+        let Some(guild_id) = field("guild_id").and_then(|id| Snowflake::parse(id).ok()) else {
+            return;
+        };
+
+        // Voice states of other users in the guild don't concern the bot's connection.
+        if let (Some(user), Some(user_id)) = (&self.self_user, field("user_id")) {
+            if Snowflake::parse(user_id).ok() != Some(user.id()) {
+                return;
+            }
+        }
+
+        if let (Some(session_id), Some(waiter)) =
+            (field("session_id"), self.voice_waiters.get_mut(&guild_id))
+        {
+            waiter.session_id = Some(session_id.to_owned());
+            self.resolve_voice_waiter(&guild_id);
+        }
+    }
+
+    pub fn debug_info(&self) -> ShardDebugInfo {
+        ShardDebugInfo {
+            shard_id: self.id,
+            status: self.status,
+            has_connection: self.connection.is_some(),
+            has_session: self.session.is_some(),
+            sequence: self.session.as_ref().map(|session| session.sequence),
+            heartbeat_interval_ms: self.heartbeat_interval,
+            next_heartbeat_in: self
+                .next_heartbeat
+                .saturating_sub(self.last_heartbeat_elapsed()),
+            is_ack: self.is_ack,
+            started_at_elapsed: self.started_at.elapsed(),
+            last_heartbeat_elapsed: self.last_heartbeat_elapsed(),
+            reconnect_attempts: self.reconnect_attempts,
+        }
+    }
+
+    /// How long to wait before sending the next heartbeat.
+    #[inline]
+    pub fn next_heartbeat(&self) -> Duration {
+        self.next_heartbeat
+    }
+
     #[inline]
     pub async fn debug(&self, msg: &[&str]) {
         self.event_handler
@@ -122,7 +427,22 @@ impl WebSocketShard {
 
         self.status = WebSocketShardStatus::Connecting;
 
-        let connection = WebSocket::create(&self.options.gateway_info.lock().await.url).await?;
+        self.load_session().await;
+
+        // Waiting for the session start limit to reset can take hours, the gateway would close a
+        // connection opened before that without heartbeats.
+        if self.session.is_none() {
+            self.options.identify_queue.wait_for_session_start().await;
+        }
+
+        let url = match self.session {
+            Some(ref session) => session.resume_url.clone(),
+            None => self.options.gateway_info.lock().await.url.clone(),
+        };
+
+        check_tls_backend(self.options.tls_backend)?;
+
+        let connection = WebSocket::create_with_proxy(url, self.options.proxy.as_ref()).await?;
 
         self.debug(&[&format!(
             "WebSocket connection established after {:?}",
@@ -134,7 +454,11 @@ impl WebSocketShard {
 
         loop {
             if let Some(GatewayReceivePayload::Hello(_)) = self.wait_event().await? {
-                self.identify().await?;
+                if self.session.is_some() {
+                    self.resume().await?;
+                } else {
+                    self.identify().await?;
+                }
                 break;
             }
         }
@@ -145,8 +469,12 @@ impl WebSocketShard {
     pub async fn destroy(
         &mut self,
         info: Option<CloseFrame<'static>>,
-        recover: Option<bool>,
+        reason: DestroyReason,
     ) -> Result<()> {
+        if let DestroyReason::FatalCloseCode(_) = reason {
+            self.session = None;
+        }
+
         if self.status == WebSocketShardStatus::Idle {
             self.debug(&["Tried to destroy an idle shard"]).await;
             return Ok(());
@@ -164,67 +492,161 @@ impl WebSocketShard {
                 info.as_ref()
                     .map_or_else(|| "none".to_owned(), |i| i.code.to_string())
             ),
-            &format!(
-                "Recover: {}",
-                recover.map_or_else(
-                    || "none",
-                    |resume| if resume { "resume" } else { "reconnect" }
-                )
-            ),
+            &format!("Destroy reason: {reason:?}"),
         ])
         .await;
 
-        let Some(ref mut connection) = self.connection else { return Ok(()); };
+        if reason == DestroyReason::Intentional {
+            self.store_session().await;
+        }
+
+        let Some(ref mut connection) = self.connection else {
+            return Ok(());
+        };
 
-        connection
-            .close(info)
-            .await
-            .map_err(ShardError::Tungstenite)?;
+        if let Err(err) = connection.close(info).await {
+            self.debug(&[&format!("Failed to close the connection: {err}")])
+                .await;
+        }
 
-        if matches!(recover, Some(resume) if !resume) && self.session.is_some() {
-            self.session = None;
+        self.clear_connection(reason.keeps_session());
+
+        self.event_handler.shard_destroyed(self.id, reason).await;
+
+        if reason.reconnects() {
+            self.drain_worker_queue();
+            self.connect().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Closes the connection with a normal closure and waits for the gateway to confirm it,
+    /// the shard stays idle until the next `Connect`. The session can't be resumed afterwards.
+    pub async fn close_cleanly(&mut self) -> Result<()> {
+        const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(5);
+
+        let Some(mut connection) = self.connection.take() else {
+            self.debug(&["Tried to close an idle shard"]).await;
+            return Ok(());
+        };
+
+        self.debug(&["Closing the connection cleanly"]).await;
+
+        let frame = CloseFrame {
+            code: CloseCode::Normal,
+            reason: "Normal Closure".into(),
         };
 
+        if let Err(err) = connection.close(Some(frame)).await {
+            self.debug(&[&format!("Failed to close the connection: {err}")])
+                .await;
+            self.clear_connection(false);
+            return Ok(());
+        }
+
+        let confirmed = timeout(CONFIRMATION_TIMEOUT, async {
+            while let Some(Ok(msg)) = connection.next().await {
+                if msg.is_close() {
+                    break;
+                }
+            }
+        })
+        .await
+        .is_ok();
+
+        if !confirmed {
+            self.debug(&["The gateway didn't confirm the close in time"])
+                .await;
+        }
+
+        self.clear_connection(false);
+
+        Ok(())
+    }
+
+    fn clear_connection(&mut self, keep_session: bool) {
+        if !keep_session {
+            self.session = None;
+        }
+
         self.is_ack = true;
 
         self.heartbeat_interval = -1;
 
+        self.stop_watchdog();
+
         self.connection = None;
 
         self.status = WebSocketShardStatus::Idle;
+    }
 
-        if recover.is_some() {
-            self.connect().await?;
+    async fn connect_and_reply(&mut self, reply: oneshot::Sender<()>) -> Result<()> {
+        if let Err(err) = self.connect().await {
+            self.error(&err).await;
+            return Err(err);
         }
 
+        let _ = reply.send(());
+
         Ok(())
     }
 
     pub async fn event_loop(&mut self) -> Result<()> {
         loop {
+            if self.status != WebSocketShardStatus::Idle {
+                self.drain_worker_queue();
+            }
+
             match self.wait_worker_event().await {
                 Ok(e) => match e {
-                    WorkerMessage::Connect => {
-                        let Err(err) = self.connect().await else {
+                    // The replies are dropped when the caller stopped waiting for them.
+                    WorkerMessage::Connect(reply) => {
+                        self.connect_and_reply(reply).await?;
+                        continue;
+                    }
 
-                        if self.sender.send(ShardMessage::Connected).await.is_err() {
-                            return Ok(());
-                        };
+                    WorkerMessage::Resume(snapshot, reply) => {
+                        self.restore_session(snapshot);
+                        self.connect_and_reply(reply).await?;
                         continue;
-                     };
-                        self.resolve_ws_error(&err).await?;
-                        return Err(err);
                     }
 
-                    WorkerMessage::Destroy(info) => {
-                        self.destroy(info, None).await?;
+                    WorkerMessage::Destroy(info, reply) => {
+                        self.destroy(info, DestroyReason::Intentional).await?;
 
-                        if self.sender.send(ShardMessage::Destroyed).await.is_err() {
-                            return Ok(());
-                        };
+                        let _ = reply.send(());
 
                         return Ok(());
                     }
+
+                    WorkerMessage::CloseCleanly(reply) => {
+                        self.close_cleanly().await?;
+
+                        let _ = reply.send(());
+                    }
+
+                    WorkerMessage::Ping(reply) => {
+                        let _ = reply.send(());
+                    }
+
+                    WorkerMessage::DebugInfo(reply) => {
+                        let _ = reply.send(self.debug_info());
+                    }
+
+                    WorkerMessage::UpdateVoiceState(data, reply) => {
+                        let _ = reply.send(self.update_voice_state(data).await);
+                    }
+
+                    WorkerMessage::WaitForVoiceParams(guild_id, sender, reply) => {
+                        self.add_voice_waiter(guild_id, sender);
+
+                        let _ = reply.send(());
+                    }
+
+                    WorkerMessage::SaveSession(reply) => {
+                        let _ = reply.send(self.save_session());
+                    }
                 },
                 Err(e) if e => return Ok(()),
                 _ => (),
@@ -232,18 +654,100 @@ impl WebSocketShard {
 
             if self.connection.is_some() && self.heartbeat_interval != -1 {
                 if let Err(e) = self.heartbeat(false).await {
-                    self.resolve_ws_error(&e).await?;
-                    return Err(e);
+                    if !self.resolve_ws_error(&e).await? {
+                        return Err(e);
+                    }
                 };
             }
 
+            if let Err(e) = self.send_guild_members_requests().await {
+                if !self.resolve_ws_error(&e).await? {
+                    return Err(e);
+                }
+            }
+
             self.wait_event().await?;
+
+            if self.watchdog_fired.swap(false, Ordering::AcqRel) {
+                self.debug(&[&format!(
+                    "No heartbeat was sent for {:?}, reconnecting.",
+                    self.last_heartbeat_elapsed()
+                )])
+                .await;
+
+                self.reconnect(ReconnectReason::Watchdog).await?;
+            }
         }
     }
 
+    /// 1.5 times the heartbeat interval, `None` until the gateway said hello.
+    fn watchdog_period(&self) -> Option<Duration> {
+        (self.heartbeat_interval != -1)
+            .then(|| Duration::from_millis(self.heartbeat_interval as u64 * 3 / 2))
+    }
+
+    /// Checks every watchdog period that the heartbeats are still sent, from its own task so an
+    /// event that takes long to resolve neither delays nor gets cancelled by the check.
+    fn start_watchdog(&mut self) {
+        self.stop_watchdog();
+
+        let Some(period) = self.watchdog_period() else {
+            return;
+        };
+
+        let shard_id = self.id;
+        let last_heartbeat = self.last_heartbeat.clone();
+        let fired = self.watchdog_fired.clone();
+        let options = self.options.clone();
+
+        self.watchdog = Some(spawn(async move {
+            let mut timer = interval(period);
+
+            loop {
+                timer.tick().await;
+
+                let elapsed = last_heartbeat.lock().unwrap().elapsed();
+
+                if elapsed > period + WATCHDOG_GRACE && !fired.swap(true, Ordering::AcqRel) {
+                    // The handler may have been replaced since the watchdog started.
+                    let event_handler = options.event_handler.read().unwrap().clone();
+
+                    event_handler.watchdog_triggered(shard_id, elapsed).await;
+                }
+            }
+        }));
+    }
+
+    fn stop_watchdog(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            watchdog.abort();
+        }
+
+        self.watchdog_fired.store(false, Ordering::Release);
+    }
+
+    #[inline]
+    fn mark_heartbeat(&self) {
+        *self.last_heartbeat.lock().unwrap() = Instant::now();
+    }
+
+    #[inline]
+    fn last_heartbeat_elapsed(&self) -> Duration {
+        self.last_heartbeat.lock().unwrap().elapsed()
+    }
+
+    /// Whether heartbeats stopped being sent at all, unlike zombie connections where
+    /// they're sent but not acknowledged.
+    pub fn watchdog_expired(&self) -> bool {
+        self.watchdog_period()
+            .is_some_and(|period| self.last_heartbeat_elapsed() > period + WATCHDOG_GRACE)
+    }
+
     #[inline]
     pub async fn wait_event(&mut self) -> Result<Option<GatewayReceivePayload>> {
-        let Some(ref mut connection) = self.connection else { return Ok(None); };
+        let Some(ref mut connection) = self.connection else {
+            return Ok(None);
+        };
 
         match connection.recv_next().await {
             Ok(Some(e)) => {
@@ -253,23 +757,33 @@ impl WebSocketShard {
             Ok(None) => Ok(None),
 
             Err(err) => {
-                self.resolve_ws_error(&err).await?;
-                Err(err)
+                if self.resolve_ws_error(&err).await? {
+                    Ok(None)
+                } else {
+                    Err(err)
+                }
             }
         }
     }
 
     pub async fn heartbeat(&mut self, requested: bool) -> Result<()> {
-        if !requested && self.last_heartbeat.elapsed() <= self.next_heartbeat {
+        if !requested && self.last_heartbeat_elapsed() <= self.next_heartbeat {
             return Ok(());
         }
 
+        if !requested && !self.is_ack {
+            self.debug(&["The last heartbeat was not acknowledged, reconnecting."])
+                .await;
+
+            return self.reconnect(ReconnectReason::ZombieConnection).await;
+        }
+
         self.send(GatewaySendPayload::Heartbeat(
             self.session.as_ref().map(|s| s.sequence),
         ))
         .await?;
 
-        self.last_heartbeat = Instant::now();
+        self.mark_heartbeat();
 
         if self.next_heartbeat.as_millis() != self.heartbeat_interval as u128 {
             self.next_heartbeat = Duration::from_millis(self.heartbeat_interval as u64);
@@ -281,6 +795,8 @@ impl WebSocketShard {
     }
 
     pub async fn resolve_event(&mut self, event: &GatewayReceivePayload) -> Result<()> {
+        self.event_handler = self.options.event_handler.read().unwrap().clone();
+
         match event {
             GatewayReceivePayload::Hello(heartbeat_interval) => {
                 self.debug(&[&format!(
@@ -289,10 +805,16 @@ impl WebSocketShard {
                 .await;
 
                 self.heartbeat_interval = *heartbeat_interval as i64;
+                self.mark_heartbeat();
+                self.start_watchdog();
 
-                self.next_heartbeat = Duration::from_millis(
-                    (self.heartbeat_interval as f64 * rand::thread_rng().gen::<f64>()) as u64,
-                );
+                let jitter = match self.options.jitter {
+                    Some(jitter) => jitter.clamp(0.0, 1.0),
+                    None => self.rng.gen::<f64>(),
+                };
+
+                self.next_heartbeat =
+                    Duration::from_millis((self.heartbeat_interval as f64 * jitter) as u64);
             }
 
             GatewayReceivePayload::HeartbeatRequest => self.heartbeat(true).await?,
@@ -300,9 +822,15 @@ impl WebSocketShard {
             GatewayReceivePayload::HeartbeatAck => {
                 self.is_ack = true;
 
+                self.options.metrics_sink.observe_histogram(
+                    "gateway_heartbeat_latency_seconds",
+                    self.last_heartbeat_elapsed().as_secs_f64(),
+                    &[("shard", &self.id.to_string())],
+                );
+
                 self.debug(&[&format!(
                     "The latency since the last heartbeat is: {:?}",
-                    self.last_heartbeat.elapsed()
+                    self.last_heartbeat_elapsed()
                 )])
                 .await;
             }
@@ -313,18 +841,40 @@ impl WebSocketShard {
                 )])
                 .await;
 
+                let reason = ReconnectReason::InvalidSession {
+                    resumable: *can_resume,
+                };
+
                 if *can_resume && self.session.is_some() {
+                    self.reconnecting(reason).await;
                     self.resume().await?;
                 } else {
-                    self.destroy(None, Some(false)).await?;
+                    self.remove_stored_session().await;
+                    self.reconnect(reason).await?;
                 }
             }
-            GatewayReceivePayload::Reconnect => self.destroy(None, Some(true)).await?,
+            GatewayReceivePayload::Reconnect => {
+                self.reconnect(ReconnectReason::GatewayReconnectOpcode)
+                    .await?
+            }
             GatewayReceivePayload::Dispatch((s, payload)) => {
+                self.options.metrics_sink.increment_counter(
+                    "gateway_events_received_total",
+                    &[("shard", &self.id.to_string()), ("event", payload.name())],
+                );
+
                 match payload {
                     DispatchPayload::Ready(data) => {
+                        self.set_self_user(&data.user);
+                        self.status = WebSocketShardStatus::Ready;
+                        self.reconnect_attempts = 0;
                         self.event_handler.ready(self.id, data).await;
 
+                        if self.reconnecting {
+                            self.reconnecting = false;
+                            self.event_handler.shard_reconnected(self.id, false).await;
+                        }
+
                         if self.session.is_none() {
                             self.session = Some(Session {
                                 id: data.session_id.clone(),
@@ -336,26 +886,259 @@ impl WebSocketShard {
                         }
                     }
 
+                    DispatchPayload::ChannelCreate(channel) => {
+                        self.event_handler.channel_create(self.id, channel).await;
+                    }
+
+                    DispatchPayload::ChannelPinsUpdate(data) => {
+                        self.event_handler.channel_pins_update(self.id, data).await;
+                    }
+
+                    DispatchPayload::MessageDelete(data) => {
+                        self.event_handler.message_delete(self.id, data).await;
+                    }
+
+                    DispatchPayload::MessageDeleteBulk(data) => {
+                        self.event_handler.message_delete_bulk(self.id, data).await;
+                    }
+
+                    DispatchPayload::MessageReactionRemoveAll(data) => {
+                        self.event_handler
+                            .message_reaction_remove_all(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::MessageReactionRemoveEmoji(data) => {
+                        self.event_handler
+                            .message_reaction_remove_emoji(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::ApplicationCommandPermissionsUpdate(data) => {
+                        self.event_handler
+                            .application_command_permissions_update(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::ThreadMemberUpdate(data) => {
+                        self.event_handler.thread_member_update(self.id, data).await;
+                    }
+
+                    DispatchPayload::ThreadMembersUpdate(data) => {
+                        self.event_handler
+                            .thread_members_update(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::GuildCreate(guild) if !guild.is_available() => {
+                        self.debug(&[&format!("Guild {} is unavailable", guild.id)])
+                            .await;
+                    }
+
+                    DispatchPayload::GuildCreate(guild) => {
+                        if let Some(ref cache) = self.options.cache {
+                            cache.insert_guild(guild);
+                        }
+
+                        // The guild is still dispatched if the request can't be sent.
+                        if guild.is_large() && self.should_prefetch_members() {
+                            if let Err(err) = self.prefetch_members(&guild.id).await {
+                                self.debug(&[&format!(
+                                    "Failed to request the members of the guild {}: {err}",
+                                    guild.id
+                                )])
+                                .await;
+                            }
+                        }
+                    }
+
+                    DispatchPayload::GuildUpdate(data) => {
+                        let old = self
+                            .options
+                            .cache
+                            .as_ref()
+                            .and_then(|cache| cache.get_guild(&data.id));
+
+                        // Only a cached guild is updated, the update alone misses the
+                        // `GUILD_CREATE` fields.
+                        let guild = match (&old, self.options.cache.as_ref()) {
+                            (Some(old), Some(cache)) => {
+                                let mut guild = old.clone();
+                                guild.apply_update(data.clone());
+                                cache.insert_guild(&guild);
+                                guild
+                            }
+                            _ => GuildObject::from(data.clone()),
+                        };
+
+                        self.event_handler.guild_update(self.id, old, &guild).await;
+                    }
+
+                    DispatchPayload::MessageCreate(data) => {
+                        if let Some(ref cache) = self.options.cache {
+                            match serde_json::from_value(Value::Object(data.clone())) {
+                                Ok(message) => cache.insert_message(&message),
+                                Err(err) => {
+                                    self.debug(&[&format!("Couldn't cache a message: {err}")])
+                                        .await
+                                }
+                            }
+                        }
+                    }
+
+                    DispatchPayload::MessageUpdate(data) => {
+                        if let Some(ref cache) = self.options.cache {
+                            cache.update_message(data);
+                        }
+                    }
+
+                    DispatchPayload::GuildMemberAdd(data) => {
+                        if let Some(ref cache) = self.options.cache {
+                            cache.increment_member_count(&data.guild_id);
+                        }
+                    }
+
+                    DispatchPayload::GuildMemberRemove(data) => {
+                        if let Some(ref cache) = self.options.cache {
+                            cache.decrement_member_count(&data.guild_id);
+                        }
+                    }
+
+                    DispatchPayload::GuildMemberUpdate(data) => {
+                        self.event_handler.guild_member_update(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildAuditLogEntryCreate(data) => {
+                        self.event_handler
+                            .guild_audit_log_entry_create(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::GuildMembersChunk(data) => {
+                        self.event_handler.guild_members_chunk(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildBanAdd(data) => {
+                        self.event_handler.guild_ban_add(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildBanRemove(data) => {
+                        self.event_handler.guild_ban_remove(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildRoleCreate(data) => {
+                        self.event_handler.guild_role_create(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildRoleUpdate(data) => {
+                        self.event_handler.guild_role_update(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildRoleDelete(data) => {
+                        self.event_handler.guild_role_delete(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildScheduledEventUserAdd(data) => {
+                        self.event_handler
+                            .guild_scheduled_event_user_add(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::GuildScheduledEventUserRemove(data) => {
+                        self.event_handler
+                            .guild_scheduled_event_user_remove(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::GuildEmojisUpdate(data) => {
+                        self.event_handler.guild_emojis_update(self.id, data).await;
+                    }
+
+                    DispatchPayload::GuildStickersUpdate(data) => {
+                        self.event_handler
+                            .guild_stickers_update(self.id, data)
+                            .await;
+                    }
+
+                    DispatchPayload::GuildIntegrationsUpdate(data) => {
+                        self.event_handler
+                            .guild_integrations_update(self.id, &data.guild_id)
+                            .await;
+                    }
+
+                    DispatchPayload::VoiceStateUpdate(data) => {
+                        self.resolve_voice_state(data);
+                    }
+
+                    DispatchPayload::VoiceServerUpdate(data) => {
+                        if let Some(waiter) = self.voice_waiters.get_mut(&data.guild_id) {
+                            waiter.server = Some(data.clone());
+                            self.resolve_voice_waiter(&data.guild_id);
+                        }
+
+                        self.event_handler.voice_server_update(self.id, data).await;
+                    }
+
+                    DispatchPayload::UserUpdate(user) => {
+                        self.set_self_user(user);
+                        self.event_handler.user_update(self.id, user).await;
+                    }
+
+                    DispatchPayload::InteractionCreate(interaction)
+                        if interaction.ty == InteractionType::MessageComponent =>
+                    {
+                        if let Some(ref router) = self.options.component_router {
+                            router
+                                .route(self.id, interaction, self.options.rest.clone())
+                                .await;
+                        }
+                    }
+
+                    DispatchPayload::InteractionCreate(interaction)
+                        if interaction.ty == InteractionType::ApplicationCommand =>
+                    {
+                        if let Some(ref registry) = self.options.command_registry {
+                            if let Err(err) = registry
+                                .route(self.id, interaction, self.options.rest.clone())
+                                .await
+                            {
+                                self.debug(&[&format!("Failed to execute the command: {err}")])
+                                    .await;
+                            }
+                        }
+                    }
+
+                    DispatchPayload::Unknown(name, data) => {
+                        self.event_handler
+                            .unknown_dispatch_event(self.id, name, data)
+                            .await;
+                    }
+
                     DispatchPayload::Resume => {
                         self.status = WebSocketShardStatus::Ready;
+                        self.reconnect_attempts = 0;
                         self.event_handler.resumed(self.id).await;
                         self.debug(&["Resumed"]).await;
+
+                        if self.reconnecting {
+                            self.reconnecting = false;
+                            self.event_handler.shard_reconnected(self.id, true).await;
+                        }
                     }
 
                     _ => (),
                 }
 
                 if let Some(session) = &mut self.session {
-                    if *s > session.sequence {
-                        session.sequence = *s;
-                    }
+                    session.update_sequence(*s);
                 };
 
                 self.event_handler.dispatch(self.id, payload).await;
             }
-            // TODO: Impl unknown_op function.
-            GatewayReceivePayload::UnknownOp(op, _) => {
-                self.debug(&[&format!("unknown op: {op}")]).await
+            GatewayReceivePayload::UnknownOp(op, data) => {
+                self.debug(&[&format!("unknown op: {op}")]).await;
+
+                self.event_handler.unknown_op(self.id, *op, data).await;
             }
         }
         Ok(())
@@ -364,11 +1147,9 @@ impl WebSocketShard {
     pub async fn resume(&mut self) -> Result<()> {
         self.debug(&["Resuming session"]).await;
 
-        let (Some(connection), Some(Session {
-            sequence,
-            id,
-            ..
-        })) = (&mut self.connection, &self.session) else {
+        let (Some(connection), Some(Session { sequence, id, .. })) =
+            (&mut self.connection, &self.session)
+        else {
             self.debug(&["There is a resume without connection or session, Please open an issue for this problem on github."]).await;
 
             return self.connect().await;
@@ -387,22 +1168,149 @@ impl WebSocketShard {
             )
             .await?;
 
+        self.options
+            .metrics_sink
+            .increment_counter("gateway_resumes_total", &[("shard", &self.id.to_string())]);
+
         Ok(())
     }
 
-    pub async fn resolve_ws_error(&mut self, error: &WebSocketError) -> Result<()> {
+    /// Returns the current session, so it can be resumed later, even from another process.
+    pub fn save_session(&self) -> Option<SessionSnapshot> {
+        self.session.clone().map(Into::into)
+    }
+
+    /// Replaces the current session, the next `connect` resumes it instead of identifying.
+    pub fn restore_session(&mut self, snapshot: SessionSnapshot) {
+        self.session = Some(snapshot.into());
+    }
+
+    /// Restores the session saved by a previous process, on the first connection only so
+    /// an invalidated session isn't resumed again.
+    async fn load_session(&mut self) {
+        if std::mem::replace(&mut self.session_loaded, true) || self.session.is_some() {
+            return;
+        }
+
+        let Some(store) = self.options.session_store.clone() else {
+            return;
+        };
+
+        match store.load(self.id).await {
+            Ok(Some(snapshot)) => {
+                self.debug(&["Resuming the stored session"]).await;
+                self.restore_session(snapshot);
+            }
+            Ok(None) => (),
+            Err(err) => {
+                self.debug(&[&format!("Failed to load the stored session: {err}")])
+                    .await
+            }
+        }
+    }
+
+    async fn store_session(&mut self) {
+        let (Some(store), Some(snapshot)) =
+            (self.options.session_store.clone(), self.save_session())
+        else {
+            return;
+        };
+
+        if let Err(err) = store.save(&snapshot).await {
+            self.debug(&[&format!("Failed to store the session: {err}")])
+                .await;
+        }
+    }
+
+    /// Removes the stored session, so the next process doesn't try to resume it again.
+    async fn remove_stored_session(&mut self) {
+        let Some(store) = self.options.session_store.clone() else {
+            return;
+        };
+
+        if let Err(err) = store.remove(self.id).await {
+            self.debug(&[&format!("Failed to remove the stored session: {err}")])
+                .await;
+        }
+    }
+
+    /// Reports the error and reconnects if it can be recovered from, returns whether it was.
+    pub async fn resolve_ws_error(&mut self, error: &WebSocketError) -> Result<bool> {
         self.error(error).await;
 
-        if let WebSocketError::Shard(_) = error {
-            // TODO: Resolve close error.
+        // A payload that doesn't parse is reported and skipped, the connection is still fine.
+        if let WebSocketError::Json(_) = error {
+            return Ok(true);
+        }
+
+        let WebSocketError::Shard(error) = error else {
+            return Ok(false);
         };
 
-        Ok(())
+        let reason = match error {
+            ShardError::Closed(Some(frame)) => {
+                match GatewayCloseCode::from_u16(frame.code.into()) {
+                    Some(code) if code.is_reconnectable() => ReconnectReason::CloseCode(code),
+                    Some(code) => {
+                        self.destroy(None, DestroyReason::FatalCloseCode(code))
+                            .await?;
+                        return Ok(false);
+                    }
+                    None => ReconnectReason::NetworkError,
+                }
+            }
+            ShardError::Closed(None)
+            | ShardError::Tungstenite(_)
+            | ShardError::TungsteniteProtocol(_) => ReconnectReason::NetworkError,
+            _ => return Ok(false),
+        };
+
+        self.reconnect(reason).await?;
+
+        Ok(true)
+    }
+
+    async fn reconnecting(&mut self, reason: ReconnectReason) {
+        self.reconnecting = true;
+        self.reconnect_attempts += 1;
+
+        self.event_handler.shard_reconnecting(self.id, reason).await;
+    }
+
+    pub async fn reconnect(&mut self, reason: ReconnectReason) -> Result<()> {
+        self.debug(&[&format!("Reconnecting, reason: {reason:?}")])
+            .await;
+
+        self.reconnecting(reason).await;
+
+        self.options.metrics_sink.increment_counter(
+            "gateway_reconnects_total",
+            &[("shard", &self.id.to_string()), ("reason", reason.name())],
+        );
+
+        self.destroy(None, reason.into()).await
+    }
+
+    /// Answers the stale `Connect` messages queued by the worker right away, the other messages are
+    /// kept for the next `wait_worker_event` call.
+    pub fn drain_worker_queue(&mut self) {
+        while let Ok(Some(msg)) = self.receiver.try_recv() {
+            match msg {
+                WorkerMessage::Connect(reply) => {
+                    let _ = reply.send(());
+                }
+                msg => self.pending.push_back(msg),
+            }
+        }
     }
 
     pub async fn wait_worker_event(
         &mut self,
     ) -> core::result::Result<WorkerMessage, /*need_to_stop: */ bool> {
+        if let Some(msg) = self.pending.pop_front() {
+            return Ok(msg);
+        }
+
         if self.connection.is_some() {
             return match self.receiver.try_recv() {
                 Ok(Some(e)) => Ok(e),
@@ -423,6 +1331,7 @@ impl WebSocketShard {
             intents,
             gateway_info,
             identify_queue,
+            initial_presence,
             ..
         } = self.options.as_ref();
 
@@ -441,11 +1350,150 @@ impl WebSocketShard {
             properties: identify_properties.clone(),
 
             shard: Some((self.id as u64, gateway_info.lock().await.shards)),
+            presence: initial_presence.clone(),
 
             ..Default::default()
         };
 
-        self.send(data.into()).await
+        self.send(data.into()).await?;
+
+        self.options.metrics_sink.increment_counter(
+            "gateway_identifies_total",
+            &[("shard", &self.id.to_string())],
+        );
+
+        if let Some((remaining, total)) = self.options.identify_queue.session_started().await {
+            self.debug(&[&format!(
+                "Only {remaining} of {total} session starts remaining, refreshing the limit"
+            )])
+            .await;
+
+            self.event_handler
+                .session_start_limit_warning(remaining, total)
+                .await;
+
+            self.refresh_session_start_limit().await;
+        }
+
+        Ok(())
+    }
+
+    /// Re-fetches the session start limit, the other gateway information is kept.
+    async fn refresh_session_start_limit(&self) {
+        let result = self.options.rest.lock().await.get_gateway_bot().await;
+
+        match result {
+            Ok(info) => {
+                self.options.gateway_info.lock().await.session_start_limit =
+                    info.session_start_limit;
+            }
+            Err(err) => {
+                self.debug(&[&format!("Failed to refresh the session start limit: {err}")])
+                    .await;
+            }
+        }
+    }
+
+    /// Whether the members of the next large guild should be requested, see
+    /// [`WebSocketWorkerOptions::fetch_all_members`].
+    fn should_prefetch_members(&self) -> bool {
+        self.options.fetch_all_members
+            && self
+                .options
+                .max_large_guilds_to_prefetch
+                .is_none_or(|max| self.prefetched_large_guilds < max)
+    }
+
+    /// Requests all the members of a guild, the offline ones aren't sent for large guilds. The
+    /// request is queued when the rate limit is exhausted, so this never waits for it.
+    async fn prefetch_members(&mut self, guild_id: &Snowflake) -> Result<()> {
+        if !self
+            .options
+            .intents
+            .contains(GatewayIntentBits::GuildMembers)
+        {
+            self.debug(&[&format!(
+                "Not requesting the members of the guild {guild_id}, the `GuildMembers` intent is missing"
+            )])
+            .await;
+
+            return Ok(());
+        }
+
+        self.prefetched_large_guilds += 1;
+
+        self.request_guild_members_queued(RequestGuildMembersData {
+            guild_id: *guild_id,
+            query: Some(String::new()),
+            limit: 0,
+            presences: None,
+            user_ids: None,
+            nonce: None,
+        })
+        .await
+    }
+
+    /// Joins or moves the bot to the voice channel, use
+    /// [`wait_for_voice_params`](Self::wait_for_voice_params) beforehand to connect to it.
+    pub async fn join_voice_channel(
+        &mut self,
+        guild_id: &Snowflake,
+        channel_id: &Snowflake,
+        self_mute: bool,
+        self_deaf: bool,
+    ) -> Result<()> {
+        self.update_voice_state(VoiceStateUpdateData {
+            guild_id: *guild_id,
+            channel_id: Some(*channel_id),
+            self_mute,
+            self_deaf,
+        })
+        .await
+    }
+
+    /// Disconnects the bot from the voice channel it is in within the guild.
+    pub async fn leave_voice_channel(&mut self, guild_id: &Snowflake) -> Result<()> {
+        self.update_voice_state(VoiceStateUpdateData {
+            guild_id: *guild_id,
+            channel_id: None,
+            self_mute: false,
+            self_deaf: false,
+        })
+        .await
+    }
+
+    async fn update_voice_state(&mut self, data: VoiceStateUpdateData) -> Result<()> {
+        if self.connection.is_none() {
+            Err(ShardError::NotConnected)?;
+        }
+
+        self.send(GatewaySendPayload::VoiceStateUpdate(data)).await
+    }
+
+    /// Queues a `REQUEST_GUILD_MEMBERS` payload, sent right away if the rate limit of the
+    /// connection allows it, or from the event loop once it does.
+    pub async fn request_guild_members_queued(
+        &mut self,
+        data: RequestGuildMembersData,
+    ) -> Result<()> {
+        self.request_guild_members_queue.push(data);
+
+        self.send_guild_members_requests().await
+    }
+
+    /// Sends the queued `REQUEST_GUILD_MEMBERS` payloads the rate limit allows, they wait for
+    /// the next connection while the shard isn't connected.
+    async fn send_guild_members_requests(&mut self) -> Result<()> {
+        if self.connection.is_none() {
+            return Ok(());
+        }
+
+        while let Some(data) = self.request_guild_members_queue.pop_ready() {
+            self.send(GatewaySendPayload::RequestGuildMembers(data))
+                .await?;
+        }
+
+        Ok(())
     }
 
     pub async fn send(&mut self, op: GatewaySendPayload) -> Result<()> {
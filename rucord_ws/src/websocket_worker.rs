@@ -1,11 +1,33 @@
-use std::sync::Arc;
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+};
 
 use async_tungstenite::tungstenite::protocol::CloseFrame;
-use kanal::{AsyncReceiver, AsyncSender};
-use rucord_api_types::{GatewayBotObject, GatewayIntentBits, IdentifyConnectionProperties};
-use tokio::{spawn, sync::Mutex};
+use futures::{
+    future::{BoxFuture, Shared},
+    FutureExt,
+};
+use kanal::AsyncSender;
+use rucord_api_types::{
+    GatewayBotObject, GatewayIntentBits, IdentifyConnectionProperties, SessionStartLimitObject,
+    Snowflake, UpdatePresenceData, UserObject, VoiceStateUpdateData,
+};
+use rucord_rest::{RequestManager, TlsBackend};
+use tokio::{
+    spawn,
+    sync::{oneshot, Mutex},
+    task::JoinError,
+};
 
-use crate::{IdentifyQueue, ShardId, ShardMessage, WebSocketEventHandler, WebSocketShard};
+use crate::{
+    Cache, CommandRegistry, ComponentRouter, IdentifyQueue, MetricsSink, MulticastEventHandler,
+    NoopMetricsSink, Proxy, Result, SessionSnapshot, SessionStore, ShardDebugInfo, ShardError,
+    ShardId, VoiceConnectionParams, WebSocketError, WebSocketEventHandler, WebSocketShard,
+};
 
 pub struct WebSocketWorkerOptions {
     pub gateway_info: Arc<Mutex<GatewayBotObject>>,
@@ -16,72 +38,280 @@ pub struct WebSocketWorkerOptions {
 
     pub identify_queue: IdentifyQueue,
 
-    pub event_handler: Arc<dyn WebSocketEventHandler>,
+    /// Replaced by [`WebSocketManager::replace_event_handler`](crate::WebSocketManager::replace_event_handler),
+    /// the shards pick the new handler up before their next event.
+    pub event_handler: Arc<RwLock<Arc<dyn WebSocketEventHandler>>>,
 
     pub intents: GatewayIntentBits,
+
+    pub rest: Arc<Mutex<RequestManager>>,
+
+    pub component_router: Option<Arc<ComponentRouter>>,
+
+    pub command_registry: Option<Arc<CommandRegistry>>,
+
+    pub metrics_sink: Arc<dyn MetricsSink>,
+
+    /// Fixed jitter applied to the first heartbeat, clamped to `[0.0, 1.0]`. Random if `None`.
+    pub jitter: Option<f64>,
+
+    /// The proxy the gateway connections go through.
+    pub proxy: Option<Proxy>,
+
+    pub tls_backend: TlsBackend,
+
+    pub cache: Option<Arc<dyn Cache>>,
+
+    pub initial_presence: Option<UpdatePresenceData>,
+
+    pub session_store: Option<Arc<dyn SessionStore>>,
+
+    /// Whether the shards request the offline members of the large guilds they receive.
+    pub fetch_all_members: bool,
+
+    /// How many large guilds each shard requests the members of, all of them if `None`.
+    pub max_large_guilds_to_prefetch: Option<usize>,
+}
+
+/// A single shard on the default gateway URL without a token, override the fields the shard needs
+/// with `..Default::default()`.
+impl Default for WebSocketWorkerOptions {
+    fn default() -> Self {
+        let gateway_info = Arc::new(Mutex::new(GatewayBotObject {
+            url: "wss://gateway.discord.gg".into(),
+            shards: 1,
+            session_start_limit: SessionStartLimitObject {
+                total: 1000,
+                remaining: 1000,
+                reset_after: 0,
+                max_concurrency: 1,
+            },
+        }));
+
+        Self {
+            identify_queue: IdentifyQueue::new(gateway_info.clone()),
+            gateway_info,
+            token: Default::default(),
+            identify_properties: Default::default(),
+            event_handler: Arc::new(RwLock::new(Arc::new(MulticastEventHandler::default()))),
+            intents: Default::default(),
+            rest: Default::default(),
+            component_router: None,
+            command_registry: None,
+            metrics_sink: Arc::new(NoopMetricsSink),
+            jitter: None,
+            proxy: None,
+            tls_backend: Default::default(),
+            cache: None,
+            initial_presence: None,
+            session_store: None,
+            fetch_all_members: false,
+            max_large_guilds_to_prefetch: None,
+        }
+    }
 }
 
+/// The messages the worker sends to its shard, each carries the sender the shard replies through.
 pub enum WorkerMessage {
-    Connect,
-    Destroy(Option<CloseFrame<'static>>),
+    Connect(oneshot::Sender<()>),
+    Destroy(Option<CloseFrame<'static>>, oneshot::Sender<()>),
+    CloseCleanly(oneshot::Sender<()>),
+    Ping(oneshot::Sender<()>),
+    Resume(SessionSnapshot, oneshot::Sender<()>),
+    SaveSession(oneshot::Sender<Option<SessionSnapshot>>),
+    DebugInfo(oneshot::Sender<ShardDebugInfo>),
+    UpdateVoiceState(VoiceStateUpdateData, oneshot::Sender<Result<()>>),
+    /// Sends the voice connection details of the guild through the first sender once they
+    /// arrived, the reply confirms the wait is registered.
+    WaitForVoiceParams(
+        Snowflake,
+        oneshot::Sender<VoiceConnectionParams>,
+        oneshot::Sender<()>,
+    ),
+}
+
+/// How a shard task exited, the result of its event loop, or the [`JoinError`] of the task when it
+/// panicked. Both errors are shared between the callers of
+/// [`WebSocketWorker::wait_for_exit`].
+pub type ShardExit =
+    core::result::Result<core::result::Result<(), Arc<WebSocketError>>, Arc<JoinError>>;
+
+/// Clears the connecting flag of the worker once dropped.
+struct ConnectingGuard<'a>(&'a AtomicBool);
+
+impl Drop for ConnectingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
 }
 
 pub struct WebSocketWorker {
     pub id: ShardId,
     pub options: Arc<WebSocketWorkerOptions>,
     pub shard_sender: AsyncSender<WorkerMessage>,
-    pub worker_receiver: AsyncReceiver<ShardMessage>,
+    /// Resolves to how the shard task exited, shared so any number of callers can wait for it
+    /// without holding a lock.
+    exit: Option<Shared<BoxFuture<'static, ShardExit>>>,
+    destroyed: AtomicBool,
+    is_connecting: Arc<AtomicBool>,
+    self_user: Arc<RwLock<Option<UserObject>>>,
 }
 
 impl WebSocketWorker {
     pub async fn new(id: ShardId, options: Arc<WebSocketWorkerOptions>) -> Self {
         let (shard_sender, shard_receiver) = kanal::unbounded_async();
-        let (worker_sender, worker_receiver) = kanal::unbounded_async();
 
-        let mut shard = WebSocketShard::new(id, options.clone(), shard_receiver, worker_sender);
+        let mut shard = WebSocketShard::new(id, options.clone(), shard_receiver);
+        let self_user = shard.shared_self_user();
 
-        spawn(async move { shard.event_loop().await });
+        let exit = spawn(async move { shard.event_loop().await })
+            .map(|result| match result {
+                Ok(result) => Ok(result.map_err(Arc::new)),
+                Err(err) => Err(Arc::new(err)),
+            })
+            .boxed()
+            .shared();
+
+        let mut worker = Self::from_channels(id, options, shard_sender);
+        worker.self_user = self_user;
+
+        worker.exit = Some(exit);
+
+        worker
+    }
 
+    /// Creates a worker talking to a shard that is driven elsewhere, through the given channel.
+    pub fn from_channels(
+        id: ShardId,
+        options: Arc<WebSocketWorkerOptions>,
+        shard_sender: AsyncSender<WorkerMessage>,
+    ) -> Self {
         Self {
             id,
             options,
             shard_sender,
-            worker_receiver,
+            exit: None,
+            destroyed: AtomicBool::new(false),
+            is_connecting: Arc::new(AtomicBool::new(false)),
+            self_user: Default::default(),
         }
     }
 
+    /// The user object of the bot, received by the shard on `READY` and `USER_UPDATE`. Always
+    /// `None` for the workers created with [`from_channels`](Self::from_channels).
+    #[inline]
+    pub fn self_user(&self) -> Option<UserObject> {
+        self.self_user.read().unwrap().clone()
+    }
+
+    /// Waits for the shard task to exit and returns how it exited, returns immediately if it
+    /// already exited. The workers created with [`from_channels`](Self::from_channels) have no
+    /// task and exit cleanly.
+    pub async fn wait_for_exit(&self) -> ShardExit {
+        match self.exit {
+            Some(ref exit) => exit.clone().await,
+            None => Ok(Ok(())),
+        }
+    }
+
+    #[inline]
+    pub fn is_destroyed(&self) -> bool {
+        self.destroyed.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn is_connecting(&self) -> bool {
+        self.is_connecting.load(Ordering::Acquire)
+    }
+
+    #[inline]
     pub async fn connect(&self) {
-        if self
-            .shard_sender
-            .send(WorkerMessage::Connect)
+        self.connect_with(WorkerMessage::Connect).await
+    }
+
+    /// Connects the shard, resuming the given session instead of identifying.
+    #[inline]
+    pub async fn resume(&self, snapshot: SessionSnapshot) {
+        self.connect_with(|reply| WorkerMessage::Resume(snapshot, reply))
             .await
-            .is_err()
-        {
-            return;
-        }
+    }
 
-        loop {
-            let Ok(msg) = self.worker_receiver.recv().await else { return; };
-            if let ShardMessage::Connected = msg {
-                return;
-            }
-        }
+    pub async fn save_session(&self) -> Option<SessionSnapshot> {
+        self.request(WorkerMessage::SaveSession).await.flatten()
     }
-    pub async fn destroy(&self, info: Option<CloseFrame<'static>>) {
-        if self
-            .shard_sender
-            .send(WorkerMessage::Destroy(info))
+
+    pub async fn debug_info(&self) -> Option<ShardDebugInfo> {
+        self.request(WorkerMessage::DebugInfo).await
+    }
+
+    /// Sends the voice state update through the shard, see
+    /// [`WebSocketShard::join_voice_channel`].
+    /// Registers a wait for the voice connection details of the guild, see
+    /// [`WebSocketShard::wait_for_voice_params`]. Returns once the wait is registered, so the
+    /// Voice State Update that joins the channel can be sent before awaiting the details.
+    /// `None` if the shard task exited.
+    pub async fn wait_for_voice_params(
+        &self,
+        guild_id: Snowflake,
+    ) -> Option<impl Future<Output = Option<VoiceConnectionParams>>> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request(|reply| WorkerMessage::WaitForVoiceParams(guild_id, sender, reply))
+            .await?;
+
+        Some(async move { receiver.await.ok() })
+    }
+
+    pub async fn update_voice_state(&self, data: VoiceStateUpdateData) -> Result<()> {
+        let Some(result) = self
+            .request(|reply| WorkerMessage::UpdateVoiceState(data, reply))
             .await
+        else {
+            Err(ShardError::NotConnected)?
+        };
+
+        result
+    }
+
+    /// Sends the message to the shard and waits for its reply, `None` if the shard exited first.
+    async fn request<T>(&self, msg: impl FnOnce(oneshot::Sender<T>) -> WorkerMessage) -> Option<T> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.shard_sender.send(msg(sender)).await.ok()?;
+
+        receiver.await.ok()
+    }
+
+    /// Does nothing if the shard is already connecting, so concurrent calls don't queue
+    /// duplicate messages.
+    async fn connect_with(&self, msg: impl FnOnce(oneshot::Sender<()>) -> WorkerMessage) {
+        if self
+            .is_connecting
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
             .is_err()
         {
             return;
         }
 
-        loop {
-            let Ok(msg) = self.worker_receiver.recv().await else { return; };
-            if let ShardMessage::Destroyed = msg {
-                return;
-            }
-        }
+        // Cleared even if the caller stops waiting, e.g. on a timeout.
+        let _connecting = ConnectingGuard(&self.is_connecting);
+
+        self.request(msg).await;
+    }
+
+    pub async fn ping(&self) {
+        self.request(WorkerMessage::Ping).await;
+    }
+
+    /// Closes the connection with a normal closure, the shard can be connected again later.
+    pub async fn close_cleanly(&self) {
+        self.request(WorkerMessage::CloseCleanly).await;
+    }
+
+    pub async fn destroy(&self, info: Option<CloseFrame<'static>>) {
+        self.destroyed.store(true, Ordering::Release);
+
+        self.request(|reply| WorkerMessage::Destroy(info, reply))
+            .await;
     }
 }
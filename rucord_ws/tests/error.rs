@@ -0,0 +1,34 @@
+use std::error::Error;
+
+use rucord_ws::{
+    tungstenite::{error::ProtocolError, Error as TungsteniteError},
+    ShardError, WebSocketError,
+};
+
+#[test]
+fn test_shard_error_source_chain() {
+    let error = WebSocketError::Shard(ShardError::from(TungsteniteError::ConnectionClosed));
+
+    let shard = error.source().expect("expected the shard error");
+    let tungstenite = shard.source().expect("expected the tungstenite error");
+
+    assert!(shard.is::<ShardError>());
+    assert!(tungstenite.is::<TungsteniteError>());
+    assert!(tungstenite.source().is_none());
+}
+
+#[test]
+fn test_shard_error_from_protocol_error() {
+    let error = ShardError::from(TungsteniteError::Protocol(
+        ProtocolError::ResetWithoutClosingHandshake,
+    ));
+
+    assert!(matches!(
+        error,
+        ShardError::TungsteniteProtocol(ref e) if **e == ProtocolError::ResetWithoutClosingHandshake
+    ));
+    assert!(matches!(
+        ShardError::from(TungsteniteError::ConnectionClosed),
+        ShardError::Tungstenite(ref e) if matches!(**e, TungsteniteError::ConnectionClosed)
+    ));
+}
@@ -0,0 +1,38 @@
+use rucord_rest::TlsBackend;
+use rucord_ws::{check_tls_backend, gateway_url, WebSocketError};
+
+#[test]
+fn test_gateway_url_adds_params() {
+    for url in ["wss://gateway.discord.gg", "wss://gateway.discord.gg/"] {
+        assert_eq!(
+            gateway_url(url).unwrap(),
+            "wss://gateway.discord.gg/?v=10&encoding=json"
+        );
+    }
+
+    assert_eq!(
+        gateway_url("wss://gateway.discord.gg/?v=10").unwrap(),
+        "wss://gateway.discord.gg/?v=10&encoding=json"
+    );
+    assert_eq!(
+        gateway_url("wss://gateway.discord.gg/?encoding=json&v=10").unwrap(),
+        "wss://gateway.discord.gg/?encoding=json&v=10"
+    );
+    assert_eq!(
+        gateway_url("wss://gateway-us-east1-b.discord.gg?compress=zlib-stream").unwrap(),
+        "wss://gateway-us-east1-b.discord.gg/?compress=zlib-stream&v=10&encoding=json"
+    );
+}
+
+#[test]
+fn test_gateway_url_version_mismatch() {
+    assert!(matches!(
+        gateway_url("wss://gateway.discord.gg/?v=9"),
+        Err(WebSocketError::ApiVersionMismatch(9, 10))
+    ));
+}
+
+#[test]
+fn test_default_tls_backend_available() {
+    assert!(check_tls_backend(TlsBackend::default()).is_ok());
+}
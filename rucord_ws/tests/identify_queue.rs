@@ -0,0 +1,84 @@
+use std::{sync::Arc, time::Duration};
+
+use rucord_api_types::{GatewayBotObject, SessionStartLimitObject};
+use rucord_ws::IdentifyQueue;
+use tokio::{sync::Mutex, time::Instant};
+
+fn gateway_info(max_concurrency: u64) -> GatewayBotObject {
+    GatewayBotObject {
+        url: "wss://gateway.discord.gg".into(),
+        shards: 1,
+        session_start_limit: SessionStartLimitObject {
+            total: 1000,
+            remaining: 1000,
+            reset_after: 0,
+            max_concurrency,
+        },
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_queue_reset() {
+    let info = Arc::new(Mutex::new(gateway_info(1)));
+    let queue = IdentifyQueue::new(info.clone());
+
+    queue.wait_for_identify().await;
+
+    *info.lock().await = gateway_info(3);
+    queue.reset().await;
+
+    let started_at = Instant::now();
+
+    for _ in 0..3 {
+        queue.wait_for_identify().await;
+    }
+
+    // With the stale `max_concurrency` of 1 every identify would wait for a new window.
+    assert!(started_at.elapsed() < Duration::from_secs(10));
+}
+
+#[tokio::test]
+async fn test_session_started_low_water_mark() {
+    let info = Arc::new(Mutex::new(gateway_info(1)));
+    info.lock().await.session_start_limit.remaining = 102;
+
+    let queue = IdentifyQueue::new(info.clone()).with_session_low_water_mark(0.1);
+
+    assert_eq!(queue.session_started().await, None);
+    assert_eq!(queue.session_started().await, Some((100, 1000)));
+    assert_eq!(info.lock().await.session_start_limit.remaining, 100);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_wait_for_session_start() {
+    let info = Arc::new(Mutex::new(gateway_info(1)));
+
+    {
+        let mut info = info.lock().await;
+        info.session_start_limit.remaining = 0;
+        info.session_start_limit.reset_after = 60_000;
+    }
+
+    let queue = IdentifyQueue::new(info.clone());
+    let started_at = Instant::now();
+
+    queue.wait_for_session_start().await;
+
+    assert!(started_at.elapsed() >= Duration::from_secs(60));
+    assert_eq!(info.lock().await.session_start_limit.remaining, 1000);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_identify_queue_shared_rate_limit() {
+    let queue = IdentifyQueue::new(Arc::new(Mutex::new(gateway_info(1))));
+    let shared = queue.share_rate_limit(Arc::new(Mutex::new(gateway_info(1))));
+
+    queue.wait_for_identify().await;
+
+    let started_at = Instant::now();
+
+    shared.wait_for_identify().await;
+
+    // The identify of the first queue used up the window of both.
+    assert!(started_at.elapsed() >= Duration::from_secs(5));
+}
@@ -0,0 +1,102 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use rucord_api_types::{DispatchPayload, GuildObject};
+use rucord_ws::{MulticastEventHandler, ShardId, TypedEventHandler, WebSocketEventHandler};
+use serde_json::json;
+
+/// Records the events it receives in a log shared between the handlers.
+struct RecordingHandler {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl WebSocketEventHandler for RecordingHandler {
+    async fn debug(&self, shard_id: ShardId, message: String) {
+        let entry = format!("{} debug {shard_id}: {message}", self.name);
+        self.log.lock().unwrap().push(entry);
+    }
+
+    async fn resumed(&self, shard_id: ShardId) {
+        let entry = format!("{} resumed {shard_id}", self.name);
+        self.log.lock().unwrap().push(entry);
+    }
+}
+
+struct TypedRecordingHandler(Arc<Mutex<Vec<String>>>);
+
+#[async_trait]
+impl TypedEventHandler for TypedRecordingHandler {
+    async fn guild_create(&self, _shard_id: ShardId, data: &GuildObject) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("typed guild {}", data.id));
+    }
+}
+
+#[actix_rt::test]
+async fn test_multicast_in_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let first = Arc::new(RecordingHandler {
+        name: "first",
+        log: log.clone(),
+    });
+
+    let handler = MulticastEventHandler::default()
+        .with(first.clone())
+        .with(Arc::new(TypedRecordingHandler(log.clone())))
+        .with(Arc::new(RecordingHandler {
+            name: "second",
+            log: log.clone(),
+        }));
+
+    assert_eq!(handler.handlers().len(), 3);
+
+    handler.debug(1, "hello".into()).await;
+    handler.resumed(2).await;
+    handler
+        .dispatch(
+            0,
+            &DispatchPayload::GuildCreate(
+                serde_json::from_value(json!({ "id": "197038439483310086" })).unwrap(),
+            ),
+        )
+        .await;
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "first debug 1: hello",
+            "second debug 1: hello",
+            "first resumed 2",
+            "second resumed 2",
+            "typed guild 197038439483310086",
+        ]
+    );
+
+    // The handlers stay reachable through the clones of their `Arc`.
+    assert_eq!(first.name, "first");
+}
+
+#[actix_rt::test]
+async fn test_arc_forwards_to_handler() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let handler: Arc<dyn WebSocketEventHandler> = Arc::new(RecordingHandler {
+        name: "shared",
+        log: log.clone(),
+    });
+
+    // `Arc<dyn WebSocketEventHandler>` is a handler itself, so it can be nested.
+    let nested = MulticastEventHandler::new(vec![handler.clone()]).with(Arc::new(handler));
+
+    nested.resumed(3).await;
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["shared resumed 3", "shared resumed 3"]
+    );
+}
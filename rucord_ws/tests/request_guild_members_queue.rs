@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use rucord_api_types::{RequestGuildMembersData, Snowflake};
+use rucord_ws::RequestGuildMembersQueue;
+
+fn request(guild_id: u64) -> RequestGuildMembersData {
+    RequestGuildMembersData {
+        guild_id: Snowflake::new(guild_id),
+        query: Some(String::new()),
+        limit: 0,
+        presences: None,
+        user_ids: None,
+        nonce: None,
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_request_guild_members_queue_limit() {
+    let mut queue = RequestGuildMembersQueue::with_limit(3, Duration::from_secs(60));
+
+    for id in 1..=4 {
+        queue.push(request(id));
+    }
+
+    for id in 1..=3 {
+        assert_eq!(queue.pop_ready().unwrap().guild_id, Snowflake::new(id));
+    }
+
+    assert_eq!(queue.remaining(), 0);
+    assert!(queue.pop_ready().is_none());
+    assert_eq!(queue.len(), 1);
+
+    tokio::time::advance(Duration::from_secs(60)).await;
+
+    assert_eq!(queue.pop_ready().unwrap().guild_id, Snowflake::new(4));
+    assert_eq!(queue.remaining(), 2);
+    assert!(queue.is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_request_guild_members_queue_reset() {
+    let mut queue = RequestGuildMembersQueue::new();
+
+    queue.push(request(1));
+    queue.pop_ready().unwrap();
+    assert_eq!(queue.remaining(), RequestGuildMembersQueue::LIMIT - 1);
+
+    tokio::time::advance(RequestGuildMembersQueue::WINDOW).await;
+
+    assert_eq!(queue.remaining(), RequestGuildMembersQueue::LIMIT);
+
+    // Nothing queued, so nothing is counted.
+    assert!(queue.pop_ready().is_none());
+    assert_eq!(queue.remaining(), RequestGuildMembersQueue::LIMIT);
+}
@@ -0,0 +1,97 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use async_trait::async_trait;
+use rucord_rest::RequestManager;
+use rucord_ws::{
+    api_types::InteractionObject, ApplicationCommandHandler, CommandError, CommandRegistry,
+    ComponentHandler, ComponentRouter, ShardId,
+};
+use serde_json::{from_value, json};
+use tokio::sync::Mutex;
+
+struct CountingHandler(Arc<AtomicUsize>);
+
+#[async_trait]
+impl ComponentHandler for CountingHandler {
+    async fn handle(
+        &self,
+        _shard_id: ShardId,
+        _interaction: &InteractionObject,
+        _rest: Arc<Mutex<RequestManager>>,
+    ) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+struct PingCommand(Arc<AtomicUsize>);
+
+#[async_trait]
+impl ApplicationCommandHandler for PingCommand {
+    fn name() -> &'static str {
+        "ping"
+    }
+
+    async fn execute(
+        &self,
+        _shard_id: ShardId,
+        _interaction: &InteractionObject,
+        _rest: Arc<Mutex<RequestManager>>,
+    ) -> Result<(), CommandError> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+fn interaction(custom_id: &str) -> InteractionObject {
+    from_value(json!({
+        "id": "1",
+        "application_id": "2",
+        "type": 3,
+        "data": { "custom_id": custom_id, "component_type": 2 },
+        "token": "token",
+        "version": 1
+    }))
+    .unwrap()
+}
+
+#[actix_rt::test]
+async fn test_component_router() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let rest = Arc::new(Mutex::new(RequestManager::default()));
+
+    let mut router = ComponentRouter::new();
+    router.register("click_one", CountingHandler(calls.clone()));
+
+    assert!(
+        router
+            .route(0, &interaction("click_one"), rest.clone())
+            .await
+    );
+    assert!(!router.route(0, &interaction("click_two"), rest).await);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[actix_rt::test]
+async fn test_command_registry() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let rest = Arc::new(Mutex::new(RequestManager::default()));
+
+    let mut registry = CommandRegistry::new();
+    registry.register(PingCommand(calls.clone()));
+
+    let interaction: InteractionObject = from_value(json!({
+        "id": "1",
+        "application_id": "2",
+        "type": 2,
+        "data": { "id": "3", "name": "ping", "type": 1 },
+        "token": "token",
+        "version": 1
+    }))
+    .unwrap();
+
+    registry.route(0, &interaction, rest).await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
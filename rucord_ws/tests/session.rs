@@ -0,0 +1,55 @@
+use rucord_ws::{
+    api_types::{DispatchPayload, GatewaySendPayload},
+    MemorySessionStore, Session, SessionSnapshot, SessionStore,
+};
+use serde_json::{from_value, json, to_value};
+
+#[test]
+fn test_session_sequence_starts_at_zero() {
+    let payload = from_value(json!({ "t": "RESUMED", "s": 0, "d": null })).unwrap();
+
+    let (s, _) = DispatchPayload::from_payload(payload).unwrap();
+
+    let mut session = Session {
+        id: "session".into(),
+        shard_id: 0,
+        resume_url: "wss://gateway.discord.gg".into(),
+        shard_count: 1,
+        sequence: s,
+    };
+
+    assert_eq!(session.sequence, 0);
+    assert_eq!(
+        to_value(GatewaySendPayload::Heartbeat(Some(session.sequence))).unwrap(),
+        json!({ "op": 1, "d": 0 })
+    );
+
+    session.update_sequence(-1);
+    assert_eq!(session.sequence, 0);
+
+    session.update_sequence(5);
+    session.update_sequence(3);
+    assert_eq!(session.sequence, 5);
+}
+
+#[actix_rt::test]
+async fn test_memory_session_store() {
+    let store = MemorySessionStore::new();
+
+    let snapshot = SessionSnapshot {
+        id: "session".into(),
+        resume_url: "wss://gateway.discord.gg".into(),
+        sequence: 42,
+        shard_id: 1,
+        shard_count: 2,
+    };
+
+    store.save(&snapshot).await.unwrap();
+
+    assert_eq!(store.load(1).await.unwrap(), Some(snapshot));
+    assert_eq!(store.load(0).await.unwrap(), None);
+
+    store.remove(1).await.unwrap();
+
+    assert_eq!(store.load(1).await.unwrap(), None);
+}
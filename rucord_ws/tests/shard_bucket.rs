@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use rucord_ws::{bucket_shard_ids, respawn_delay};
+
+#[test]
+fn test_bucket_shard_ids() {
+    let ids: Vec<_> = (0..16).collect();
+
+    let buckets = bucket_shard_ids(&ids, 8);
+
+    assert_eq!(buckets.len(), 8);
+    for (key, bucket) in buckets.iter().enumerate() {
+        assert_eq!(bucket, &[key, key + 8]);
+    }
+}
+
+#[test]
+fn test_bucket_shard_ids_single_concurrency() {
+    assert_eq!(bucket_shard_ids(&[0, 1, 2], 1), vec![vec![0, 1, 2]]);
+    assert_eq!(bucket_shard_ids(&[3], 16), vec![vec![3]]);
+}
+
+#[test]
+fn test_respawn_delay() {
+    assert_eq!(respawn_delay(0), Duration::from_secs(1));
+    assert_eq!(respawn_delay(1), Duration::from_secs(2));
+    assert_eq!(respawn_delay(5), Duration::from_secs(32));
+    assert_eq!(respawn_delay(6), Duration::from_secs(60));
+    assert_eq!(respawn_delay(u32::MAX), Duration::from_secs(60));
+}
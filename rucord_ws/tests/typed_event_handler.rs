@@ -0,0 +1,45 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use rucord_api_types::{DispatchPayload, GuildObject};
+use rucord_ws::{ShardId, TypedEventHandler, WebSocketEventHandler};
+use serde_json::{json, Map, Value};
+
+#[derive(Default)]
+struct CountingHandler {
+    messages: AtomicUsize,
+    guilds: AtomicUsize,
+}
+
+#[async_trait]
+impl TypedEventHandler for CountingHandler {
+    async fn message_create(&self, _shard_id: ShardId, _data: &Map<String, Value>) {
+        self.messages.fetch_add(1, Ordering::SeqCst);
+    }
+
+    async fn guild_create(&self, _shard_id: ShardId, _data: &GuildObject) {
+        self.guilds.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[actix_rt::test]
+async fn test_typed_dispatch() {
+    let handler = CountingHandler::default();
+
+    let events = [
+        DispatchPayload::MessageCreate(Map::new()),
+        DispatchPayload::GuildCreate(
+            serde_json::from_value(json!({ "id": "197038439483310086" })).unwrap(),
+        ),
+        DispatchPayload::MessageCreate(Map::new()),
+        DispatchPayload::Resume,
+        DispatchPayload::Unknown("NEW_EVENT".into(), Map::new()),
+    ];
+
+    for event in &events {
+        WebSocketEventHandler::dispatch(&handler, 0, event).await;
+    }
+
+    assert_eq!(handler.messages.load(Ordering::SeqCst), 2);
+    assert_eq!(handler.guilds.load(Ordering::SeqCst), 1);
+}
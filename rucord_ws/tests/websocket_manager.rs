@@ -0,0 +1,370 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use async_tungstenite::{
+    tokio::accept_async,
+    tungstenite::{protocol::CloseFrame, Message},
+};
+use futures::{future::join, SinkExt, StreamExt};
+use rucord_api_types::{GatewayBotObject, ReadyData, SessionStartLimitObject};
+use rucord_ws::{
+    MemorySessionStore, ShardId, WebSocketError, WebSocketEventHandler, WebSocketManager,
+    WebSocketManagerOptions, WebSocketShardStatus,
+};
+use tokio::{
+    net::TcpListener,
+    time::{sleep, timeout},
+};
+
+struct NoopEventHandler;
+
+impl WebSocketEventHandler for NoopEventHandler {}
+
+struct ReadyHandler {
+    ready: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl WebSocketEventHandler for ReadyHandler {
+    async fn ready(&self, _shard_id: ShardId, _data: &ReadyData) {
+        self.ready.store(true, Ordering::SeqCst);
+    }
+}
+
+fn gateway_info(url: String) -> GatewayBotObject {
+    GatewayBotObject {
+        url,
+        shards: 1,
+        session_start_limit: SessionStartLimitObject {
+            total: 1000,
+            remaining: 1000,
+            reset_after: 60_000,
+            max_concurrency: 2,
+        },
+    }
+}
+
+fn manager_options() -> WebSocketManagerOptions {
+    let mut options =
+        WebSocketManagerOptions::new("token".into(), Default::default(), Default::default());
+
+    // Keeps the first heartbeat out of the payloads the tests expect.
+    options.jitter = Some(1.0);
+    options
+}
+
+/// Accepts the shard connections, says hello and answers the identifies of the first
+/// `ready_connections` connections with a ready, the other identifies are ignored or closed with
+/// `close_code`. Returns the gateway url, and the index of the connection along with the op of
+/// every payload it receives.
+async fn spawn_gateway(
+    ready_connections: usize,
+    close_code: Option<u16>,
+) -> (String, kanal::AsyncReceiver<(usize, u64)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let (sender, receiver) = kanal::unbounded_async();
+
+    let resume_url = url.clone();
+    tokio::spawn(async move {
+        for index in 0.. {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (sender, resume_url) = (sender.clone(), resume_url.clone());
+
+            tokio::spawn(async move {
+                let mut gateway = accept_async(stream).await.unwrap();
+
+                gateway
+                    .send(Message::Text(
+                        r#"{"op":10,"d":{"heartbeat_interval":45000}}"#.into(),
+                    ))
+                    .await
+                    .unwrap();
+
+                // Reading until the end also answers the close frames.
+                while let Some(Ok(message)) = gateway.next().await {
+                    let Message::Text(text) = message else {
+                        continue;
+                    };
+
+                    let payload: serde_json::Value = serde_json::from_str(&text).unwrap();
+                    let op = payload["op"].as_u64().unwrap();
+
+                    if let (2, Some(code)) = (op, close_code.filter(|_| index >= ready_connections))
+                    {
+                        let _ = gateway
+                            .close(Some(CloseFrame {
+                                code: code.into(),
+                                reason: "".into(),
+                            }))
+                            .await;
+                    }
+
+                    if op == 2 && index < ready_connections {
+                        let ready = serde_json::json!({
+                            "op": 0,
+                            "t": "READY",
+                            "s": 1,
+                            "d": {
+                                "v": 10,
+                                "user": {
+                                    "id": "80351110224678912",
+                                    "username": "Nelly",
+                                    "discriminator": "1337",
+                                    "avatar": null
+                                },
+                                "guilds": [],
+                                "session_id": format!("session-{index}"),
+                                "resume_gateway_url": resume_url,
+                                "application": { "id": "80351110224678912", "flags": 0 }
+                            }
+                        });
+
+                        let _ = gateway.send(Message::Text(ready.to_string())).await;
+                    }
+
+                    let _ = sender.send((index, op)).await;
+                }
+            });
+        }
+    });
+
+    (url, receiver)
+}
+
+async fn wait_until_ready(manager: &WebSocketManager, shard_id: ShardId) {
+    timeout(Duration::from_secs(5), async {
+        while manager.debug_info(shard_id).await.unwrap().status != WebSocketShardStatus::Ready {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("expected the shard to be ready");
+}
+
+#[actix_rt::test]
+async fn test_manager_runtime_apis() {
+    let (url, payloads) = spawn_gateway(usize::MAX, None).await;
+
+    let mut options = manager_options();
+    options.session_store = Some(Arc::new(MemorySessionStore::default()));
+
+    let mut manager = WebSocketManager::new(options);
+    manager.set_gateway_info(gateway_info(url)).await.unwrap();
+
+    timeout(
+        Duration::from_secs(5),
+        manager.start(Arc::new(NoopEventHandler)),
+    )
+    .await
+    .expect("expected start to return once the shards are spawned")
+    .unwrap();
+
+    assert_eq!(payloads.recv().await.unwrap(), (0, 2));
+    wait_until_ready(&manager, 0).await;
+    assert!(manager.self_user().is_some());
+
+    // The previous worker is destroyed first, so the new one resumes the session it stored.
+    manager.add_shard(0, 1).await.unwrap();
+    assert_eq!(payloads.recv().await.unwrap(), (1, 6));
+
+    manager.add_shard(1, 2).await.unwrap();
+    assert_eq!(payloads.recv().await.unwrap(), (2, 2));
+    wait_until_ready(&manager, 1).await;
+
+    manager.remove_shard(1).await.unwrap();
+    assert!(manager.debug_info(1).await.is_none());
+    assert_eq!(manager.save_sessions().await.len(), 1);
+
+    tokio::select! {
+        (id, panicked) = manager.wait_for_unexpected_exit() => {
+            manager.respawn(id, panicked).await;
+            panic!("expected shard {id} to keep running");
+        }
+        _ = sleep(Duration::from_millis(100)) => (),
+    }
+
+    manager.shutdown_gracefully().await;
+    manager.destroy(None).await;
+}
+
+#[actix_rt::test]
+async fn test_replace_event_handler_while_running() {
+    let (url, payloads) = spawn_gateway(usize::MAX, None).await;
+
+    let mut manager = WebSocketManager::new(manager_options());
+    manager.set_gateway_info(gateway_info(url)).await.unwrap();
+    manager.start(Arc::new(NoopEventHandler)).await.unwrap();
+
+    assert_eq!(payloads.recv().await.unwrap(), (0, 2));
+
+    let ready = Arc::new(AtomicBool::new(false));
+    let handle = manager.event_handler_handle();
+
+    // The handle replaces the handler while `run` borrows the manager.
+    let replace = async {
+        handle.replace(ReadyHandler {
+            ready: ready.clone(),
+        });
+    };
+    let _ = timeout(Duration::from_millis(100), join(manager.run(), replace)).await;
+
+    manager.add_shard(1, 2).await.unwrap();
+    wait_until_ready(&manager, 1).await;
+
+    assert!(ready.load(Ordering::SeqCst));
+
+    manager.destroy(None).await;
+}
+
+#[actix_rt::test]
+async fn test_add_shard_before_start() {
+    let (url, payloads) = spawn_gateway(usize::MAX, None).await;
+
+    let mut manager = WebSocketManager::new(manager_options());
+    manager.set_gateway_info(gateway_info(url)).await.unwrap();
+
+    // No shard ids are configured, so every shard of the new count is spawned.
+    manager.add_shard(1, 2).await.unwrap();
+    manager.start(Arc::new(NoopEventHandler)).await.unwrap();
+
+    let mut ops = vec![
+        payloads.recv().await.unwrap().1,
+        payloads.recv().await.unwrap().1,
+    ];
+    ops.sort_unstable();
+
+    assert_eq!(ops, [2, 2]);
+    assert!(manager.debug_info(0).await.is_some());
+    assert!(manager.debug_info(1).await.is_some());
+
+    manager.destroy(None).await;
+}
+
+#[actix_rt::test]
+async fn test_gateway_info_refresh_keeps_shard_count() {
+    let mut manager = WebSocketManager::new(manager_options());
+    manager
+        .set_gateway_info(gateway_info("ws://127.0.0.1:1".into()))
+        .await
+        .unwrap();
+
+    manager.add_shard(1, 2).await.unwrap();
+
+    // The refresh recommends a single shard again, but moves the gateway.
+    manager
+        .set_gateway_info(gateway_info("ws://127.0.0.1:2".into()))
+        .await
+        .unwrap();
+
+    let info = manager.fetch_gateway_info().await.unwrap();
+    let info = info.lock().await;
+
+    assert_eq!(info.shards, 2);
+    assert_eq!(info.url, "ws://127.0.0.1:2");
+    assert_eq!(manager.shard_ids().await.unwrap(), &[0, 1]);
+}
+
+#[actix_rt::test]
+async fn test_reshard_timeout_rolls_back() {
+    // Only the current shard gets ready.
+    let (url, payloads) = spawn_gateway(1, None).await;
+
+    let mut options = manager_options();
+    options.reshard_timeout = Duration::from_millis(500);
+
+    let mut manager = WebSocketManager::new(options);
+    manager.set_gateway_info(gateway_info(url)).await.unwrap();
+    manager.start(Arc::new(NoopEventHandler)).await.unwrap();
+
+    assert_eq!(payloads.recv().await.unwrap(), (0, 2));
+    wait_until_ready(&manager, 0).await;
+
+    assert!(matches!(
+        manager.reshard(2).await,
+        Err(WebSocketError::ReshardTimedOut(_))
+    ));
+
+    assert_eq!(manager.shard_ids().await.unwrap(), &[0]);
+    assert_eq!(
+        manager.debug_info(0).await.unwrap().status,
+        WebSocketShardStatus::Ready
+    );
+    assert!(manager.debug_info(1).await.is_none());
+
+    manager.destroy(None).await;
+}
+
+#[actix_rt::test]
+async fn test_reshard_exited_shard_rolls_back() {
+    // The new shards identify with an invalid shard count.
+    let (url, payloads) = spawn_gateway(1, Some(4010)).await;
+
+    let mut manager = WebSocketManager::new(manager_options());
+    manager.set_gateway_info(gateway_info(url)).await.unwrap();
+    manager.start(Arc::new(NoopEventHandler)).await.unwrap();
+
+    assert_eq!(payloads.recv().await.unwrap(), (0, 2));
+    wait_until_ready(&manager, 0).await;
+
+    assert!(matches!(
+        manager.reshard(2).await,
+        Err(WebSocketError::ReshardShardExited)
+    ));
+
+    assert_eq!(manager.shard_ids().await.unwrap(), &[0]);
+    assert_eq!(
+        manager.debug_info(0).await.unwrap().status,
+        WebSocketShardStatus::Ready
+    );
+
+    manager.destroy(None).await;
+}
+
+#[actix_rt::test]
+async fn test_gateway_url_change_reconnects_shards() {
+    let first = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let second = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+    let mut manager = WebSocketManager::new(manager_options());
+
+    manager
+        .set_gateway_info(gateway_info(format!(
+            "ws://{}",
+            first.local_addr().unwrap()
+        )))
+        .await
+        .unwrap();
+
+    // The cached gateway information is fresh, so starting doesn't need the rest manager. The
+    // shard waits for the hello, which fails once the stream is dropped.
+    let (result, _) = join(manager.start(Arc::new(NoopEventHandler)), async {
+        let (stream, _) = timeout(Duration::from_secs(1), first.accept())
+            .await
+            .expect("expected the shard to connect to the first url")
+            .unwrap();
+        drop(stream);
+    })
+    .await;
+
+    result.unwrap();
+
+    let url = format!("ws://{}", second.local_addr().unwrap());
+
+    let (result, _) = join(manager.set_gateway_info(gateway_info(url)), async {
+        let (stream, _) = timeout(Duration::from_secs(1), second.accept())
+            .await
+            .expect("expected the shard to reconnect to the new url")
+            .unwrap();
+        drop(stream);
+    })
+    .await;
+
+    result.unwrap();
+}
@@ -0,0 +1,1049 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use async_tungstenite::{tokio::accept_async, tungstenite::Message};
+use futures::{SinkExt, StreamExt};
+use rucord_api_types::{
+    DispatchPayload, GatewayCloseCode, GatewayIntentBits, GatewayReceivePayload,
+    RequestGuildMembersData, Snowflake, UserObject,
+};
+use rucord_ws::{
+    Cache, DestroyReason, MemoryCache, MemorySessionStore, ReconnectReason,
+    RequestGuildMembersQueue, SessionSnapshot, SessionStore, ShardError, ShardId,
+    VoiceConnectionParams, WebSocketError, WebSocketEventHandler, WebSocketShard,
+    WebSocketShardStatus, WebSocketWorker, WebSocketWorkerOptions, WorkerMessage,
+};
+use tokio::{
+    join,
+    net::TcpListener,
+    sync::{oneshot, Mutex},
+    time::{sleep, timeout, Instant},
+};
+
+struct UserUpdateHandler {
+    called: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl WebSocketEventHandler for UserUpdateHandler {
+    async fn user_update(&self, _shard_id: ShardId, _data: &UserObject) {
+        self.called.store(true, Ordering::SeqCst);
+    }
+}
+
+struct UnknownEventHandler {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl WebSocketEventHandler for UnknownEventHandler {
+    async fn unknown_dispatch_event(
+        &self,
+        _shard_id: ShardId,
+        event_name: &str,
+        data: &serde_json::Map<String, serde_json::Value>,
+    ) {
+        assert_eq!(data.get("answer"), Some(&serde_json::Value::from(42)));
+        self.events.lock().await.push(event_name.to_owned());
+    }
+}
+
+struct DispatchHandler {
+    dispatched: Arc<AtomicBool>,
+}
+
+#[async_trait]
+impl WebSocketEventHandler for DispatchHandler {
+    async fn dispatch(&self, _shard_id: ShardId, _data: &DispatchPayload) {
+        self.dispatched.store(true, Ordering::SeqCst);
+    }
+}
+
+struct WatchdogHandler {
+    triggered: Arc<Mutex<Vec<Duration>>>,
+}
+
+#[async_trait]
+impl WebSocketEventHandler for WatchdogHandler {
+    async fn watchdog_triggered(&self, _shard_id: ShardId, elapsed: Duration) {
+        self.triggered.lock().await.push(elapsed);
+    }
+}
+
+/// Accepts a shard connection and says hello, returns the payloads the shard sent up to its
+/// first heartbeat.
+async fn gateway_payloads(listener: &TcpListener) -> Vec<serde_json::Value> {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut gateway = accept_async(stream).await.unwrap();
+
+    gateway
+        .send(Message::Text(
+            r#"{"op":10,"d":{"heartbeat_interval":45000}}"#.into(),
+        ))
+        .await
+        .unwrap();
+
+    let mut payloads = Vec::new();
+
+    while let Some(Ok(Message::Text(text))) = gateway.next().await {
+        let payload: serde_json::Value = serde_json::from_str(&text).unwrap();
+        let op = payload["op"].as_u64().unwrap();
+
+        payloads.push(payload);
+
+        if op == 1 {
+            break;
+        }
+    }
+
+    payloads
+}
+
+fn worker_options() -> Arc<WebSocketWorkerOptions> {
+    worker_options_with_jitter(None)
+}
+
+fn worker_options_with_jitter(jitter: Option<f64>) -> Arc<WebSocketWorkerOptions> {
+    Arc::new(WebSocketWorkerOptions {
+        jitter,
+        ..Default::default()
+    })
+}
+
+#[actix_rt::test]
+async fn test_worker_ping() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    timeout(Duration::from_secs(1), worker.ping())
+        .await
+        .expect("expected the shard to answer the ping");
+}
+
+#[actix_rt::test]
+async fn test_drain_worker_queue() {
+    let (shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    let (connect, connected) = oneshot::channel();
+    let (ping, _pong) = oneshot::channel();
+
+    shard_sender
+        .send(WorkerMessage::Connect(connect))
+        .await
+        .unwrap();
+    shard_sender.send(WorkerMessage::Ping(ping)).await.unwrap();
+    shard_sender
+        .send(WorkerMessage::Connect(oneshot::channel().0))
+        .await
+        .unwrap();
+
+    shard.drain_worker_queue();
+
+    assert!(matches!(
+        shard.wait_worker_event().await,
+        Ok(WorkerMessage::Ping(_))
+    ));
+    assert!(
+        timeout(Duration::from_millis(100), shard.wait_worker_event())
+            .await
+            .is_err(),
+        "expected the stale connect messages to be discarded"
+    );
+    assert!(
+        connected.await.is_ok(),
+        "expected the stale connect messages to be answered"
+    );
+}
+
+#[actix_rt::test]
+async fn test_session_snapshot() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    assert_eq!(shard.save_session(), None);
+
+    let snapshot = SessionSnapshot {
+        id: "session".into(),
+        resume_url: "wss://gateway.discord.gg".into(),
+        sequence: 42,
+        shard_id: 0,
+        shard_count: 1,
+    };
+
+    shard.restore_session(snapshot.clone());
+
+    assert_eq!(shard.save_session(), Some(snapshot));
+}
+
+#[actix_rt::test]
+async fn test_fatal_close_code_clears_session() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    shard.restore_session(SessionSnapshot {
+        id: "session".into(),
+        resume_url: "wss://gateway.discord.gg".into(),
+        sequence: 42,
+        shard_id: 0,
+        shard_count: 1,
+    });
+
+    shard
+        .destroy(
+            None,
+            DestroyReason::FatalCloseCode(GatewayCloseCode::AuthenticationFailed),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(shard.save_session(), None);
+}
+
+#[test]
+fn test_destroy_reason() {
+    assert_eq!(
+        DestroyReason::from(ReconnectReason::InvalidSession { resumable: false }),
+        DestroyReason::Reconnect
+    );
+    assert_eq!(
+        DestroyReason::from(ReconnectReason::NetworkError),
+        DestroyReason::Resume
+    );
+
+    assert!(DestroyReason::Intentional.keeps_session());
+    assert!(!DestroyReason::Intentional.reconnects());
+    assert!(!DestroyReason::Reconnect.keeps_session());
+    assert!(DestroyReason::Resume.reconnects());
+}
+
+#[actix_rt::test]
+async fn test_worker_save_session() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    assert_eq!(
+        timeout(Duration::from_secs(1), worker.save_session())
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[actix_rt::test]
+async fn test_worker_wait_for_exit() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    worker.destroy(None).await;
+
+    assert!(worker.is_destroyed());
+    assert!(matches!(
+        timeout(Duration::from_secs(1), worker.wait_for_exit())
+            .await
+            .unwrap(),
+        Ok(Ok(()))
+    ));
+}
+
+#[actix_rt::test]
+async fn test_worker_exit_error() {
+    let options = WebSocketWorkerOptions::default();
+    // Nothing listens on the port, so connecting fails and the task exits with the error.
+    options.gateway_info.lock().await.url = "ws://127.0.0.1:1".into();
+
+    let worker = WebSocketWorker::new(0, Arc::new(options)).await;
+
+    worker.connect().await;
+
+    let exit = timeout(Duration::from_secs(5), worker.wait_for_exit())
+        .await
+        .unwrap();
+
+    assert!(matches!(exit, Ok(Err(_))));
+}
+
+#[actix_rt::test]
+async fn test_worker_concurrent_wait_for_exit() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    let exits = async { join!(worker.wait_for_exit(), worker.wait_for_exit()) };
+
+    let (exits, _) = timeout(Duration::from_secs(1), async {
+        join!(exits, worker.destroy(None))
+    })
+    .await
+    .expect("expected every waiter to see the exit");
+
+    assert!(matches!(exits, (Ok(Ok(())), Ok(Ok(())))));
+}
+
+#[actix_rt::test]
+async fn test_worker_concurrent_connect() {
+    let (shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let worker = WebSocketWorker::from_channels(0, worker_options(), shard_sender);
+
+    let shard = async {
+        sleep(Duration::from_millis(100)).await;
+
+        assert!(worker.is_connecting());
+        assert_eq!(shard_receiver.len(), 1);
+
+        let Ok(Some(WorkerMessage::Connect(reply))) = shard_receiver.try_recv() else {
+            panic!("expected a single `Connect` message");
+        };
+        reply.send(()).unwrap();
+    };
+
+    timeout(Duration::from_secs(1), async {
+        join!(worker.connect(), worker.connect(), shard)
+    })
+    .await
+    .unwrap();
+
+    assert!(!worker.is_connecting());
+    assert!(matches!(shard_receiver.try_recv(), Ok(None)));
+}
+
+#[actix_rt::test]
+async fn test_worker_cancelled_connect() {
+    let (shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let worker = WebSocketWorker::from_channels(0, worker_options(), shard_sender);
+
+    // Nothing answers, so the caller gives up.
+    assert!(timeout(Duration::from_millis(100), worker.connect())
+        .await
+        .is_err());
+    assert!(!worker.is_connecting());
+
+    let connect = async {
+        worker.connect().await;
+    };
+
+    let shard = async {
+        while shard_receiver.len() < 2 {
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        // The cancelled `Connect` is still queued before the new one.
+        let _cancelled = shard_receiver.try_recv();
+
+        let Ok(Some(WorkerMessage::Connect(reply))) = shard_receiver.try_recv() else {
+            panic!("expected the later `Connect` to be sent");
+        };
+        reply.send(()).unwrap();
+    };
+
+    timeout(Duration::from_secs(1), async { join!(connect, shard) })
+        .await
+        .unwrap();
+}
+
+#[actix_rt::test]
+async fn test_worker_concurrent_requests() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    let (_, info, session) = timeout(Duration::from_secs(1), async {
+        join!(worker.ping(), worker.debug_info(), worker.save_session())
+    })
+    .await
+    .expect("expected every request to get its own reply");
+
+    assert_eq!(info.unwrap().shard_id, 0);
+    assert_eq!(session, None);
+}
+
+#[actix_rt::test]
+async fn test_dropped_shard_drops_replies() {
+    let (shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    let (ping, pong) = oneshot::channel();
+    shard_sender.send(WorkerMessage::Ping(ping)).await.unwrap();
+
+    drop(shard);
+
+    assert!(pong.await.is_err());
+}
+
+#[actix_rt::test]
+async fn test_worker_close_cleanly_idle() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    timeout(Duration::from_secs(1), worker.close_cleanly())
+        .await
+        .expect("expected an idle shard to answer the close");
+    timeout(Duration::from_secs(1), worker.ping())
+        .await
+        .expect("expected the shard to keep running after closing");
+}
+
+#[actix_rt::test]
+async fn test_hello_jitter() {
+    for (jitter, expected) in [(0.0, 0), (0.5, 20_000), (2.0, 40_000)] {
+        let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+        let mut shard =
+            WebSocketShard::new(0, worker_options_with_jitter(Some(jitter)), shard_receiver);
+
+        shard
+            .resolve_event(&GatewayReceivePayload::Hello(40_000))
+            .await
+            .unwrap();
+
+        assert_eq!(shard.next_heartbeat(), Duration::from_millis(expected));
+    }
+}
+
+#[actix_rt::test]
+async fn test_invalid_session_removes_stored_session() {
+    let store = Arc::new(MemorySessionStore::new());
+
+    store
+        .save(&SessionSnapshot {
+            id: "session".into(),
+            resume_url: "wss://gateway.discord.gg".into(),
+            sequence: 42,
+            shard_id: 0,
+            shard_count: 1,
+        })
+        .await
+        .unwrap();
+
+    let options = Arc::new(WebSocketWorkerOptions {
+        session_store: Some(store.clone()),
+        ..Default::default()
+    });
+
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+    let mut shard = WebSocketShard::new(0, options, shard_receiver);
+
+    shard
+        .resolve_event(&GatewayReceivePayload::InvalidSession(false))
+        .await
+        .unwrap();
+
+    assert_eq!(store.load(0).await.unwrap(), None);
+}
+
+#[actix_rt::test]
+async fn test_watchdog_triggered() {
+    tokio::time::pause();
+
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let options = worker_options();
+    let mut shard = WebSocketShard::new(0, options.clone(), shard_receiver);
+
+    shard
+        .resolve_event(&GatewayReceivePayload::Hello(1000))
+        .await
+        .unwrap();
+
+    // Replaced after the watchdog started, which still reaches the new handler.
+    let triggered = Arc::new(Mutex::new(vec![]));
+    *options.event_handler.write().unwrap() = Arc::new(WatchdogHandler {
+        triggered: triggered.clone(),
+    });
+
+    sleep(Duration::from_secs(6)).await;
+    assert!(triggered.lock().await.is_empty());
+
+    // Fires once, until the event loop reconnects.
+    sleep(Duration::from_secs(10)).await;
+
+    let triggered = triggered.lock().await;
+    assert_eq!(triggered.len(), 1);
+    assert!(triggered[0] > Duration::from_millis(6500));
+    assert!(shard.watchdog_expired());
+}
+
+#[actix_rt::test]
+async fn test_user_update_self_user() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    assert!(shard.self_user().is_none());
+
+    let user = serde_json::from_value(serde_json::json!({
+        "id": "80351110224678912",
+        "username": "Nelly",
+        "discriminator": "1337",
+        "avatar": null,
+        "bot": true
+    }))
+    .unwrap();
+
+    shard
+        .resolve_event(&GatewayReceivePayload::Dispatch((
+            1,
+            DispatchPayload::UserUpdate(user),
+        )))
+        .await
+        .unwrap();
+
+    assert_eq!(shard.self_user().map(|user| user.username()), Some("Nelly"));
+}
+
+#[actix_rt::test]
+async fn test_worker_debug_info() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    let info = timeout(Duration::from_secs(1), worker.debug_info())
+        .await
+        .unwrap()
+        .expect("expected the shard to answer");
+
+    assert_eq!(info.shard_id, 0);
+    assert_eq!(info.status, WebSocketShardStatus::Idle);
+    assert!(!info.has_connection);
+    assert_eq!(info.sequence, None);
+    assert_eq!(info.reconnect_attempts, 0);
+    assert!(info.to_string().starts_with("Shard 0:\n  status: Idle\n"));
+}
+
+#[actix_rt::test]
+async fn test_ready_self_user() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+    let shared = shard.shared_self_user();
+
+    let ready = serde_json::from_value(serde_json::json!({
+        "v": 10,
+        "user": {
+            "id": "80351110224678912",
+            "username": "Nelly",
+            "discriminator": "1337",
+            "avatar": null,
+            "bot": true
+        },
+        "guilds": [],
+        "session_id": "session",
+        "resume_gateway_url": "wss://gateway.discord.gg",
+        "application": { "id": "80351110224678912", "flags": 0 }
+    }))
+    .unwrap();
+
+    shard
+        .resolve_event(&GatewayReceivePayload::Dispatch((
+            1,
+            DispatchPayload::Ready(ready),
+        )))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        shard.self_user().map(|user| user.id()),
+        Some(Snowflake::new(80351110224678912))
+    );
+    assert_eq!(
+        shared.read().unwrap().as_ref().map(|user| user.is_bot()),
+        Some(true)
+    );
+}
+
+#[actix_rt::test]
+async fn test_replaced_event_handler() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let options = worker_options();
+    let mut shard = WebSocketShard::new(0, options.clone(), shard_receiver);
+
+    let called = Arc::new(AtomicBool::new(false));
+    *options.event_handler.write().unwrap() = Arc::new(UserUpdateHandler {
+        called: called.clone(),
+    });
+
+    let user = serde_json::from_value(serde_json::json!({
+        "id": "80351110224678912",
+        "username": "Nelly",
+        "discriminator": "1337",
+        "avatar": null
+    }))
+    .unwrap();
+
+    shard
+        .resolve_event(&GatewayReceivePayload::Dispatch((
+            1,
+            DispatchPayload::UserUpdate(user),
+        )))
+        .await
+        .unwrap();
+
+    assert!(called.load(Ordering::SeqCst));
+}
+
+#[actix_rt::test]
+async fn test_cache_member_count() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let cache = Arc::new(MemoryCache::new());
+    let options = Arc::new(WebSocketWorkerOptions {
+        cache: Some(cache.clone()),
+        ..Default::default()
+    });
+    let mut shard = WebSocketShard::new(0, options, shard_receiver);
+
+    let guild_id = Snowflake::new(197038439483310086);
+    let user = serde_json::json!({
+        "id": "80351110224678912",
+        "username": "Nelly",
+        "discriminator": "1337",
+        "avatar": null
+    });
+
+    let events = [
+        DispatchPayload::GuildCreate(
+            serde_json::from_value(serde_json::json!({
+                "id": "197038439483310086",
+                "name": "Discord Developers",
+                "member_count": 2
+            }))
+            .unwrap(),
+        ),
+        DispatchPayload::GuildMemberAdd(
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "197038439483310086",
+                "user": user,
+                "roles": [],
+                "joined_at": "2015-04-26T06:26:56.936000+00:00"
+            }))
+            .unwrap(),
+        ),
+        DispatchPayload::GuildMemberAdd(
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "197038439483310086",
+                "roles": [],
+                "joined_at": "2015-04-26T06:26:56.936000+00:00"
+            }))
+            .unwrap(),
+        ),
+        DispatchPayload::GuildMemberRemove(
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "197038439483310086",
+                "user": user
+            }))
+            .unwrap(),
+        ),
+    ];
+
+    for (s, event) in events.into_iter().enumerate() {
+        shard
+            .resolve_event(&GatewayReceivePayload::Dispatch((s as i64, event)))
+            .await
+            .unwrap();
+    }
+
+    assert_eq!(cache.get_member_count(&guild_id), Some(3));
+    assert_eq!(
+        cache
+            .get_guild(&guild_id)
+            .and_then(|guild| guild.member_count),
+        Some(3)
+    );
+    assert_eq!(cache.get_member_count(&Snowflake::new(1)), None);
+}
+
+#[actix_rt::test]
+async fn test_cache_message_update() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let cache = Arc::new(MemoryCache::new());
+    let options = Arc::new(WebSocketWorkerOptions {
+        cache: Some(cache.clone()),
+        ..Default::default()
+    });
+    let mut shard = WebSocketShard::new(0, options, shard_receiver);
+
+    let channel_id = Snowflake::new(41771983423143937);
+    let message_id = Snowflake::new(1067461416862814210);
+
+    let events = [
+        DispatchPayload::MessageCreate(
+            serde_json::from_value(serde_json::json!({
+                "id": "1067461416862814210",
+                "channel_id": "41771983423143937",
+                "author": {
+                    "id": "80351110224678912",
+                    "username": "Nelly",
+                    "discriminator": "1337",
+                    "avatar": null
+                },
+                "content": "Hello, world!",
+                "timestamp": "2023-01-24T18:30:00.000000+00:00",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "pinned": false
+            }))
+            .unwrap(),
+        ),
+        DispatchPayload::MessageUpdate(
+            serde_json::from_value(serde_json::json!({
+                "id": "1067461416862814210",
+                "channel_id": "41771983423143937",
+                "content": "Hello, edited world!",
+                "edited_timestamp": "2023-01-24T18:31:00.000000+00:00"
+            }))
+            .unwrap(),
+        ),
+        // Not cached, so the partial update is dropped.
+        DispatchPayload::MessageUpdate(
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "channel_id": "41771983423143937",
+                "content": "Unknown"
+            }))
+            .unwrap(),
+        ),
+    ];
+
+    for (s, event) in events.into_iter().enumerate() {
+        shard
+            .resolve_event(&GatewayReceivePayload::Dispatch((s as i64, event)))
+            .await
+            .unwrap();
+    }
+
+    let message = cache.get_message(&channel_id, &message_id).unwrap();
+
+    assert_eq!(message.content, "Hello, edited world!");
+    assert_eq!(message.author.username(), "Nelly");
+    assert!(message.edited_timestamp.is_some());
+    assert!(cache.get_message(&channel_id, &Snowflake::new(1)).is_none());
+}
+
+#[actix_rt::test]
+async fn test_wait_for_voice_params() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    let params = shard.wait_for_voice_params(&Snowflake::new(41771983423143937));
+
+    let events = [
+        DispatchPayload::VoiceServerUpdate(
+            serde_json::from_value(serde_json::json!({
+                "token": "my_token",
+                "guild_id": "41771983423143937",
+                "endpoint": "sweetwater-12345.discord.media:2048"
+            }))
+            .unwrap(),
+        ),
+        DispatchPayload::VoiceStateUpdate(
+            serde_json::from_value(serde_json::json!({
+                "guild_id": "41771983423143937",
+                "channel_id": "127121515262115840",
+                "user_id": "80351110224678912",
+                "session_id": "90326bd25d71d39b9ef95b299e3872ff"
+            }))
+            .unwrap(),
+        ),
+    ];
+
+    for (s, event) in events.into_iter().enumerate() {
+        shard
+            .resolve_event(&GatewayReceivePayload::Dispatch((s as i64, event)))
+            .await
+            .unwrap();
+    }
+
+    let params = timeout(Duration::from_secs(1), params)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        params,
+        VoiceConnectionParams {
+            guild_id: Snowflake::new(41771983423143937),
+            session_id: "90326bd25d71d39b9ef95b299e3872ff".into(),
+            token: "my_token".into(),
+            endpoint: Some("sweetwater-12345.discord.media:2048".into()),
+        }
+    );
+}
+
+#[actix_rt::test]
+async fn test_replaced_voice_params_wait() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, worker_options(), shard_receiver);
+
+    let guild_id = Snowflake::new(41771983423143937);
+    let first = shard.wait_for_voice_params(&guild_id);
+    let _second = shard.wait_for_voice_params(&guild_id);
+
+    assert_eq!(first.await, None);
+}
+
+#[actix_rt::test]
+async fn test_worker_wait_for_voice_params() {
+    let worker = WebSocketWorker::new(0, worker_options()).await;
+
+    let params = worker
+        .wait_for_voice_params(Snowflake::new(41771983423143937))
+        .await
+        .expect("expected the shard to register the wait");
+
+    // The wait ends with the shard instead of panicking.
+    worker.destroy(None).await;
+
+    assert_eq!(timeout(Duration::from_secs(1), params).await.unwrap(), None);
+    assert!(worker
+        .wait_for_voice_params(Snowflake::new(41771983423143937))
+        .await
+        .is_none());
+}
+
+#[actix_rt::test]
+async fn test_unknown_dispatch_event() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let options = worker_options();
+    let mut shard = WebSocketShard::new(0, options.clone(), shard_receiver);
+
+    let events = Arc::new(Mutex::new(vec![]));
+    *options.event_handler.write().unwrap() = Arc::new(UnknownEventHandler {
+        events: events.clone(),
+    });
+
+    let payload = serde_json::from_value(serde_json::json!({
+        "t": "FUTURE_EVENT",
+        "s": 3,
+        "op": 0,
+        "d": { "answer": 42 }
+    }))
+    .unwrap();
+
+    shard
+        .resolve_event(&GatewayReceivePayload::Dispatch(
+            DispatchPayload::from_payload(payload).unwrap(),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(*events.lock().await, ["FUTURE_EVENT"]);
+}
+
+#[actix_rt::test]
+async fn test_prefetch_large_guild_members() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+    let options = WebSocketWorkerOptions {
+        intents: GatewayIntentBits::Guilds | GatewayIntentBits::GuildMembers,
+        fetch_all_members: true,
+        max_large_guilds_to_prefetch: Some(1),
+        ..Default::default()
+    };
+    options.gateway_info.lock().await.url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let mut shard = WebSocketShard::new(0, Arc::new(options), shard_receiver);
+
+    let client = async {
+        shard.connect().await.unwrap();
+
+        for (s, id) in [(1, "197038439483310086"), (2, "41771983423143937")] {
+            let guild = serde_json::from_value(serde_json::json!({
+                "id": id,
+                "name": "Discord Developers",
+                "large": true
+            }))
+            .unwrap();
+
+            shard
+                .resolve_event(&GatewayReceivePayload::Dispatch((
+                    s,
+                    DispatchPayload::GuildCreate(guild),
+                )))
+                .await
+                .unwrap();
+        }
+
+        shard.heartbeat(true).await.unwrap();
+    };
+
+    let (ops, _) = join!(gateway_payloads(&listener), client);
+
+    let ops: Vec<_> = ops.iter().map(|payload| payload["op"].as_u64()).collect();
+    assert_eq!(ops, [Some(2), Some(8), Some(1)]);
+}
+
+#[actix_rt::test]
+async fn test_session_start_limit_waits_before_connecting() {
+    tokio::time::pause();
+
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let options = WebSocketWorkerOptions::default();
+
+    {
+        let mut info = options.gateway_info.lock().await;
+        info.url = format!("ws://{}", listener.local_addr().unwrap());
+        info.session_start_limit.remaining = 0;
+        info.session_start_limit.reset_after = 60_000;
+    }
+
+    let mut shard = WebSocketShard::new(0, Arc::new(options), shard_receiver);
+    let started_at = Instant::now();
+
+    let gateway = async {
+        let accepted = timeout(Duration::from_secs(30), listener.accept()).await;
+        assert!(
+            accepted.is_err(),
+            "expected the shard to wait before connecting"
+        );
+
+        gateway_payloads(&listener).await
+    };
+
+    let client = async {
+        shard.connect().await.unwrap();
+        shard.heartbeat(true).await.unwrap();
+    };
+
+    let (payloads, _) = join!(gateway, client);
+
+    assert!(started_at.elapsed() >= Duration::from_secs(60));
+    assert_eq!(payloads[0]["op"], 2);
+}
+
+#[actix_rt::test]
+async fn test_prefetch_without_connection_dispatches() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let options = Arc::new(WebSocketWorkerOptions {
+        intents: GatewayIntentBits::Guilds | GatewayIntentBits::GuildMembers,
+        fetch_all_members: true,
+        ..Default::default()
+    });
+
+    let dispatched = Arc::new(AtomicBool::new(false));
+    *options.event_handler.write().unwrap() = Arc::new(DispatchHandler {
+        dispatched: dispatched.clone(),
+    });
+
+    let mut shard = WebSocketShard::new(0, options, shard_receiver);
+
+    let guild = serde_json::from_value(serde_json::json!({
+        "id": "197038439483310086",
+        "name": "Discord Developers",
+        "large": true
+    }))
+    .unwrap();
+
+    // The request waits for a connection instead of failing the event.
+    shard
+        .resolve_event(&GatewayReceivePayload::Dispatch((
+            1,
+            DispatchPayload::GuildCreate(guild),
+        )))
+        .await
+        .unwrap();
+
+    assert!(dispatched.load(Ordering::SeqCst));
+}
+
+#[actix_rt::test]
+async fn test_request_guild_members_rate_limit() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let options = WebSocketWorkerOptions::default();
+    options.gateway_info.lock().await.url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let mut shard = WebSocketShard::new(0, Arc::new(options), shard_receiver);
+
+    let client = async {
+        shard.connect().await.unwrap();
+
+        // The request past the limit is held back instead of blocking the shard for a minute.
+        for _ in 0..=RequestGuildMembersQueue::LIMIT {
+            let request = RequestGuildMembersData {
+                guild_id: Snowflake::new(197038439483310086),
+                query: Some(String::new()),
+                limit: 0,
+                presences: None,
+                user_ids: None,
+                nonce: None,
+            };
+
+            timeout(
+                Duration::from_secs(1),
+                shard.request_guild_members_queued(request),
+            )
+            .await
+            .expect("expected the request to be queued without waiting")
+            .unwrap();
+        }
+
+        shard.heartbeat(true).await.unwrap();
+    };
+
+    let (payloads, _) = join!(gateway_payloads(&listener), client);
+
+    let requests = payloads.iter().filter(|payload| payload["op"] == 8).count();
+    assert_eq!(requests, RequestGuildMembersQueue::LIMIT as usize);
+}
+
+#[actix_rt::test]
+async fn test_join_and_leave_voice_channel() {
+    let (_shard_sender, shard_receiver) = kanal::unbounded_async();
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let options = WebSocketWorkerOptions::default();
+    options.gateway_info.lock().await.url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let mut shard = WebSocketShard::new(0, Arc::new(options), shard_receiver);
+
+    let guild_id = Snowflake::new(197038439483310086);
+    let channel_id = Snowflake::new(41771983423143937);
+
+    assert!(matches!(
+        shard.leave_voice_channel(&guild_id).await,
+        Err(WebSocketError::Shard(ShardError::NotConnected))
+    ));
+
+    let client = async {
+        shard.connect().await.unwrap();
+
+        shard
+            .join_voice_channel(&guild_id, &channel_id, false, true)
+            .await
+            .unwrap();
+        shard.leave_voice_channel(&guild_id).await.unwrap();
+
+        shard.heartbeat(true).await.unwrap();
+    };
+
+    let (payloads, _) = join!(gateway_payloads(&listener), client);
+
+    assert_eq!(
+        payloads[1],
+        serde_json::json!({
+            "op": 4,
+            "d": {
+                "guild_id": "197038439483310086",
+                "channel_id": "41771983423143937",
+                "self_mute": false,
+                "self_deaf": true
+            }
+        })
+    );
+    assert_eq!(payloads[2]["op"], 4);
+    assert_eq!(payloads[2]["d"]["channel_id"], serde_json::Value::Null);
+}